@@ -1,35 +1,37 @@
-pub fn ease_in_quad(x: f64) -> f64 {
+use crate::float::Float;
+
+pub fn ease_in_quad<T: Float>(x: T) -> T {
     x * x
 }
 
-pub fn ease_out_quad(x: f64) -> f64 {
-    1.0 - (1.0 - x) * (1.0 - x)
+pub fn ease_out_quad<T: Float>(x: T) -> T {
+    T::ONE - (T::ONE - x) * (T::ONE - x)
 }
 
-pub fn ease_in_out_quad(x: f64) -> f64 {
-    if x < 0.5 {
-        2.0 * x * x
+pub fn ease_in_out_quad<T: Float>(x: T) -> T {
+    if x < T::HALF {
+        T::TWO * x * x
     } else {
-        1.0 - 2.0 * (1.0 - x) * (1.0 - x)
+        T::ONE - T::TWO * (T::ONE - x) * (T::ONE - x)
     }
 }
 
-pub fn bounce(x: f64) -> f64 {
-    let t = x.abs() % 2.0;
-    if t < 1.0 {
+pub fn bounce<T: Float>(x: T) -> T {
+    let t = x.abs() % T::TWO;
+    if t < T::ONE {
         t
     } else {
-        2.0 - t
+        T::TWO - t
     }
 }
 
-pub fn elastic(x: f64) -> f64 {
-    if x == 0.0 {
-        0.0
-    } else if x == 1.0 {
-        1.0
+pub fn elastic<T: Float>(x: T) -> T {
+    if x == T::ZERO {
+        T::ZERO
+    } else if x == T::ONE {
+        T::ONE
     } else {
-        let c4 = (2.0 * std::f64::consts::PI) / 3.0;
-        -(2.0_f64.powf(10.0 * (x - 1.0))) * ((x - 1.0) * c4 - std::f64::consts::PI / 2.0).sin()
+        let c4 = T::TAU / T::from_f64(3.0);
+        -(T::TWO.powf(T::from_f64(10.0) * (x - T::ONE))) * ((x - T::ONE) * c4 - T::FRAC_PI_2).sin()
     }
 }
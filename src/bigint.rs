@@ -0,0 +1,788 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+// Below this many limbs in the shorter factor schoolbook multiplication is faster
+const KARATSUBA_THRESHOLD: usize = 32;
+
+// Largest power of ten in a limb, used for decimal conversion
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+// Sign and magnitude, with the magnitude in base 2^32 limbs from least significant
+// up and no leading zero limbs, so that zero is the empty vector and never negative
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl BigInt {
+    pub const ZERO: BigInt = BigInt {
+        negative: false,
+        digits: Vec::new(),
+    };
+
+    fn from_parts(negative: bool, mut digits: Vec<u32>) -> Self {
+        trim(&mut digits);
+        let negative = negative && !digits.is_empty();
+        Self { negative, digits }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.digits == [1]
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.digits.is_empty()
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.digits.first().is_none_or(|d| d % 2 == 0)
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    pub fn signum(&self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.digits.is_empty() {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.digits.clone())
+    }
+
+    // Number of bits in the magnitude, 0 for zero
+    pub fn bits(&self) -> u64 {
+        match self.digits.last() {
+            None => 0,
+            Some(&top) => 32 * self.digits.len() as u64 - top.leading_zeros() as u64,
+        }
+    }
+
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let index = self.digits.iter().position(|&d| d != 0)?;
+        Some(32 * index as u64 + self.digits[index].trailing_zeros() as u64)
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result = BigInt::from(1);
+        let mut base = self.clone();
+
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = &result * &base;
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    // Floor of the square root through Newton's iteration from above
+    pub fn isqrt(&self) -> Self {
+        if self.negative {
+            panic!("argument of integer square root cannot be negative");
+        }
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+
+        let mut x = BigInt::from(1) << self.bits().div_ceil(2) as u32;
+        loop {
+            let y = (&x + self / &x) >> 1;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    // Quotient rounded toward zero and remainder with the sign of self, as for i64
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        let (q, r) = div_rem_magnitude(&self.digits, &rhs.digits);
+        (
+            Self::from_parts(self.negative != rhs.negative, q),
+            Self::from_parts(self.negative, r),
+        )
+    }
+
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.abs();
+        let mut b = other.abs();
+
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+
+        a
+    }
+
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::ZERO;
+        }
+        (self.abs() / self.gcd(other)) * other.abs()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.negative || self.digits.len() > 2 {
+            return None;
+        }
+        Some(
+            self.digits
+                .iter()
+                .rev()
+                .fold(0, |acc, &d| acc << 32 | d as u64),
+        )
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.abs().to_u64()?;
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.negative || self.digits.len() > 4 {
+            return None;
+        }
+        Some(
+            self.digits
+                .iter()
+                .rev()
+                .fold(0, |acc, &d| acc << 32 | d as u128),
+        )
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.abs().to_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    // Nearest f64 from the top 64 bits, infinite past f64::MAX
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        let magnitude = if bits <= 64 {
+            self.abs().to_u64().unwrap_or(0) as f64
+        } else {
+            let shift = bits - 64;
+            // Keep a sticky bit so that the rounding to 53 bits sees what was shifted out
+            let sticky = self.trailing_zeros().is_some_and(|tz| tz < shift) as u64;
+            let top = (self.abs() >> shift as u32).to_u64().unwrap_or(0) | sticky;
+            top as f64 * 2f64.powi(shift.min(i32::MAX as u64) as i32)
+        };
+
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+fn trim(digits: &mut Vec<u32>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+
+    for (i, &d) in long.iter().enumerate() {
+        let sum = d as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+
+    result
+}
+
+// a - b for a >= b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, &d) in a.iter().enumerate() {
+        let diff = d as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        result.push(diff as u32);
+        borrow = (diff < 0) as i64;
+    }
+
+    trim(&mut result);
+    result
+}
+
+// Adds x · 2^(32 shift) into the accumulator in place
+fn add_shifted(acc: &mut Vec<u32>, x: &[u32], shift: usize) {
+    if acc.len() < shift + x.len() + 1 {
+        acc.resize(shift + x.len() + 1, 0);
+    }
+
+    let mut carry = 0u64;
+    let mut i = shift;
+    for &d in x {
+        let sum = acc[i] as u64 + d as u64 + carry;
+        acc[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    while carry > 0 {
+        if i == acc.len() {
+            acc.push(0);
+        }
+        let sum = acc[i] as u64 + carry;
+        acc[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let p = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = p as u32;
+            carry = p >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+
+    trim(&mut result);
+    result
+}
+
+// Karatsuba: with a = a1 B + a0 and b = b1 B + b0, the middle term
+// a1 b0 + a0 b1 is (a0 + a1)(b0 + b1) - a0 b0 - a1 b1, three products instead of four
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+
+    let m = a.len().max(b.len()) / 2;
+    let split = |x: &[u32]| {
+        let (lo, hi) = x.split_at(m.min(x.len()));
+        let mut lo = lo.to_vec();
+        trim(&mut lo);
+        (lo, hi.to_vec())
+    };
+    let (a0, a1) = split(a);
+    let (b0, b1) = split(b);
+
+    let z0 = mul_magnitude(&a0, &b0);
+    let z2 = mul_magnitude(&a1, &b1);
+    let cross = mul_magnitude(&add_magnitude(&a0, &a1), &add_magnitude(&b0, &b1));
+    let z1 = sub_magnitude(&sub_magnitude(&cross, &z0), &z2);
+
+    let mut result = z0;
+    add_shifted(&mut result, &z1, m);
+    add_shifted(&mut result, &z2, 2 * m);
+    trim(&mut result);
+    result
+}
+
+fn div_rem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+
+    for i in (0..a.len()).rev() {
+        let current = remainder << 32 | a[i] as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+
+    trim(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+fn shift_left_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for &d in a {
+        result.push(d << shift | carry);
+        carry = d >> (32 - shift);
+    }
+    result.push(carry);
+    result
+}
+
+// Knuth's algorithm D: estimate each quotient limb from the top two limbs of the
+// running remainder over the normalized top limb of the divisor, which is at most
+// two too large, then correct
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_rem_small(a, b[0]);
+        let r = if r == 0 { Vec::new() } else { vec![r] };
+        return (q, r);
+    }
+
+    let shift = b[b.len() - 1].leading_zeros();
+    let mut v = shift_left_bits(b, shift);
+    trim(&mut v);
+    let mut u = shift_left_bits(a, shift);
+    if u.len() == a.len() {
+        u.push(0);
+    }
+
+    let n = v.len();
+    let m = u.len() - n;
+    let mut quotient = vec![0u32; m];
+    let base = 1u64 << 32;
+
+    for j in (0..m).rev() {
+        let numerator = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+        let mut qhat = numerator / v[n - 1] as u64;
+        let mut rhat = numerator % v[n - 1] as u64;
+
+        while qhat >= base || qhat * v[n - 2] as u64 > (rhat << 32 | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * v[i] as u64 + carry;
+            carry = p >> 32;
+            let t = u[i + j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+            u[i + j] = t as u32;
+            borrow = -(t >> 32);
+        }
+        let t = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = t as u32;
+
+        // The estimate was one too large: add the divisor back
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = qhat as u32;
+    }
+
+    trim(&mut quotient);
+    u.truncate(n);
+    let mut remainder = if shift == 0 {
+        u
+    } else {
+        (0..n)
+            .map(|i| u[i] >> shift | u.get(i + 1).map_or(0, |&d| d << (32 - shift)))
+            .collect()
+    };
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+impl From<u64> for BigInt {
+    fn from(n: u64) -> Self {
+        Self::from_parts(false, vec![n as u32, (n >> 32) as u32])
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let mut result = BigInt::from(n.unsigned_abs());
+        result.negative = n < 0;
+        result
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> Self {
+        Self::from_parts(false, (0..4).map(|i| (n >> (32 * i)) as u32).collect())
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        let mut result = BigInt::from(n.unsigned_abs());
+        result.negative = n < 0;
+        result
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(n: i32) -> Self {
+        BigInt::from(n as i64)
+    }
+}
+
+impl From<u32> for BigInt {
+    fn from(n: u32) -> Self {
+        BigInt::from(n as u64)
+    }
+}
+
+impl From<usize> for BigInt {
+    fn from(n: usize) -> Self {
+        BigInt::from(n as u64)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, body) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        if body.is_empty() || !body.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        // Fold in chunks of nine digits, the first one taking the remainder
+        let mut digits: Vec<u32> = Vec::new();
+        let first = body.len() % DECIMAL_DIGITS;
+        let mut start = 0;
+        while start < body.len() {
+            let end = if start == 0 && first > 0 {
+                first
+            } else {
+                start + DECIMAL_DIGITS
+            };
+            let chunk: u32 = body[start..end].parse().map_err(|_| ParseBigIntError)?;
+            let scale = 10u32.pow((end - start) as u32);
+
+            let mut carry = chunk as u64;
+            for d in digits.iter_mut() {
+                let p = *d as u64 * scale as u64 + carry;
+                *d = p as u32;
+                carry = p >> 32;
+            }
+            if carry > 0 {
+                digits.push(carry as u32);
+            }
+            start = end;
+        }
+
+        Ok(Self::from_parts(negative, digits))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut rest = self.digits.clone();
+        while !rest.is_empty() {
+            let (q, r) = div_rem_small(&rest, DECIMAL_BASE);
+            chunks.push(r);
+            rest = q;
+        }
+
+        let mut s = chunks.last().unwrap().to_string();
+        for chunk in chunks.iter().rev().skip(1) {
+            s.push_str(&format!("{:09}", chunk));
+        }
+
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.digits, &other.digits),
+            (true, true) => compare_magnitude(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> BigInt {
+        self.negative = !self.negative && !self.digits.is_empty();
+        self
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.digits, &rhs.digits));
+        }
+
+        match compare_magnitude(&self.digits, &rhs.digits) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.digits, &self.digits))
+            }
+            _ => BigInt::from_parts(self.negative, sub_magnitude(&self.digits, &rhs.digits)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &(-rhs)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.digits, &rhs.digits),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+// The owned and mixed forms, and the ones with an i64 operand, all go through the
+// reference implementations above
+macro_rules! forward_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $trait<&BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: &BigInt) -> BigInt {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $trait<BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $trait<i64> for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: i64) -> BigInt {
+                (&self).$method(&BigInt::from(rhs))
+            }
+        }
+
+        impl $trait<i64> for &BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: i64) -> BigInt {
+                self.$method(&BigInt::from(rhs))
+            }
+        }
+
+        impl $assign_trait for BigInt {
+            fn $assign_method(&mut self, rhs: BigInt) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+
+        impl $assign_trait<&BigInt> for BigInt {
+            fn $assign_method(&mut self, rhs: &BigInt) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+
+        impl $assign_trait<i64> for BigInt {
+            fn $assign_method(&mut self, rhs: i64) {
+                *self = (&*self).$method(&BigInt::from(rhs));
+            }
+        }
+    };
+}
+
+forward_binop!(Add, add, AddAssign, add_assign);
+forward_binop!(Sub, sub, SubAssign, sub_assign);
+forward_binop!(Mul, mul, MulAssign, mul_assign);
+forward_binop!(Div, div, DivAssign, div_assign);
+forward_binop!(Rem, rem, RemAssign, rem_assign);
+
+impl Shl<u32> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, shift: u32) -> BigInt {
+        let mut digits = vec![0u32; (shift / 32) as usize];
+        digits.extend(shift_left_bits(&self.digits, shift % 32));
+        BigInt::from_parts(self.negative, digits)
+    }
+}
+
+impl Shl<u32> for BigInt {
+    type Output = BigInt;
+
+    fn shl(self, shift: u32) -> BigInt {
+        &self << shift
+    }
+}
+
+// Rounds toward negative infinity, as the shift of a signed primitive does
+impl Shr<u32> for &BigInt {
+    type Output = BigInt;
+
+    fn shr(self, shift: u32) -> BigInt {
+        if self.negative {
+            let magnitude = (self.abs() - 1i64) >> shift;
+            return -(magnitude + 1i64);
+        }
+
+        let skip = (shift / 32) as usize;
+        if skip >= self.digits.len() {
+            return BigInt::ZERO;
+        }
+
+        let bits = shift % 32;
+        let rest = &self.digits[skip..];
+        let digits = if bits == 0 {
+            rest.to_vec()
+        } else {
+            (0..rest.len())
+                .map(|i| rest[i] >> bits | rest.get(i + 1).map_or(0, |&d| d << (32 - bits)))
+                .collect()
+        };
+        BigInt::from_parts(false, digits)
+    }
+}
+
+impl Shr<u32> for BigInt {
+    type Output = BigInt;
+
+    fn shr(self, shift: u32) -> BigInt {
+        &self >> shift
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1), |acc, x| acc * x)
+    }
+}
@@ -0,0 +1,314 @@
+use crate::bigint::BigInt;
+use crate::float::Float;
+use crate::number_theory::gcd_u64;
+use crate::special::{log_beta, log_gamma};
+
+// Exact counts return None once the result no longer fits. Each recurrence
+// below only passes through values no larger than its result, so the first
+// overflow along the way already means the result overflows.
+
+// Exact n!, special::factorial being the gamma-based version for real x
+pub fn factorial_u64(n: u32) -> Option<u64> {
+    (1..=n as u64).try_fold(1u64, |acc, k| acc.checked_mul(k))
+}
+
+pub fn factorial_big(n: u32) -> BigInt {
+    product_big(1, n as u64)
+}
+
+// n (n - 2) (n - 4) ... down to 1 or 2
+pub fn double_factorial(n: u32) -> Option<u64> {
+    (1..=n as u64)
+        .rev()
+        .step_by(2)
+        .try_fold(1u64, |acc, k| acc.checked_mul(k))
+}
+
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    // C(n - k + i, i) for i = 1..k, each dividing exactly and growing up to
+    // the result as k <= n / 2
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 1..=k {
+        result = result * (n - k + i) as u128 / i as u128;
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+
+    Some(result as u64)
+}
+
+pub fn binomial_big(n: u64, k: u64) -> BigInt {
+    if k > n {
+        return BigInt::ZERO;
+    }
+
+    let k = k.min(n - k);
+    product_big(n - k + 1, n) / factorial_big(k as u32)
+}
+
+// (k1 + k2 + ...)! / (k1! k2! ...), the number of ways to split a set into
+// parts of those sizes
+pub fn multinomial(ks: &[u64]) -> Option<u64> {
+    let mut total: u64 = 0;
+    let mut result: u64 = 1;
+    for &k in ks {
+        total = total.checked_add(k)?;
+        result = result.checked_mul(binomial(total, k)?)?;
+    }
+    Some(result)
+}
+
+// Signed Stirling numbers of the first kind, (-1)^(n - k) times the number of
+// permutations of n elements with k cycles
+pub fn stirling_s1(n: u32, k: u32) -> Option<i64> {
+    if k > n {
+        return Some(0);
+    }
+
+    let unsigned = stirling_band(n, k, |i, _| i as u64 - 1)?;
+    let value = i64::try_from(unsigned).ok()?;
+    Some(if (n - k).is_multiple_of(2) {
+        value
+    } else {
+        -value
+    })
+}
+
+// Stirling numbers of the second kind, partitions of n elements into k blocks
+pub fn stirling_s2(n: u32, k: u32) -> Option<u64> {
+    stirling_band(n, k, |_, j| j as u64)
+}
+
+pub fn stirling_s1_big(n: u32, k: u32) -> BigInt {
+    let unsigned = stirling_band_big(n, k, |i, _| i as u64 - 1);
+    if n.saturating_sub(k).is_multiple_of(2) {
+        unsigned
+    } else {
+        -unsigned
+    }
+}
+
+pub fn stirling_s2_big(n: u32, k: u32) -> BigInt {
+    stirling_band_big(n, k, |_, j| j as u64)
+}
+
+// T(n, k) for T(i, j) = weight(i, j) T(i - 1, j) + T(i - 1, j - 1), computed
+// only over the band of (i, j) that T(n, k) depends on
+fn stirling_band(n: u32, k: u32, weight: impl Fn(u32, u32) -> u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k as usize;
+    let mut row = vec![0u64; k + 1];
+    row[0] = 1;
+
+    for i in 1..=n {
+        let lowest = (k + i as usize).saturating_sub(n as usize).max(1);
+        for j in (lowest..=k.min(i as usize)).rev() {
+            let carried = weight(i, j as u32).checked_mul(row[j])?;
+            row[j] = carried.checked_add(row[j - 1])?;
+        }
+        row[0] = 0;
+    }
+
+    Some(row[k])
+}
+
+fn stirling_band_big(n: u32, k: u32, weight: impl Fn(u32, u32) -> u64) -> BigInt {
+    if k > n {
+        return BigInt::ZERO;
+    }
+
+    let k = k as usize;
+    let mut row = vec![BigInt::ZERO; k + 1];
+    row[0] = BigInt::from(1);
+
+    for i in 1..=n {
+        let lowest = (k + i as usize).saturating_sub(n as usize).max(1);
+        for j in (lowest..=k.min(i as usize)).rev() {
+            row[j] = BigInt::from(weight(i, j as u32)) * &row[j] + &row[j - 1];
+        }
+        row[0] = BigInt::ZERO;
+    }
+
+    row.swap_remove(k)
+}
+
+// Number of partitions of a set of n elements, from the Bell triangle
+pub fn bell_number(n: u32) -> Option<u64> {
+    let mut row = vec![1u64];
+    for _ in 1..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(*row.last().unwrap());
+        for &x in &row {
+            next.push(next.last().unwrap().checked_add(x)?);
+        }
+        row = next;
+    }
+    Some(*row.last().unwrap())
+}
+
+pub fn bell_number_big(n: u32) -> BigInt {
+    let mut row = vec![BigInt::from(1)];
+    for _ in 1..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row.last().unwrap().clone());
+        for x in &row {
+            let sum = next.last().unwrap() + x;
+            next.push(sum);
+        }
+        row = next;
+    }
+    row.pop().unwrap()
+}
+
+pub fn catalan_number(n: u32) -> Option<u64> {
+    // C(i + 1) = C(i) 2 (2i + 1) / (i + 2)
+    let mut result: u128 = 1;
+    for i in 0..n as u128 {
+        result = result * 2 * (2 * i + 1) / (i + 2);
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+// Number of Dyck paths of length 2n with k peaks, C(n, k) C(n, k - 1) / n
+pub fn narayana_number(n: u64, k: u64) -> Option<u64> {
+    if n == 0 {
+        return Some((k == 0) as u64);
+    }
+    if k == 0 || k > n {
+        return Some(0);
+    }
+    if k == 1 || k == n {
+        return Some(1);
+    }
+
+    // n divides the product, so n / g divides C(n, k - 1)
+    let a = binomial(n, k)?;
+    let g = gcd_u64(a, n);
+    (a / g).checked_mul(binomial(n, k - 1)? / (n / g))
+}
+
+// Number of alternating permutations of n elements, the Euler zigzag numbers
+// 1, 1, 1, 2, 5, 16, 61, ... from the Seidel-Entringer triangle
+pub fn euler_zigzag(n: u32) -> Option<u64> {
+    let mut row = vec![1u64];
+    for i in 1..=n as usize {
+        let mut next = Vec::with_capacity(i + 1);
+        next.push(0u64);
+        for j in 0..i {
+            next.push(next[j].checked_add(row[i - 1 - j])?);
+        }
+        row = next;
+    }
+    Some(*row.last().unwrap())
+}
+
+// Number of partitions of the integer n by Euler's pentagonal number theorem
+pub fn partitions_p(n: u32) -> Option<u64> {
+    let mut p: Vec<u64> = Vec::with_capacity(n as usize + 1);
+    p.push(1);
+    for m in 1..=n as usize {
+        let value = pentagonal_sum(m, |i| p[i] as i128);
+        p.push(u64::try_from(value).ok()?);
+    }
+    Some(p[n as usize])
+}
+
+pub fn partitions_p_big(n: u32) -> BigInt {
+    let mut p: Vec<BigInt> = Vec::with_capacity(n as usize + 1);
+    p.push(BigInt::from(1));
+    for m in 1..=n as usize {
+        let mut value = BigInt::ZERO;
+        for (index, positive) in pentagonal_terms(m) {
+            if positive {
+                value += &p[index];
+            } else {
+                value -= &p[index];
+            }
+        }
+        p.push(value);
+    }
+    p.swap_remove(n as usize)
+}
+
+// The indices m - g_k for the generalized pentagonal numbers g_k <= m, with
+// signs + + - - + + ...
+fn pentagonal_terms(m: usize) -> impl Iterator<Item = (usize, bool)> {
+    (1..)
+        .flat_map(|k: usize| [k * (3 * k - 1) / 2, k * (3 * k + 1) / 2])
+        .take_while(move |&g| g <= m)
+        .enumerate()
+        .map(move |(i, g)| (m - g, i % 4 < 2))
+}
+
+fn pentagonal_sum(m: usize, p: impl Fn(usize) -> i128) -> i128 {
+    pentagonal_terms(m)
+        .map(|(index, positive)| if positive { p(index) } else { -p(index) })
+        .sum()
+}
+
+pub fn log_factorial<T: Float>(x: T) -> T {
+    log_gamma(x + T::ONE)
+}
+
+// ln C(n, k) for real n >= k >= 0, through the beta function to avoid the
+// cancellation between log gammas when k is small against n
+pub fn log_binomial<T: Float>(n: T, k: T) -> T {
+    -(n + T::ONE).ln() - log_beta(n - k + T::ONE, k + T::ONE)
+}
+
+// ln n!! for real n >= 0, from n!! = 2^(n/2) Γ(n/2 + 1) for even n, with an
+// extra factor sqrt(2/π) for odd n
+pub fn log_double_factorial<T: Float>(n: T) -> T {
+    let half = n * T::HALF;
+    let even = half * T::LN_2 + log_gamma(half + T::ONE);
+    if half.fract() == T::ZERO {
+        even
+    } else {
+        even + T::HALF * (T::LN_2 - T::PI.ln())
+    }
+}
+
+// ln C(2n, n) / (n + 1)
+pub fn log_catalan<T: Float>(n: T) -> T {
+    log_binomial(n + n, n) - (n + T::ONE).ln()
+}
+
+// ln C(n, k) C(n, k - 1) / n for real n >= k >= 1
+pub fn log_narayana<T: Float>(n: T, k: T) -> T {
+    log_binomial(n, k) + log_binomial(n, k - T::ONE) - n.ln()
+}
+
+pub fn log_multinomial<T: Float>(ks: &[T]) -> T {
+    let mut total = T::ZERO;
+    let mut result = T::ZERO;
+    for &k in ks {
+        total += k;
+        result += log_binomial(total, k);
+    }
+    result
+}
+
+// lo (lo + 1) ... hi by splitting in halves, so the factors stay balanced
+fn product_big(lo: u64, hi: u64) -> BigInt {
+    if lo > hi {
+        return BigInt::from(1);
+    }
+    if hi - lo < 8 {
+        return (lo..=hi).map(BigInt::from).product();
+    }
+
+    let mid = lo + (hi - lo) / 2;
+    product_big(lo, mid) * product_big(mid + 1, hi)
+}
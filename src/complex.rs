@@ -0,0 +1,656 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn recip(self) -> Self {
+        Complex::ONE / self
+    }
+
+    pub fn scale(self, k: f64) -> Self {
+        Self::new(self.re * k, self.im * k)
+    }
+
+    pub fn mul_i(self) -> Self {
+        Self::new(-self.im, self.re)
+    }
+
+    pub fn mul_neg_i(self) -> Self {
+        Self::new(self.im, -self.re)
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    pub fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    pub fn is_real(self) -> bool {
+        self.im == 0.0
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+}
+
+impl From<(f64, f64)> for Complex {
+    fn from((re, im): (f64, f64)) -> Self {
+        Self::new(re, im)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im.is_sign_negative() && !self.im.is_nan() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    // Smith's algorithm, avoids overflow in |rhs|^2
+    fn div(self, rhs: Complex) -> Complex {
+        if rhs.im == 0.0 {
+            return Complex::new(self.re / rhs.re, self.im / rhs.re);
+        }
+
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Complex::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Complex::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl Add<f64> for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: f64) -> Complex {
+        Complex::new(self.re + rhs, self.im)
+    }
+}
+
+impl Sub<f64> for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: f64) -> Complex {
+        Complex::new(self.re - rhs, self.im)
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: f64) -> Complex {
+        self.scale(rhs)
+    }
+}
+
+impl Div<f64> for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: f64) -> Complex {
+        Complex::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl Add<Complex> for f64 {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self + rhs.re, rhs.im)
+    }
+}
+
+impl Sub<Complex> for f64 {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self - rhs.re, -rhs.im)
+    }
+}
+
+impl Mul<Complex> for f64 {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        rhs.scale(self)
+    }
+}
+
+impl Div<Complex> for f64 {
+    type Output = Complex;
+
+    fn div(self, rhs: Complex) -> Complex {
+        Complex::from(self) / rhs
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Complex) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, rhs: Complex) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Complex) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Complex {
+    fn div_assign(&mut self, rhs: Complex) {
+        *self = *self / rhs;
+    }
+}
+
+impl AddAssign<f64> for Complex {
+    fn add_assign(&mut self, rhs: f64) {
+        self.re += rhs;
+    }
+}
+
+impl SubAssign<f64> for Complex {
+    fn sub_assign(&mut self, rhs: f64) {
+        self.re -= rhs;
+    }
+}
+
+impl MulAssign<f64> for Complex {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = self.scale(rhs);
+    }
+}
+
+impl DivAssign<f64> for Complex {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl std::iter::Sum for Complex {
+    fn sum<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ZERO, |acc, z| acc + z)
+    }
+}
+
+impl std::iter::Product for Complex {
+    fn product<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ONE, |acc, z| acc * z)
+    }
+}
+
+const NAN: Complex = Complex::new(f64::NAN, f64::NAN);
+
+pub fn cexp(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z.im == 0.0 {
+        return Complex::new(z.re.exp(), z.im);
+    }
+
+    if z.re == f64::NEG_INFINITY && z.im.is_infinite() {
+        return Complex::ZERO;
+    }
+
+    let (s, c) = z.im.sin_cos();
+
+    // Split the scaling so that e^re * |cos| can stay finite when e^re alone overflows
+    if z.re > 709.0 {
+        let half = (z.re * 0.5).exp();
+        return Complex::new(c * half * half, s * half * half);
+    }
+
+    let r = z.re.exp();
+    Complex::new(r * c, r * s)
+}
+
+pub fn cexp2(z: Complex) -> Complex {
+    cexp(z.scale(std::f64::consts::LN_2))
+}
+
+pub fn cexp10(z: Complex) -> Complex {
+    cexp(z.scale(std::f64::consts::LN_10))
+}
+
+fn log_abs(z: Complex) -> f64 {
+    let a = z.re.abs().max(z.im.abs());
+    let b = z.re.abs().min(z.im.abs());
+
+    // Near the unit circle ln|z| cancels badly; rewrite it through ln_1p
+    if a > 0.5 && a < 2.0 {
+        return 0.5 * ((a - 1.0) * (a + 1.0) + b * b).ln_1p();
+    }
+
+    z.abs().ln()
+}
+
+pub fn clog(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z.is_infinite() {
+        return Complex::new(f64::INFINITY, z.arg());
+    }
+
+    if z.re == 0.0 && z.im == 0.0 {
+        return Complex::new(f64::NEG_INFINITY, z.arg());
+    }
+
+    Complex::new(log_abs(z), z.arg())
+}
+
+pub fn clog_base(a: Complex, z: Complex) -> Complex {
+    if a == Complex::ONE {
+        return NAN;
+    }
+
+    clog(z) / clog(a)
+}
+
+pub fn clog10(z: Complex) -> Complex {
+    clog(z).scale(std::f64::consts::LOG10_E)
+}
+
+pub fn clog2(z: Complex) -> Complex {
+    clog(z).scale(std::f64::consts::LOG2_E)
+}
+
+pub fn csqrt(z: Complex) -> Complex {
+    if z.im.is_infinite() {
+        return Complex::new(f64::INFINITY, z.im);
+    }
+
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z.re == 0.0 && z.im == 0.0 {
+        return Complex::new(0.0, z.im);
+    }
+
+    if z.re == f64::NEG_INFINITY {
+        return Complex::new(0.0, f64::INFINITY.copysign(z.im));
+    }
+
+    if z.re == f64::INFINITY {
+        return Complex::new(f64::INFINITY, 0.0_f64.copysign(z.im));
+    }
+
+    // Scale down huge inputs so |z| + |re| cannot overflow
+    let scaled = z.re.abs() > 1e300 || z.im.abs() > 1e300;
+    let w = if scaled { z.scale(0.25) } else { z };
+
+    let t = ((w.re.abs() + w.abs()) * 0.5).sqrt();
+    let (re, im) = if w.re >= 0.0 {
+        (t, w.im / (2.0 * t))
+    } else {
+        (w.im.abs() / (2.0 * t), t.copysign(w.im))
+    };
+
+    if scaled {
+        Complex::new(re * 2.0, im * 2.0)
+    } else {
+        Complex::new(re, im)
+    }
+}
+
+pub fn cpow(z: Complex, w: Complex) -> Complex {
+    if z.is_nan() || w.is_nan() {
+        return NAN;
+    }
+
+    if w == Complex::ZERO {
+        return Complex::ONE;
+    }
+
+    if z == Complex::ZERO {
+        if w.im == 0.0 && w.re > 0.0 {
+            return Complex::ZERO;
+        }
+        if w.im == 0.0 && w.re < 0.0 {
+            return Complex::new(f64::INFINITY, 0.0);
+        }
+        return NAN;
+    }
+
+    if w.im == 0.0 && w.re.fract() == 0.0 && w.re.abs() < 1e10 {
+        return complex_integer_power(z, w.re as i64);
+    }
+
+    cexp(w * clog(z))
+}
+
+fn complex_integer_power(z: Complex, n: i64) -> Complex {
+    if n < 0 {
+        return complex_integer_power(z, -n).recip();
+    }
+
+    let mut result = Complex::ONE;
+    let mut base = z;
+    let mut exp = n;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exp /= 2;
+    }
+
+    result
+}
+
+pub fn csquare(z: Complex) -> Complex {
+    z * z
+}
+
+pub fn ccube(z: Complex) -> Complex {
+    z * z * z
+}
+
+pub fn cnth_root(z: Complex, n: i32) -> Complex {
+    if n == 0 {
+        return NAN;
+    }
+
+    if n == 2 {
+        return csqrt(z);
+    }
+
+    if z == Complex::ZERO {
+        return if n > 0 {
+            Complex::ZERO
+        } else {
+            Complex::new(f64::INFINITY, 0.0)
+        };
+    }
+
+    cexp(clog(z) / n as f64)
+}
+
+pub fn ccbrt(z: Complex) -> Complex {
+    cnth_root(z, 3)
+}
+
+pub fn csin(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        return Complex::new(z.re.sin(), z.im);
+    }
+
+    let (s, c) = z.re.sin_cos();
+    Complex::new(s * z.im.cosh(), c * z.im.sinh())
+}
+
+pub fn ccos(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        return Complex::new(z.re.cos(), -z.re.sin() * z.im);
+    }
+
+    let (s, c) = z.re.sin_cos();
+    Complex::new(c * z.im.cosh(), -s * z.im.sinh())
+}
+
+pub fn ctan(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z.im == 0.0 {
+        return Complex::new(z.re.tan(), z.im);
+    }
+
+    // cosh(2y) overflows long before tan(z) stops converging to ±i
+    if z.im.abs() > 20.0 {
+        let re = 4.0 * z.re.sin() * z.re.cos() * (-2.0 * z.im.abs()).exp();
+        return Complex::new(re, 1.0_f64.copysign(z.im));
+    }
+
+    let (s, c) = (2.0 * z.re).sin_cos();
+    let d = c + (2.0 * z.im).cosh();
+    Complex::new(s / d, (2.0 * z.im).sinh() / d)
+}
+
+pub fn ccot(z: Complex) -> Complex {
+    ctan(z).recip()
+}
+
+pub fn ccsc(z: Complex) -> Complex {
+    csin(z).recip()
+}
+
+pub fn csec(z: Complex) -> Complex {
+    ccos(z).recip()
+}
+
+pub fn csinc(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z == Complex::ZERO {
+        return Complex::ONE;
+    }
+
+    csin(z) / z
+}
+
+pub fn csinh(z: Complex) -> Complex {
+    csin(z.mul_i()).mul_neg_i()
+}
+
+pub fn ccosh(z: Complex) -> Complex {
+    ccos(z.mul_i())
+}
+
+pub fn ctanh(z: Complex) -> Complex {
+    ctan(z.mul_i()).mul_neg_i()
+}
+
+pub fn ccoth(z: Complex) -> Complex {
+    ctanh(z).recip()
+}
+
+pub fn ccsch(z: Complex) -> Complex {
+    csinh(z).recip()
+}
+
+pub fn csech(z: Complex) -> Complex {
+    ccosh(z).recip()
+}
+
+// Kahan's formulas; the branch cuts follow the sign of zero in the imaginary part
+pub fn casin(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    let s1 = csqrt(1.0 - z);
+    let s2 = csqrt(1.0 + z);
+
+    Complex::new(
+        z.re.atan2(s1.re * s2.re - s1.im * s2.im),
+        (s1.re * s2.im - s1.im * s2.re).asinh(),
+    )
+}
+
+pub fn cacos(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    let s1 = csqrt(1.0 - z);
+    let s2 = csqrt(1.0 + z);
+
+    Complex::new(
+        2.0 * s1.re.atan2(s2.re),
+        (s2.re * s1.im - s2.im * s1.re).asinh(),
+    )
+}
+
+pub fn catan(z: Complex) -> Complex {
+    catanh(z.mul_i()).mul_neg_i()
+}
+
+pub fn cacot(z: Complex) -> Complex {
+    catan(z.recip())
+}
+
+pub fn cacsc(z: Complex) -> Complex {
+    casin(z.recip())
+}
+
+pub fn casec(z: Complex) -> Complex {
+    cacos(z.recip())
+}
+
+pub fn casinh(z: Complex) -> Complex {
+    casin(z.mul_i()).mul_neg_i()
+}
+
+pub fn cacosh(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    let s1 = csqrt(z - 1.0);
+    let s2 = csqrt(z + 1.0);
+
+    Complex::new(
+        (s1.re * s2.re + s1.im * s2.im).asinh(),
+        2.0 * s1.im.atan2(s2.re),
+    )
+}
+
+pub fn catanh(z: Complex) -> Complex {
+    if z.is_nan() {
+        return NAN;
+    }
+
+    if z.im == 0.0 && z.re.abs() < 1.0 {
+        return Complex::new(z.re.atanh(), z.im);
+    }
+
+    if z.im == 0.0 && z.re.abs() == 1.0 {
+        return Complex::new(f64::INFINITY.copysign(z.re), z.im);
+    }
+
+    // ½ ln((1 + z) / (1 - z)) with the quotient expanded, so nothing cancels
+    // for small |z|
+    let (x, y) = (z.re, z.im);
+    let one_minus_x = 1.0 - x;
+    Complex::new(
+        0.25 * (4.0 * x / (one_minus_x * one_minus_x + y * y)).ln_1p(),
+        0.5 * (2.0 * y).atan2(one_minus_x * (1.0 + x) - y * y),
+    )
+}
+
+pub fn cacoth(z: Complex) -> Complex {
+    catanh(z.recip())
+}
+
+pub fn cacsch(z: Complex) -> Complex {
+    casinh(z.recip())
+}
+
+pub fn casech(z: Complex) -> Complex {
+    cacosh(z.recip())
+}
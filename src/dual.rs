@@ -0,0 +1,697 @@
+use crate::float::Float;
+use crate::lambert_w::{product_log, wright_omega};
+use crate::special::{digamma, erf, erfc, gamma, log_gamma, polygamma, trigamma};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+// Forward-mode automatic differentiation. Both types implement Float, so every
+// generic function of the library accepts them and returns the derivatives of
+// what it computes along with the value. Comparisons only look at the value.
+
+// a + b ε with ε² = 0; seeding b = 1 carries f'(a) in the ε part
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dual<T = f64> {
+    pub value: T,
+    pub derivative: T,
+}
+
+// a + b ε₁ + c ε₂ + d ε₁ε₂ with ε₁² = ε₂² = 0; seeding b = c = 1 carries
+// f'(a) in both ε parts and f''(a) in the ε₁ε₂ part
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HyperDual<T = f64> {
+    pub value: T,
+    pub eps1: T,
+    pub eps2: T,
+    pub eps12: T,
+}
+
+impl<T: Float> Dual<T> {
+    pub const fn new(value: T, derivative: T) -> Self {
+        Self { value, derivative }
+    }
+
+    pub const fn constant(value: T) -> Self {
+        Self::new(value, T::ZERO)
+    }
+
+    pub const fn variable(value: T) -> Self {
+        Self::new(value, T::ONE)
+    }
+
+    fn is_constant(self) -> bool {
+        self.derivative == T::ZERO
+    }
+
+    // f of self from f, f' and f'' at the value
+    // A constant stays constant even where f' is infinite, as at sqrt(0)
+    fn chain(self, value: T, first: T, _second: T) -> Self {
+        if self.is_constant() {
+            return Self::constant(value);
+        }
+        Self::new(value, first * self.derivative)
+    }
+}
+
+impl<T: Float> HyperDual<T> {
+    pub const fn new(value: T, eps1: T, eps2: T, eps12: T) -> Self {
+        Self {
+            value,
+            eps1,
+            eps2,
+            eps12,
+        }
+    }
+
+    pub const fn constant(value: T) -> Self {
+        Self::new(value, T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub const fn variable(value: T) -> Self {
+        Self::new(value, T::ONE, T::ONE, T::ZERO)
+    }
+
+    pub fn derivative(self) -> T {
+        self.eps1
+    }
+
+    pub fn second_derivative(self) -> T {
+        self.eps12
+    }
+
+    fn is_constant(self) -> bool {
+        self.eps1 == T::ZERO && self.eps2 == T::ZERO && self.eps12 == T::ZERO
+    }
+
+    fn chain(self, value: T, first: T, second: T) -> Self {
+        if self.is_constant() {
+            return Self::constant(value);
+        }
+        Self::new(
+            value,
+            first * self.eps1,
+            first * self.eps2,
+            first * self.eps12 + second * self.eps1 * self.eps2,
+        )
+    }
+}
+
+pub fn derivative<T: Float>(f: impl Fn(Dual<T>) -> Dual<T>, x: T) -> T {
+    f(Dual::variable(x)).derivative
+}
+
+pub fn second_derivative<T: Float>(f: impl Fn(HyperDual<T>) -> HyperDual<T>, x: T) -> T {
+    f(HyperDual::variable(x)).eps12
+}
+
+impl<T: Float> fmt::Display for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:+}ε", self.value, self.derivative)
+    }
+}
+
+impl<T: Float> fmt::Display for HyperDual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{:+}ε₁{:+}ε₂{:+}ε₁ε₂",
+            self.value, self.eps1, self.eps2, self.eps12
+        )
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self::new(
+            value,
+            (self.derivative - value * rhs.derivative) / rhs.value,
+        )
+    }
+}
+
+impl<T: Float> Neg for HyperDual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.eps1, -self.eps2, -self.eps12)
+    }
+}
+
+impl<T: Float> Add for HyperDual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.value + rhs.value,
+            self.eps1 + rhs.eps1,
+            self.eps2 + rhs.eps2,
+            self.eps12 + rhs.eps12,
+        )
+    }
+}
+
+impl<T: Float> Sub for HyperDual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.value - rhs.value,
+            self.eps1 - rhs.eps1,
+            self.eps2 - rhs.eps2,
+            self.eps12 - rhs.eps12,
+        )
+    }
+}
+
+impl<T: Float> Mul for HyperDual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.eps1 * rhs.value + self.value * rhs.eps1,
+            self.eps2 * rhs.value + self.value * rhs.eps2,
+            self.eps12 * rhs.value
+                + self.eps1 * rhs.eps2
+                + self.eps2 * rhs.eps1
+                + self.value * rhs.eps12,
+        )
+    }
+}
+
+impl<T: Float> Div for HyperDual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        // Solved term by term from self = quotient * rhs
+        let value = self.value / rhs.value;
+        let eps1 = (self.eps1 - value * rhs.eps1) / rhs.value;
+        let eps2 = (self.eps2 - value * rhs.eps2) / rhs.value;
+        let eps12 =
+            (self.eps12 - value * rhs.eps12 - eps1 * rhs.eps2 - eps2 * rhs.eps1) / rhs.value;
+        Self::new(value, eps1, eps2, eps12)
+    }
+}
+
+// Everything that is written the same way for both types
+macro_rules! impl_dual {
+    ($name:ident) => {
+        impl<T: Float> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                Self::constant(value)
+            }
+        }
+
+        impl<T: Float> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl<T: Float> PartialOrd for $name<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.value.partial_cmp(&other.value)
+            }
+        }
+
+        // x mod y = x - trunc(x / y) y, with the value taken from the exact remainder
+        impl<T: Float> Rem for $name<T> {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                let quotient = (self.value / rhs.value).trunc();
+                let mut remainder = self - rhs * Self::constant(quotient);
+                remainder.value = self.value % rhs.value;
+                remainder
+            }
+        }
+
+        impl<T: Float> Add<T> for $name<T> {
+            type Output = Self;
+
+            fn add(self, rhs: T) -> Self {
+                self + Self::constant(rhs)
+            }
+        }
+
+        impl<T: Float> Sub<T> for $name<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: T) -> Self {
+                self - Self::constant(rhs)
+            }
+        }
+
+        impl<T: Float> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                self * Self::constant(rhs)
+            }
+        }
+
+        impl<T: Float> Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self {
+                self / Self::constant(rhs)
+            }
+        }
+
+        impl<T: Float> AddAssign for $name<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Float> SubAssign for $name<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Float> MulAssign for $name<T> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Float> DivAssign for $name<T> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Float> Sum for $name<T> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, x| acc + x)
+            }
+        }
+
+        impl<T: Float> Product for $name<T> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, x| acc * x)
+            }
+        }
+
+        // Special functions differentiated in closed form rather than through
+        // the series and fits that evaluate them
+        impl<T: Float> $name<T> {
+            pub fn erf(self) -> Self {
+                let first = T::FRAC_2_SQRT_PI * (-self.value * self.value).exp();
+                self.chain(erf(self.value), first, -T::TWO * self.value * first)
+            }
+
+            pub fn erfc(self) -> Self {
+                let first = -T::FRAC_2_SQRT_PI * (-self.value * self.value).exp();
+                self.chain(erfc(self.value), first, -T::TWO * self.value * first)
+            }
+
+            pub fn gamma(self) -> Self {
+                let value = gamma(self.value);
+                let psi = digamma(self.value);
+                let second = value * (psi * psi + trigamma(self.value));
+                self.chain(value, value * psi, second)
+            }
+
+            pub fn log_gamma(self) -> Self {
+                let first = digamma(self.value);
+                self.chain(log_gamma(self.value), first, trigamma(self.value))
+            }
+
+            pub fn digamma(self) -> Self {
+                let first = trigamma(self.value);
+                self.chain(digamma(self.value), first, polygamma(2, self.value))
+            }
+
+            // W' = 1 / (e^W (1 + W)), which unlike W / (z (1 + W)) holds at z = 0
+            pub fn product_log(self) -> Self {
+                let w = product_log(self.value);
+                let scale = T::ONE / (w.exp() * (T::ONE + w));
+                let second = -(w + T::TWO) * scale * scale / (T::ONE + w);
+                self.chain(w, scale, second)
+            }
+
+            pub fn wright_omega(self) -> Self {
+                let omega = wright_omega(self.value);
+                let ratio = T::ONE / (T::ONE + omega);
+                self.chain(omega, omega * ratio, omega * ratio * ratio * ratio)
+            }
+
+            // x^n for a constant n, written so that n = 0 and n = 1 stay finite at x = 0
+            fn power_rule(self, value: T, n: T, power: impl Fn(T) -> T) -> Self {
+                let first = if n == T::ZERO {
+                    T::ZERO
+                } else {
+                    n * power(n - T::ONE)
+                };
+                let second = if n == T::ZERO || n == T::ONE {
+                    T::ZERO
+                } else {
+                    n * (n - T::ONE) * power(n - T::TWO)
+                };
+                self.chain(value, first, second)
+            }
+        }
+
+        impl<T: Float> Float for $name<T> {
+            const ZERO: Self = Self::constant(T::ZERO);
+            const ONE: Self = Self::constant(T::ONE);
+            const TWO: Self = Self::constant(T::TWO);
+            const HALF: Self = Self::constant(T::HALF);
+            const NAN: Self = Self::constant(T::NAN);
+            const INFINITY: Self = Self::constant(T::INFINITY);
+            const NEG_INFINITY: Self = Self::constant(T::NEG_INFINITY);
+            const EPSILON: Self = Self::constant(T::EPSILON);
+            const MIN_POSITIVE: Self = Self::constant(T::MIN_POSITIVE);
+            const MAX: Self = Self::constant(T::MAX);
+            const MANTISSA_DIGITS: u32 = T::MANTISSA_DIGITS;
+
+            const MAX_LN: Self = Self::constant(T::MAX_LN);
+            const MIN_POSITIVE_LN: Self = Self::constant(T::MIN_POSITIVE_LN);
+
+            const PI: Self = Self::constant(T::PI);
+            const TAU: Self = Self::constant(T::TAU);
+            const FRAC_PI_2: Self = Self::constant(T::FRAC_PI_2);
+            const FRAC_PI_4: Self = Self::constant(T::FRAC_PI_4);
+            const FRAC_1_PI: Self = Self::constant(T::FRAC_1_PI);
+            const FRAC_2_PI: Self = Self::constant(T::FRAC_2_PI);
+            const FRAC_2_SQRT_PI: Self = Self::constant(T::FRAC_2_SQRT_PI);
+            const SQRT_2: Self = Self::constant(T::SQRT_2);
+            const FRAC_1_SQRT_2: Self = Self::constant(T::FRAC_1_SQRT_2);
+            const E: Self = Self::constant(T::E);
+            const LN_2: Self = Self::constant(T::LN_2);
+            const LN_10: Self = Self::constant(T::LN_10);
+
+            fn from_f64(x: f64) -> Self {
+                Self::constant(T::from_f64(x))
+            }
+
+            fn to_f64(self) -> f64 {
+                self.value.to_f64()
+            }
+
+            fn is_nan(self) -> bool {
+                self.value.is_nan()
+            }
+
+            fn is_infinite(self) -> bool {
+                self.value.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.value.is_finite()
+            }
+
+            fn is_normal(self) -> bool {
+                self.value.is_normal()
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.value.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.value.is_sign_negative()
+            }
+
+            fn abs(self) -> Self {
+                if self.value.is_sign_negative() {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            fn signum(self) -> Self {
+                Self::constant(self.value.signum())
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                if self.value.is_sign_negative() == sign.value.is_sign_negative() {
+                    self
+                } else {
+                    -self
+                }
+            }
+
+            fn max(self, other: Self) -> Self {
+                if other.value > self.value || self.value.is_nan() {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn min(self, other: Self) -> Self {
+                if other.value < self.value || self.value.is_nan() {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn recip(self) -> Self {
+                let r = self.value.recip();
+                self.chain(r, -r * r, T::TWO * r * r * r)
+            }
+
+            // Keeps the single rounding of the value, which callers rely on to
+            // recover the error of a product
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                let mut result = self * a + b;
+                result.value = self.value.mul_add(a.value, b.value);
+                result
+            }
+
+            fn next_up(self) -> Self {
+                self.chain(self.value.next_up(), T::ONE, T::ZERO)
+            }
+
+            fn next_down(self) -> Self {
+                self.chain(self.value.next_down(), T::ONE, T::ZERO)
+            }
+
+            fn floor(self) -> Self {
+                Self::constant(self.value.floor())
+            }
+
+            fn ceil(self) -> Self {
+                Self::constant(self.value.ceil())
+            }
+
+            fn round(self) -> Self {
+                Self::constant(self.value.round())
+            }
+
+            fn trunc(self) -> Self {
+                Self::constant(self.value.trunc())
+            }
+
+            fn fract(self) -> Self {
+                self.chain(self.value.fract(), T::ONE, T::ZERO)
+            }
+
+            fn sqrt(self) -> Self {
+                let root = self.value.sqrt();
+                let first = T::HALF / root;
+                self.chain(root, first, -T::HALF * first / self.value)
+            }
+
+            fn cbrt(self) -> Self {
+                let root = self.value.cbrt();
+                let first = T::ONE / (T::from_f64(3.0) * root * root);
+                let second = -T::from_f64(2.0 / 3.0) * first / self.value;
+                self.chain(root, first, second)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                let mut result = (self * self + other * other).sqrt();
+                result.value = self.value.hypot(other.value);
+                result
+            }
+
+            fn powf(self, n: Self) -> Self {
+                if n.is_constant() {
+                    let x = self.value;
+                    return self.power_rule(x.powf(n.value), n.value, |k| x.powf(k));
+                }
+
+                let mut result = (self.ln() * n).exp();
+                result.value = self.value.powf(n.value);
+                result
+            }
+
+            fn powi(self, n: i32) -> Self {
+                let x = self.value;
+                let k = T::from_f64(n as f64);
+                self.power_rule(x.powi(n), k, |m| x.powi(m.to_f64() as i32))
+            }
+
+            fn exp(self) -> Self {
+                let value = self.value.exp();
+                self.chain(value, value, value)
+            }
+
+            fn exp2(self) -> Self {
+                let value = self.value.exp2();
+                let first = value * T::LN_2;
+                self.chain(value, first, first * T::LN_2)
+            }
+
+            fn exp_m1(self) -> Self {
+                let first = self.value.exp();
+                self.chain(self.value.exp_m1(), first, first)
+            }
+
+            fn ln(self) -> Self {
+                let r = self.value.recip();
+                self.chain(self.value.ln(), r, -r * r)
+            }
+
+            fn ln_1p(self) -> Self {
+                let r = (T::ONE + self.value).recip();
+                self.chain(self.value.ln_1p(), r, -r * r)
+            }
+
+            fn log2(self) -> Self {
+                let r = self.value.recip();
+                let first = r / T::LN_2;
+                self.chain(self.value.log2(), first, -first * r)
+            }
+
+            fn log10(self) -> Self {
+                let r = self.value.recip();
+                let first = r / T::LN_10;
+                self.chain(self.value.log10(), first, -first * r)
+            }
+
+            fn sin(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(sin, cos, -sin)
+            }
+
+            fn cos(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(cos, -sin, -cos)
+            }
+
+            fn tan(self) -> Self {
+                let tan = self.value.tan();
+                let first = T::ONE + tan * tan;
+                self.chain(tan, first, T::TWO * tan * first)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.value.sin_cos();
+                (self.chain(sin, cos, -sin), self.chain(cos, -sin, -cos))
+            }
+
+            fn asin(self) -> Self {
+                let x = self.value;
+                let first = T::ONE / (T::ONE - x * x).sqrt();
+                self.chain(x.asin(), first, x * first * first * first)
+            }
+
+            fn acos(self) -> Self {
+                let x = self.value;
+                let first = -T::ONE / (T::ONE - x * x).sqrt();
+                self.chain(x.acos(), first, x * first * first * first)
+            }
+
+            fn atan(self) -> Self {
+                let x = self.value;
+                let first = T::ONE / (T::ONE + x * x);
+                self.chain(x.atan(), first, -T::TWO * x * first * first)
+            }
+
+            // The derivatives of atan2 match those of atan(y / x), or of -atan(x / y)
+            // when |x| < |y| so that the quotient stays bounded
+            fn atan2(self, other: Self) -> Self {
+                let mut angle = if other.value.abs() >= self.value.abs() {
+                    (self / other).atan()
+                } else {
+                    -(other / self).atan()
+                };
+                angle.value = self.value.atan2(other.value);
+                angle
+            }
+
+            fn sinh(self) -> Self {
+                let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                self.chain(sinh, cosh, sinh)
+            }
+
+            fn cosh(self) -> Self {
+                let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                self.chain(cosh, sinh, cosh)
+            }
+
+            fn tanh(self) -> Self {
+                let tanh = self.value.tanh();
+                let first = T::ONE - tanh * tanh;
+                self.chain(tanh, first, -T::TWO * tanh * first)
+            }
+
+            fn asinh(self) -> Self {
+                let x = self.value;
+                let first = T::ONE / (x * x + T::ONE).sqrt();
+                self.chain(x.asinh(), first, -x * first * first * first)
+            }
+
+            fn acosh(self) -> Self {
+                let x = self.value;
+                let first = T::ONE / (x * x - T::ONE).sqrt();
+                self.chain(x.acosh(), first, -x * first * first * first)
+            }
+
+            fn atanh(self) -> Self {
+                let x = self.value;
+                let first = T::ONE / (T::ONE - x * x);
+                self.chain(x.atanh(), first, T::TWO * x * first * first)
+            }
+        }
+    };
+}
+
+impl_dual!(Dual);
+impl_dual!(HyperDual);
@@ -1,28 +1,30 @@
-pub fn smoothstep(x: f64) -> f64 {
-    if x <= 0.0 {
-        0.0
-    } else if x >= 1.0 {
-        1.0
+use crate::float::Float;
+
+pub fn smoothstep<T: Float>(x: T) -> T {
+    if x <= T::ZERO {
+        T::ZERO
+    } else if x >= T::ONE {
+        T::ONE
     } else {
-        x * x * (3.0 - 2.0 * x)
+        x * x * (T::from_f64(3.0) - T::TWO * x)
     }
 }
 
-pub fn smootherstep(x: f64) -> f64 {
-    if x <= 0.0 {
-        0.0
-    } else if x >= 1.0 {
-        1.0
+pub fn smootherstep<T: Float>(x: T) -> T {
+    if x <= T::ZERO {
+        T::ZERO
+    } else if x >= T::ONE {
+        T::ONE
     } else {
-        x * x * x * (x * (x * 6.0 - 15.0) + 10.0)
+        x * x * x * (x * (x * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
     }
 }
 
-pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+pub fn lerp<T: Float>(a: T, b: T, t: T) -> T {
     a + t * (b - a)
 }
 
-pub fn clamp(x: f64, min: f64, max: f64) -> f64 {
+pub fn clamp<T: Float>(x: T, min: T, max: T) -> T {
     if x < min {
         min
     } else if x > max {
@@ -0,0 +1,366 @@
+use std::fmt::{Debug, Display};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+// The floating point types the library is generic over. Tolerances and overflow
+// thresholds come from the associated constants, so f32 stops iterating as soon as
+// its own precision is reached instead of running the f64 algorithms to completion
+pub trait Float:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + Product
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const HALF: Self;
+    const NAN: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const EPSILON: Self;
+    const MIN_POSITIVE: Self;
+    const MAX: Self;
+    const MANTISSA_DIGITS: u32;
+
+    // ln(MAX) and ln(MIN_POSITIVE), the range of exp before overflow and underflow
+    const MAX_LN: Self;
+    const MIN_POSITIVE_LN: Self;
+
+    const PI: Self;
+    const TAU: Self;
+    const FRAC_PI_2: Self;
+    const FRAC_PI_4: Self;
+    const FRAC_1_PI: Self;
+    const FRAC_2_PI: Self;
+    const FRAC_2_SQRT_PI: Self;
+    const SQRT_2: Self;
+    const FRAC_1_SQRT_2: Self;
+    const E: Self;
+    const LN_2: Self;
+    const LN_10: Self;
+
+    fn from_f64(x: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    // Converts a tolerance chosen for f64 into the same number of ulps of Self
+    fn tolerance(tol: f64) -> Self {
+        Self::from_f64(tol * (Self::EPSILON.to_f64() / f64::EPSILON))
+    }
+
+    // Picks between thresholds tuned separately for single and double precision
+    fn by_precision(single: f64, double: f64) -> Self {
+        if Self::MANTISSA_DIGITS > f32::MANTISSA_DIGITS {
+            Self::from_f64(double)
+        } else {
+            Self::from_f64(single)
+        }
+    }
+
+    // Number of terms of a fixed expansion needed to reach full precision
+    fn series_terms(single: usize, double: usize) -> usize {
+        if Self::MANTISSA_DIGITS > f32::MANTISSA_DIGITS {
+            double
+        } else {
+            single
+        }
+    }
+
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_normal(self) -> bool;
+    fn is_sign_positive(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn recip(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn atanh(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const HALF: Self = 0.5;
+            const NAN: Self = $t::NAN;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const EPSILON: Self = $t::EPSILON;
+            const MIN_POSITIVE: Self = $t::MIN_POSITIVE;
+            const MAX: Self = $t::MAX;
+            const MANTISSA_DIGITS: u32 = $t::MANTISSA_DIGITS;
+
+            const MAX_LN: Self = ($t::MAX_EXP as $t) * std::$t::consts::LN_2;
+            const MIN_POSITIVE_LN: Self = (($t::MIN_EXP - 1) as $t) * std::$t::consts::LN_2;
+
+            const PI: Self = std::$t::consts::PI;
+            const TAU: Self = std::$t::consts::TAU;
+            const FRAC_PI_2: Self = std::$t::consts::FRAC_PI_2;
+            const FRAC_PI_4: Self = std::$t::consts::FRAC_PI_4;
+            const FRAC_1_PI: Self = std::$t::consts::FRAC_1_PI;
+            const FRAC_2_PI: Self = std::$t::consts::FRAC_2_PI;
+            const FRAC_2_SQRT_PI: Self = std::$t::consts::FRAC_2_SQRT_PI;
+            const SQRT_2: Self = std::$t::consts::SQRT_2;
+            const FRAC_1_SQRT_2: Self = std::$t::consts::FRAC_1_SQRT_2;
+            const E: Self = std::$t::consts::E;
+            const LN_2: Self = std::$t::consts::LN_2;
+            const LN_10: Self = std::$t::consts::LN_10;
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn is_normal(self) -> bool {
+                $t::is_normal(self)
+            }
+
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(self)
+            }
+
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                $t::copysign(self, sign)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn recip(self) -> Self {
+                $t::recip(self)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $t::mul_add(self, a, b)
+            }
+
+            fn next_up(self) -> Self {
+                $t::next_up(self)
+            }
+
+            fn next_down(self) -> Self {
+                $t::next_down(self)
+            }
+
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
+
+            fn fract(self) -> Self {
+                $t::fract(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn cbrt(self) -> Self {
+                $t::cbrt(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn exp2(self) -> Self {
+                $t::exp2(self)
+            }
+
+            fn exp_m1(self) -> Self {
+                $t::exp_m1(self)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+
+            fn log2(self) -> Self {
+                $t::log2(self)
+            }
+
+            fn log10(self) -> Self {
+                $t::log10(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                $t::sin_cos(self)
+            }
+
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn sinh(self) -> Self {
+                $t::sinh(self)
+            }
+
+            fn cosh(self) -> Self {
+                $t::cosh(self)
+            }
+
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+
+            fn asinh(self) -> Self {
+                $t::asinh(self)
+            }
+
+            fn acosh(self) -> Self {
+                $t::acosh(self)
+            }
+
+            fn atanh(self) -> Self {
+                $t::atanh(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
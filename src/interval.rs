@@ -0,0 +1,741 @@
+use crate::float::Float;
+use crate::power::nth_root;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Closed intervals whose operations return an enclosure of every exact result for
+// the points of their arguments. Arithmetic and sqrt are correctly rounded, so a
+// bound only moves outward when its rounding error is nonzero. The remaining
+// functions come from the platform libm, which is taken to be within LIBM_ULPS,
+// or from a few libm calls chained together, within COMPOSED_ULPS
+const LIBM_ULPS: usize = 4;
+const COMPOSED_ULPS: usize = 8;
+
+// Margin, in units of |x / π| + 1, for the error of locating x between multiples of π
+const PI_MARGIN: f64 = 4.0;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Interval<T = f64> {
+    pub lo: T,
+    pub hi: T,
+}
+
+impl<T: Float> Interval<T> {
+    pub const EMPTY: Self = Self {
+        lo: T::NAN,
+        hi: T::NAN,
+    };
+    pub const ENTIRE: Self = Self {
+        lo: T::NEG_INFINITY,
+        hi: T::INFINITY,
+    };
+    pub const ZERO: Self = Self {
+        lo: T::ZERO,
+        hi: T::ZERO,
+    };
+    pub const ONE: Self = Self {
+        lo: T::ONE,
+        hi: T::ONE,
+    };
+
+    // Reversed or NaN bounds give the empty interval
+    pub fn new(lo: T, hi: T) -> Self {
+        if lo.is_nan() || hi.is_nan() || lo > hi || lo == T::INFINITY || hi == T::NEG_INFINITY {
+            return Self::EMPTY;
+        }
+        Self { lo, hi }
+    }
+
+    pub fn point(x: T) -> Self {
+        Self::new(x, x)
+    }
+
+    pub fn pi() -> Self {
+        Self {
+            lo: T::PI.next_down(),
+            hi: T::PI.next_up(),
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan()
+    }
+
+    pub fn is_point(self) -> bool {
+        self.lo == self.hi
+    }
+
+    pub fn contains(self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn is_subset(self, other: Self) -> bool {
+        self.is_empty() || (other.lo <= self.lo && self.hi <= other.hi)
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    pub fn width(self) -> T {
+        if self.is_empty() {
+            return T::NAN;
+        }
+        upper(two_sum(self.hi, -self.lo))
+    }
+
+    pub fn radius(self) -> T {
+        let width = self.width();
+        if width == T::INFINITY {
+            return width;
+        }
+        (width * T::HALF).next_up()
+    }
+
+    pub fn midpoint(self) -> T {
+        if self.is_empty() {
+            return T::NAN;
+        }
+
+        match (self.lo.is_infinite(), self.hi.is_infinite()) {
+            (true, true) => T::ZERO,
+            (true, false) => -T::MAX,
+            (false, true) => T::MAX,
+            // Halving first keeps lo + hi from overflowing
+            (false, false) => self.lo * T::HALF + self.hi * T::HALF,
+        }
+    }
+
+    // Largest and smallest absolute values in the interval
+    pub fn mag(self) -> T {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    pub fn mig(self) -> T {
+        if self.is_empty() {
+            T::NAN
+        } else if self.contains(T::ZERO) {
+            T::ZERO
+        } else {
+            self.lo.abs().min(self.hi.abs())
+        }
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    pub fn hull(self, other: Self) -> Self {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return self;
+        }
+        Self::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.mig(), self.mag())
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(self.lo.min(other.lo), self.hi.min(other.hi))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(self.lo.max(other.lo), self.hi.max(other.hi))
+    }
+
+    // 1/x for an interval holding zero in its interior spans both infinities
+    pub fn recip(self) -> Self {
+        if self.is_empty() || (self.lo == T::ZERO && self.hi == T::ZERO) {
+            Self::EMPTY
+        } else if self.lo < T::ZERO && self.hi > T::ZERO {
+            Self::ENTIRE
+        } else if self.lo == T::ZERO {
+            Self::new(lower(quotient(T::ONE, self.hi)), T::INFINITY)
+        } else if self.hi == T::ZERO {
+            Self::new(T::NEG_INFINITY, upper(quotient(T::ONE, self.lo)))
+        } else {
+            Self::new(
+                lower(quotient(T::ONE, self.hi)),
+                upper(quotient(T::ONE, self.lo)),
+            )
+        }
+    }
+
+    pub fn square(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        let (mig, mag) = (self.mig(), self.mag());
+        Self::new(lower(product(mig, mig)), upper(product(mag, mag)))
+    }
+
+    pub fn cube(self) -> Self {
+        self.powi(3)
+    }
+
+    pub fn sqrt(self) -> Self {
+        let x = self.restrict(T::ZERO, T::INFINITY);
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(lower(root(x.lo)), upper(root(x.hi)))
+    }
+
+    pub fn cbrt(self) -> Self {
+        self.increasing(T::cbrt)
+    }
+
+    pub fn nth_root(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::EMPTY;
+        }
+
+        let k = n.unsigned_abs();
+        let root = match k {
+            1 => self,
+            2 => self.sqrt(),
+            3 => self.cbrt(),
+            _ => {
+                let x = if k.is_multiple_of(2) {
+                    self.restrict(T::ZERO, T::INFINITY)
+                } else {
+                    self
+                };
+                if x.is_empty() {
+                    return Self::EMPTY;
+                }
+
+                // x^(1/k) is computed with 1/k rounded, which is off by a further
+                // |ln x| / 2k ulps of the result
+                let bounds = |x: T| {
+                    let r = nth_root(x, k as i32);
+                    if x == T::ZERO || x.is_infinite() {
+                        return (r, r);
+                    }
+                    let ulps = x.abs().ln().abs() / T::from_f64(2.0 * k as f64) + T::ONE;
+                    let error = r.abs() * ulps * T::EPSILON;
+                    (widen_down(r - error), widen_up(r + error))
+                };
+                Self::new(bounds(x.lo).0, bounds(x.hi).1)
+            }
+        };
+
+        if n < 0 {
+            root.recip()
+        } else {
+            root
+        }
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        match n {
+            0 => Self::ONE,
+            1 => self,
+            2 => self.square(),
+            -1 => self.recip(),
+            _ if n < 0 => self.powi_unsigned(n.unsigned_abs()).recip(),
+            _ => self.powi_unsigned(n as u32),
+        }
+    }
+
+    fn powi_unsigned(self, n: u32) -> Self {
+        let k = T::from_f64(n as f64);
+        if n.is_multiple_of(2) {
+            let (mig, mag) = (self.mig(), self.mag());
+            Self::new(widen_down(mig.powf(k)).max(T::ZERO), widen_up(mag.powf(k)))
+        } else {
+            self.increasing(|x| x.abs().powf(k).copysign(x))
+        }
+    }
+
+    // x^y for x >= 0; y ln x is bilinear in y and ln x, so the extremes are at the corners
+    pub fn powf(self, exponent: Self) -> Self {
+        let base = self.restrict(T::ZERO, T::INFINITY);
+        if base.is_empty() || exponent.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let corners = [
+            base.lo.powf(exponent.lo),
+            base.lo.powf(exponent.hi),
+            base.hi.powf(exponent.lo),
+            base.hi.powf(exponent.hi),
+        ];
+        let lo = corners.iter().fold(T::INFINITY, |acc, &c| acc.min(c));
+        let hi = corners.iter().fold(T::NEG_INFINITY, |acc, &c| acc.max(c));
+        Self::new(widen_down(lo).max(T::ZERO), widen_up(hi))
+    }
+
+    pub fn exp(self) -> Self {
+        self.increasing(T::exp).clamp_below(T::ZERO)
+    }
+
+    pub fn exp2(self) -> Self {
+        self.increasing(T::exp2).clamp_below(T::ZERO)
+    }
+
+    pub fn exp10(self) -> Self {
+        let ten = T::from_f64(10.0);
+        self.increasing(|x| ten.powf(x)).clamp_below(T::ZERO)
+    }
+
+    pub fn ln(self) -> Self {
+        self.restrict(T::ZERO, T::INFINITY).increasing(T::ln)
+    }
+
+    pub fn log2(self) -> Self {
+        self.restrict(T::ZERO, T::INFINITY).increasing(T::log2)
+    }
+
+    pub fn log10(self) -> Self {
+        self.restrict(T::ZERO, T::INFINITY).increasing(T::log10)
+    }
+
+    pub fn log_base(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    pub fn sin(self) -> Self {
+        self.periodic(T::sin, T::HALF)
+    }
+
+    pub fn cos(self) -> Self {
+        self.periodic(T::cos, T::ZERO)
+    }
+
+    // Any interval that may reach a pole at (k + 1/2) π covers the whole line
+    pub fn tan(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        if !self.lo.is_finite() || !self.hi.is_finite() {
+            return Self::ENTIRE;
+        }
+
+        let (first, last) = multiples_of_pi(self.lo, self.hi, T::HALF);
+        if first <= last {
+            return Self::ENTIRE;
+        }
+        self.increasing(T::tan)
+    }
+
+    pub fn asin(self) -> Self {
+        self.restrict(-T::ONE, T::ONE).increasing(T::asin)
+    }
+
+    pub fn acos(self) -> Self {
+        self.restrict(-T::ONE, T::ONE)
+            .decreasing(T::acos)
+            .clamp_below(T::ZERO)
+    }
+
+    pub fn atan(self) -> Self {
+        self.increasing(T::atan)
+    }
+
+    pub fn sinh(self) -> Self {
+        self.increasing(T::sinh)
+    }
+
+    pub fn cosh(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(
+            widen_down(self.mig().cosh()).max(T::ONE),
+            widen_up(self.mag().cosh()),
+        )
+    }
+
+    pub fn tanh(self) -> Self {
+        self.increasing(T::tanh).clamp(-T::ONE, T::ONE)
+    }
+
+    pub fn asinh(self) -> Self {
+        self.increasing_within(T::asinh, COMPOSED_ULPS)
+    }
+
+    pub fn acosh(self) -> Self {
+        self.restrict(T::ONE, T::INFINITY)
+            .increasing_within(acosh, COMPOSED_ULPS)
+            .clamp_below(T::ZERO)
+    }
+
+    // The std atanh goes through ln_1p(2x / (1 - x)), which loses precision close to
+    // -1 but not close to 1
+    pub fn atanh(self) -> Self {
+        self.restrict(-T::ONE, T::ONE)
+            .increasing_within(|x| x.abs().atanh().copysign(x), COMPOSED_ULPS)
+    }
+
+    fn restrict(self, lo: T, hi: T) -> Self {
+        self.intersection(Self { lo, hi })
+    }
+
+    fn clamp_below(self, lo: T) -> Self {
+        self.clamp(lo, T::INFINITY)
+    }
+
+    fn clamp(self, lo: T, hi: T) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(self.lo.max(lo), self.hi.min(hi))
+    }
+
+    fn increasing(self, f: impl Fn(T) -> T) -> Self {
+        self.increasing_within(f, LIBM_ULPS)
+    }
+
+    fn increasing_within(self, f: impl Fn(T) -> T, ulps: usize) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(
+            widen_down_by(f(self.lo), ulps),
+            widen_up_by(f(self.hi), ulps),
+        )
+    }
+
+    fn decreasing(self, f: impl Fn(T) -> T) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(widen_down(f(self.hi)), widen_up(f(self.lo)))
+    }
+
+    // sin and cos between their extremes at (k + offset) π, a maximum for even k
+    fn periodic(self, f: impl Fn(T) -> T, offset: T) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let full = Self::new(-T::ONE, T::ONE);
+        if !self.lo.is_finite() || !self.hi.is_finite() {
+            return full;
+        }
+
+        let (first, last) = multiples_of_pi(self.lo, self.hi, offset);
+        if last - first >= T::ONE {
+            return full;
+        }
+
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut lo = widen_down(a.min(b));
+        let mut hi = widen_up(a.max(b));
+
+        if first == last {
+            if first % T::TWO == T::ZERO {
+                hi = T::ONE;
+            } else {
+                lo = -T::ONE;
+            }
+        }
+
+        Self::new(lo, hi).clamp(-T::ONE, T::ONE)
+    }
+}
+
+// Integers k for which (k + offset) π may lie in [lo, hi]. The quotient by the
+// rounded π is off by a few ulps, so points that close to an end count as inside
+fn multiples_of_pi<T: Float>(lo: T, hi: T, offset: T) -> (T, T) {
+    let k_lo = lo / T::PI - offset;
+    let k_hi = hi / T::PI - offset;
+    let margin = |k: T| (k.abs() + T::ONE) * T::from_f64(PI_MARGIN) * T::EPSILON;
+    ((k_lo - margin(k_lo)).ceil(), (k_hi + margin(k_hi)).floor())
+}
+
+// ln(1 + t + √(t (t + 2))) with t = x - 1 exact near 1, where the std
+// ln(x + √(x² - 1)) cancels
+fn acosh<T: Float>(x: T) -> T {
+    if x > T::EPSILON.sqrt().recip() {
+        return x.ln() + T::LN_2;
+    }
+    let t = x - T::ONE;
+    (t + (t * (t + T::TWO)).sqrt()).ln_1p()
+}
+
+fn widen_down<T: Float>(x: T) -> T {
+    widen_down_by(x, LIBM_ULPS)
+}
+
+fn widen_up<T: Float>(x: T) -> T {
+    widen_up_by(x, LIBM_ULPS)
+}
+
+fn widen_down_by<T: Float>(x: T, ulps: usize) -> T {
+    (0..ulps).fold(x, |x, _| x.next_down())
+}
+
+fn widen_up_by<T: Float>(x: T, ulps: usize) -> T {
+    (0..ulps).fold(x, |x, _| x.next_up())
+}
+
+// Rounded result and the sign of the exact value minus it. A NaN error stands for
+// an unknown one, after overflow or underflow, and widens both bounds
+fn lower<T: Float>((result, error): (T, T)) -> T {
+    if error >= T::ZERO {
+        result
+    } else {
+        result.next_down()
+    }
+}
+
+fn upper<T: Float>((result, error): (T, T)) -> T {
+    if error <= T::ZERO {
+        result
+    } else {
+        result.next_up()
+    }
+}
+
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let t = s - a;
+    (s, (a - (s - t)) + (b - t))
+}
+
+// 0 · ∞ is taken as 0, its value at the ends of an interval
+fn product<T: Float>(a: T, b: T) -> (T, T) {
+    if a == T::ZERO || b == T::ZERO {
+        return (T::ZERO, T::ZERO);
+    }
+    let p = a * b;
+    if p.abs() < T::MIN_POSITIVE {
+        return (p, T::NAN);
+    }
+    (p, a.mul_add(b, -p))
+}
+
+fn quotient<T: Float>(a: T, b: T) -> (T, T) {
+    let q = a / b;
+    if a == T::ZERO || (b.is_infinite() && a.is_finite()) {
+        return (q, T::ZERO);
+    }
+    if q.abs() < T::MIN_POSITIVE {
+        return (q, T::NAN);
+    }
+
+    // a - q b is exact, and the quotient is off by it over b
+    let r = (-q).mul_add(b, a);
+    if r.is_nan() || r == T::ZERO {
+        return (q, r);
+    }
+    (
+        q,
+        if (r > T::ZERO) == (b > T::ZERO) {
+            T::ONE
+        } else {
+            -T::ONE
+        },
+    )
+}
+
+fn root<T: Float>(x: T) -> (T, T) {
+    let s = x.sqrt();
+    if x != T::ZERO && x < T::MIN_POSITIVE {
+        return (s, T::NAN);
+    }
+    // x - s² has the sign of √x - s
+    (s, (-s).mul_add(s, x))
+}
+
+impl<T: Float> PartialEq for Interval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.is_empty() && other.is_empty()) || (self.lo == other.lo && self.hi == other.hi)
+    }
+}
+
+impl<T: Float> From<T> for Interval<T> {
+    fn from(x: T) -> Self {
+        Self::point(x)
+    }
+}
+
+impl<T: Float> From<(T, T)> for Interval<T> {
+    fn from((lo, hi): (T, T)) -> Self {
+        Self::new(lo, hi)
+    }
+}
+
+impl<T: Float> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "∅")
+        } else {
+            write!(f, "[{}, {}]", self.lo, self.hi)
+        }
+    }
+}
+
+impl<T: Float> Neg for Interval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<T: Float> Add for Interval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::EMPTY;
+        }
+        Self::new(
+            lower(two_sum(self.lo, rhs.lo)),
+            upper(two_sum(self.hi, rhs.hi)),
+        )
+    }
+}
+
+impl<T: Float> Sub for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Float> Mul for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let ends = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        let lo = ends
+            .iter()
+            .fold(T::INFINITY, |acc, &(a, b)| acc.min(lower(product(a, b))));
+        let hi = ends.iter().fold(T::NEG_INFINITY, |acc, &(a, b)| {
+            acc.max(upper(product(a, b)))
+        });
+        Self::new(lo, hi)
+    }
+}
+
+impl<T: Float> Div for Interval<T> {
+    type Output = Self;
+
+    // A divisor holding zero goes through recip, giving a half-line or the whole
+    // line, which is where 1/x and tan jump
+    fn div(self, rhs: Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::EMPTY;
+        }
+        if rhs.contains(T::ZERO) {
+            return self * rhs.recip();
+        }
+
+        // ∞/∞ ends give NaN, which min and max skip; the other ends bound the range
+        let ends = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        let lo = ends
+            .iter()
+            .fold(T::INFINITY, |acc, &(a, b)| acc.min(lower(quotient(a, b))));
+        let hi = ends.iter().fold(T::NEG_INFINITY, |acc, &(a, b)| {
+            acc.max(upper(quotient(a, b)))
+        });
+        Self::new(lo, hi)
+    }
+}
+
+impl<T: Float> Add<T> for Interval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self {
+        self + Self::point(rhs)
+    }
+}
+
+impl<T: Float> Sub<T> for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self {
+        self - Self::point(rhs)
+    }
+}
+
+impl<T: Float> Mul<T> for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self * Self::point(rhs)
+    }
+}
+
+impl<T: Float> Div<T> for Interval<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        self / Self::point(rhs)
+    }
+}
+
+impl<T: Float> AddAssign for Interval<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> SubAssign for Interval<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Float> MulAssign for Interval<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> DivAssign for Interval<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Float> std::iter::Sum for Interval<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<T: Float> std::iter::Product for Interval<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
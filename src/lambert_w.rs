@@ -1,25 +1,38 @@
-fn product_log_branch_minus1(z: f64) -> f64 {
-    if z >= 0.0 || z <= -1.0 / std::f64::consts::E {
-        return f64::NAN;
+use crate::complex::{cexp, clog, csqrt, Complex};
+use crate::float::Float;
+use std::f64::consts::{E, PI};
+
+fn product_log_branch_minus1<T: Float>(z: T) -> T {
+    if z >= T::ZERO || z <= -T::E.recip() {
+        return T::NAN;
     }
 
-    let p = (std::f64::consts::E * z + 1.0).sqrt();
-    let mut w = -1.0 + p - p * p / 3.0 + 11.0 * p * p * p / 72.0;
+    let mut w = if z < T::from_f64(-0.25) {
+        let p = -(T::TWO * (T::E * z + T::ONE)).sqrt();
+        -T::ONE + p - p * p / T::from_f64(3.0) + T::from_f64(11.0) * p * p * p / T::from_f64(72.0)
+    } else {
+        let l1 = (-z).ln();
+        let l2 = (-l1).ln();
+        l1 - l2 + l2 / l1
+    };
 
     const MAX_ITERS: usize = 20;
-    const EPSILON: f64 = 1e-15;
+    let epsilon = T::tolerance(1e-15);
+
+    // Halley on w + t with t = -z e^(-w) formed as e^(ln(-z) - w), so neither
+    // e^w nor the derivatives underflow for tiny |z|
+    let log_z = (-z).ln();
 
     for _ in 0..MAX_ITERS {
-        let ew = w.exp();
-        let wew = w * ew;
-        let f = wew - z;
-        let f1 = ew * (w + 1.0);
-        let f2 = ew * (w + 2.0);
+        let t = (log_z - w).exp();
+        let f = w + t;
+        let f1 = T::ONE - t;
+        let f2 = t;
 
-        let delta = f * f1 / (f1 * f1 - 0.5 * f * f2);
+        let delta = T::TWO * f * f1 / (T::TWO * f1 * f1 - f * f2);
         w -= delta;
 
-        if delta.abs() < EPSILON * (1.0 + w.abs()) {
+        if delta.abs() < epsilon * (T::ONE + w.abs()) {
             break;
         }
     }
@@ -27,51 +40,53 @@ fn product_log_branch_minus1(z: f64) -> f64 {
     w
 }
 
-pub fn product_log(z: f64) -> f64 {
+pub fn product_log<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
     if z.is_infinite() {
         return if z.is_sign_positive() {
-            f64::INFINITY
+            T::INFINITY
         } else {
-            f64::NAN
+            T::NAN
         };
     }
 
-    let branch_point = -1.0 / std::f64::consts::E;
+    let branch_point = -T::ONE / T::E;
     if z < branch_point {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 0.0;
+    if z == T::ZERO {
+        return T::ZERO;
     }
 
     if z == branch_point {
-        return -1.0;
+        return -T::ONE;
     }
 
-    let mut w: f64;
+    let mut w: T;
 
-    if z < 0.0 {
-        let p = (2.0 * (std::f64::consts::E * z + 1.0)).sqrt();
-        w = -1.0 + p - p * p / 3.0 + 11.0 * p * p * p / 72.0;
-    } else if z < 0.1 {
+    if z < T::ZERO {
+        let p = (T::TWO * (T::E * z + T::ONE)).sqrt();
+        w = -T::ONE + p - p * p / T::from_f64(3.0)
+            + T::from_f64(11.0) * p * p * p / T::from_f64(72.0);
+    } else if z < T::from_f64(0.1) {
         let z2 = z * z;
         let z3 = z2 * z;
         let z4 = z3 * z;
         let z5 = z4 * z;
-        w = z - z2 + 1.5 * z3 - 8.0 / 3.0 * z4 + 125.0 / 24.0 * z5;
-    } else if z < 3.0 {
-        w = z / (1.0 + z);
+        w = z - z2 + T::from_f64(1.5) * z3 - T::from_f64(8.0 / 3.0) * z4
+            + T::from_f64(125.0 / 24.0) * z5;
+    } else if z < T::from_f64(3.0) {
+        w = z / (T::ONE + z);
 
         for _ in 0..3 {
             let ew = w.exp();
             let wew = w * ew;
             let f = wew - z;
-            let df = ew * (w + 1.0);
+            let df = ew * (w + T::ONE);
             w -= f / df;
         }
     } else {
@@ -81,49 +96,267 @@ pub fn product_log(z: f64) -> f64 {
     }
 
     const MAX_ITERS: usize = 50;
-    const EPSILON: f64 = 1e-15;
+    let epsilon = T::tolerance(1e-15);
 
     for _ in 0..MAX_ITERS {
         let ew = w.exp();
         let wew = w * ew;
         let f = wew - z;
 
-        if f.abs() < EPSILON * (1.0 + z.abs()) {
+        if f.abs() < epsilon * (T::ONE + z.abs()) {
             break;
         }
 
-        let f1 = ew * (w + 1.0);
+        let f1 = ew * (w + T::ONE);
 
-        if f1.abs() < 1e-15 {
+        if f1.abs() < epsilon {
             break;
         }
 
-        let f2 = ew * (w + 2.0);
+        // Halley's step through f/f' and f''/f' = (w + 2)/(w + 1), which stay in
+        // range for large z where f'² overflows
+        let newton = f / f1;
+        let correction = T::ONE - T::HALF * newton * (w + T::TWO) / (w + T::ONE);
+        let delta = if correction.abs() > epsilon {
+            newton / correction
+        } else {
+            newton
+        };
+
+        w -= delta;
 
-        let denominator = f1 * f1 - 0.5 * f * f2;
-        let delta = if denominator.abs() > 1e-15 {
-            f * f1 / denominator
+        if delta.abs() < epsilon * (T::ONE + w.abs()) {
+            break;
+        }
+    }
+
+    w
+}
+pub fn product_log_k(k: i32, z: impl Into<Complex>) -> Complex {
+    let z = z.into();
+
+    if z.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let two_pi_k = 2.0 * PI * k as f64;
+
+    if z.re == f64::INFINITY {
+        return Complex::new(z.re, z.im + two_pi_k);
+    }
+
+    if z.re == f64::NEG_INFINITY {
+        return Complex::new(-z.re, -z.im + two_pi_k + PI);
+    }
+
+    if z == Complex::ZERO {
+        return if k == 0 {
+            Complex::ZERO
         } else {
+            Complex::new(f64::NEG_INFINITY, 0.0)
+        };
+    }
+
+    // Stay on the real line where the branch is real-valued
+    if z.im == 0.0 {
+        if k == 0 && z.re >= -1.0 / E {
+            return Complex::from(product_log(z.re));
+        }
+
+        if k == -1 && z.re >= -1.0 / E && z.re < 0.0 && z.im.is_sign_positive() {
+            let w = if z.re == -1.0 / E {
+                -1.0
+            } else {
+                product_log_branch_minus1(z.re)
+            };
+            return Complex::from(w);
+        }
+    }
+
+    let w = product_log_k_initial_guess(k, z, two_pi_k);
+    product_log_k_refine(z, w)
+}
+
+pub fn cproduct_log(z: Complex) -> Complex {
+    product_log_k(0, z)
+}
+
+fn product_log_k_initial_guess(k: i32, z: Complex, two_pi_k: f64) -> Complex {
+    let near_branch_point = (z + 1.0 / E).abs() < 0.3;
+
+    // The branches 0, -1 (from above) and 1 (from below) meet at -1/e
+    let p = csqrt(2.0 * (E * z + 1.0));
+    let branch_series = |p: Complex| -1.0 + p - p * p / 3.0 + 11.0 / 72.0 * p * p * p;
+
+    if k == 0 && near_branch_point {
+        return branch_series(p);
+    }
+
+    if k == -1 && near_branch_point && z.im.is_sign_positive() {
+        return branch_series(-p);
+    }
+
+    if k == 1 && near_branch_point && z.im.is_sign_negative() {
+        return branch_series(-p);
+    }
+
+    if k == 0 && z.abs() < 3.0 && (1.0 + z).abs() > 0.5 {
+        // Winitzki's approximation
+        let l = clog(1.0 + z);
+        return l * (1.0 - clog(1.0 + l) / (2.0 + l));
+    }
+
+    let l1 = clog(z) + Complex::new(0.0, two_pi_k);
+    let l2 = clog(l1);
+    l1 - l2 + l2 / l1
+}
+
+// Halley iteration on w - z * e^(-w), which stays finite when w * e^w would overflow
+fn product_log_k_refine(z: Complex, mut w: Complex) -> Complex {
+    const MAX_ITERS: usize = 100;
+    const EPSILON: f64 = 1e-15;
+
+    let log_z = clog(z);
+
+    for _ in 0..MAX_ITERS {
+        let t = cexp(log_z - w);
+        let f = w - t;
+        let f1 = 1.0 + t;
+        let f2 = -t;
+
+        let denominator = 2.0 * f1 * f1 - f * f2;
+        let delta = if denominator == Complex::ZERO {
             f / f1
+        } else {
+            2.0 * f * f1 / denominator
         };
 
+        if !delta.is_finite() {
+            break;
+        }
+
+        w -= delta;
+
+        if delta.abs() <= EPSILON * (1.0 + w.abs()) {
+            break;
+        }
+    }
+
+    w
+}
+
+pub fn wright_omega<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::INFINITY
+        } else {
+            T::ZERO
+        };
+    }
+
+    // ω(x) = e^x - e^2x + ..., the correction is below machine precision here
+    if x < -T::by_precision(18.0, 40.0) {
+        return x.exp();
+    }
+
+    let mut w = if x < T::by_precision(80.0, 500.0) {
+        product_log(x.exp())
+    } else {
+        x - x.ln()
+    };
+
+    const MAX_ITERS: usize = 20;
+    let epsilon = T::tolerance(1e-15);
+
+    for _ in 0..MAX_ITERS {
+        let delta = (w + w.ln() - x) * w / (T::ONE + w);
         w -= delta;
 
-        if delta.abs() < EPSILON * (1.0 + w.abs()) {
+        if delta.abs() <= epsilon * w.abs() {
             break;
         }
     }
 
     w
 }
-pub fn product_log_k(k: i32, z: f64) -> f64 {
-    if k == 0 {
-        return product_log(z);
+
+pub fn cwright_omega(z: Complex) -> Complex {
+    if z.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    if z.im == 0.0 {
+        return Complex::new(wright_omega(z.re), z.im);
+    }
+
+    // Singular points of the defining equation ω + ln ω = z
+    if z.re == -1.0 && z.im.abs() == PI {
+        return Complex::from(-1.0);
+    }
+
+    // For Re z < -1 the lines Im z = ±π map onto the real segment (-1/e, 0) of
+    // e^z, where the unwinding number picks W_0 for π and W_-1 for -π. Both are
+    // real, and at -π neither solves ω + ln ω = z.
+    if z.im.abs() == PI && z.re < -1.0 {
+        let x = -z.re.exp();
+        if z.im > 0.0 {
+            return Complex::new(product_log(x), 0.0);
+        }
+        if x != 0.0 {
+            return Complex::new(product_log_k(-1, x).re, 0.0);
+        }
+
+        // Once e^t underflows, Newton on w + ln(-w) = t from w = t - ln(-t)
+        let t = z.re;
+        let mut w = t - (-t).ln();
+        for _ in 0..4 {
+            w -= (w + (-w).ln() - t) * w / (w + 1.0);
+        }
+        return Complex::new(w, 0.0);
+    }
+
+    // ω(conj z) = conj ω(z) everywhere else, including Im z = -π for Re z > -1
+    // where W_-1 meets the conjugate of W_0 along the cut, so only the upper
+    // half plane needs the unwinding number
+    if z.im < 0.0 {
+        return cwright_omega(z.conj()).conj();
+    }
+
+    if z.re.abs() < 500.0 {
+        let k = ((z.im - PI) / (2.0 * PI)).ceil() as i32;
+        let w = product_log_k(k, cexp(z));
+        if w.is_finite() && w != Complex::ZERO {
+            return w;
+        }
     }
 
-    if k == -1 && z < 0.0 && z > -1.0 / std::f64::consts::E {
-        return product_log_branch_minus1(z);
+    // e^z only approximates ω in the principal strip -π < Im z <= π, elsewhere
+    // ω tracks z - ln z
+    let mut w = if z.re > 0.0 || z.im <= -PI || z.im > PI {
+        z - clog(z)
+    } else {
+        cexp(z)
+    };
+
+    const MAX_ITERS: usize = 50;
+    const EPSILON: f64 = 1e-15;
+
+    for _ in 0..MAX_ITERS {
+        let delta = (w + clog(w) - z) * w / (1.0 + w);
+        if !delta.is_finite() {
+            break;
+        }
+
+        w -= delta;
+
+        if delta.abs() <= EPSILON * w.abs() {
+            break;
+        }
     }
 
-    f64::NAN
+    w
 }
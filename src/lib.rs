@@ -1,13 +1,22 @@
 pub mod aggregate;
 pub mod animation;
+pub mod bigint;
+pub mod combinatorics;
+pub mod complex;
+pub mod dual;
 pub mod easing;
+pub mod float;
 pub mod geometry;
 pub mod hyperbolic;
+pub mod interval;
 pub mod lambert_w;
 pub mod logarithmic;
+pub mod modint;
 pub mod noise;
 pub mod number_theory;
+pub mod orthogonal_polynomials;
 pub mod power;
+pub mod rational;
 pub mod rounding;
 pub mod sequences;
 pub mod special;
@@ -16,14 +25,23 @@ pub mod waves;
 
 pub use aggregate::*;
 pub use animation::*;
+pub use bigint::*;
+pub use combinatorics::*;
+pub use complex::*;
+pub use dual::*;
 pub use easing::*;
+pub use float::*;
 pub use geometry::*;
 pub use hyperbolic::*;
+pub use interval::*;
 pub use lambert_w::*;
 pub use logarithmic::*;
+pub use modint::*;
 pub use noise::*;
 pub use number_theory::*;
+pub use orthogonal_polynomials::*;
 pub use power::*;
+pub use rational::*;
 pub use rounding::*;
 pub use sequences::*;
 pub use special::*;
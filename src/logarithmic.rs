@@ -1,127 +1,129 @@
-pub fn log(z: f64) -> f64 {
-    if z.is_nan() {
-        return f64::NAN;
-    }
-
-    if z == 0.0 {
-        return f64::NEG_INFINITY;
-    }
-
-    if z < 0.0 {
-        return f64::NAN;
-    }
-
-    if z == 1.0 {
-        return 0.0;
-    }
-
-    if z.is_infinite() && z.is_sign_positive() {
-        return f64::INFINITY;
-    }
-
-    z.ln()
-}
-
-pub fn log_base(a: f64, z: f64) -> f64 {
-    if a.is_nan() || z.is_nan() {
-        return f64::NAN;
-    }
-
-    if a == 1.0 {
-        return f64::NAN;
-    }
-
-    if a <= 0.0 {
-        return f64::NAN;
-    }
-
-    if z == 0.0 {
-        return f64::NEG_INFINITY;
-    }
-
-    if z < 0.0 {
-        return f64::NAN;
-    }
-
-    if z == 1.0 {
-        return 0.0;
-    }
-
-    if z == a {
-        return 1.0;
-    }
-
-    if z.is_infinite() && z.is_sign_positive() {
-        if a > 1.0 {
-            return f64::INFINITY;
-        } else if a < 1.0 && a > 0.0 {
-            return f64::NEG_INFINITY;
-        }
-    }
-
-    if a.is_infinite() && a.is_sign_positive() {
-        if z > 1.0 {
-            return 0.0;
-        } else if z < 1.0 {
-            return -0.0;
-        }
-    }
-
-    z.ln() / a.ln()
-}
-
-pub fn log10(z: f64) -> f64 {
-    if z.is_nan() {
-        return f64::NAN;
-    }
-
-    if z == 0.0 {
-        return f64::NEG_INFINITY;
-    }
-
-    if z < 0.0 {
-        return f64::NAN;
-    }
-
-    if z == 1.0 {
-        return 0.0;
-    }
-
-    if z == 10.0 {
-        return 1.0;
-    }
-
-    if z.is_infinite() && z.is_sign_positive() {
-        return f64::INFINITY;
-    }
-
-    z.log10()
-}
-
-pub fn log2(z: f64) -> f64 {
-    if z.is_nan() {
-        return f64::NAN;
-    }
-
-    if z == 0.0 {
-        return f64::NEG_INFINITY;
-    }
-
-    if z < 0.0 {
-        return f64::NAN;
-    }
-
-    if z == 1.0 {
-        return 0.0;
-    }
-
-    if z == 2.0 {
-        return 1.0;
-    }
-
-    if z.is_infinite() && z.is_sign_positive() {
-        return f64::INFINITY;
-    }
-
-    z.log2()
-}
+use crate::float::Float;
+
+pub fn log<T: Float>(z: T) -> T {
+    if z.is_nan() {
+        return T::NAN;
+    }
+
+    if z == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if z < T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ONE {
+        return T::ZERO;
+    }
+
+    if z.is_infinite() && z.is_sign_positive() {
+        return T::INFINITY;
+    }
+
+    z.ln()
+}
+
+pub fn log_base<T: Float>(a: T, z: T) -> T {
+    if a.is_nan() || z.is_nan() {
+        return T::NAN;
+    }
+
+    if a == T::ONE {
+        return T::NAN;
+    }
+
+    if a <= T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if z < T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ONE {
+        return T::ZERO;
+    }
+
+    if z == a {
+        return T::ONE;
+    }
+
+    if z.is_infinite() && z.is_sign_positive() {
+        if a > T::ONE {
+            return T::INFINITY;
+        } else if a < T::ONE && a > T::ZERO {
+            return T::NEG_INFINITY;
+        }
+    }
+
+    if a.is_infinite() && a.is_sign_positive() {
+        if z > T::ONE {
+            return T::ZERO;
+        } else if z < T::ONE {
+            return -T::ZERO;
+        }
+    }
+
+    z.ln() / a.ln()
+}
+
+pub fn log10<T: Float>(z: T) -> T {
+    if z.is_nan() {
+        return T::NAN;
+    }
+
+    if z == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if z < T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ONE {
+        return T::ZERO;
+    }
+
+    if z == T::from_f64(10.0) {
+        return T::ONE;
+    }
+
+    if z.is_infinite() && z.is_sign_positive() {
+        return T::INFINITY;
+    }
+
+    z.log10()
+}
+
+pub fn log2<T: Float>(z: T) -> T {
+    if z.is_nan() {
+        return T::NAN;
+    }
+
+    if z == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if z < T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ONE {
+        return T::ZERO;
+    }
+
+    if z == T::TWO {
+        return T::ONE;
+    }
+
+    if z.is_infinite() && z.is_sign_positive() {
+        return T::INFINITY;
+    }
+
+    z.log2()
+}
@@ -0,0 +1,187 @@
+use crate::number_theory::{mod_mul, mod_pow};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// An integer modulo M, always kept reduced to [0, M). Products go through u128
+// so every modulus up to u64::MAX works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    pub const MODULUS: u64 = M;
+
+    pub const fn new(value: u64) -> Self {
+        const { assert!(M > 0, "Modulus must be positive") };
+        Self { value: value % M }
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(self, exp: u64) -> Self {
+        Self::new(mod_pow(self.value, exp, M))
+    }
+
+    // None when the value shares a factor with M
+    pub fn inverse(self) -> Option<Self> {
+        // Extended Euclid in i128, as M may not fit in i64
+        let (mut old_r, mut r) = (self.value as i128, M as i128);
+        let (mut old_x, mut x) = (1i128, 0i128);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_x, x) = (x, old_x - q * x);
+        }
+
+        if old_r != 1 {
+            return None;
+        }
+
+        Some(Self::new(old_x.rem_euclid(M as i128) as u64))
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        let reduced = Self::new(value.unsigned_abs());
+        if value < 0 {
+            -reduced
+        } else {
+            reduced
+        }
+    }
+}
+
+impl<const M: u64> From<u32> for ModInt<M> {
+    fn from(value: u32) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl<const M: u64> From<i32> for ModInt<M> {
+    fn from(value: i32) -> Self {
+        Self::from(value as i64)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.value == 0 {
+            self
+        } else {
+            Self {
+                value: M - self.value,
+            }
+        }
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (sum, overflow) = self.value.overflowing_add(rhs.value);
+        let value = if overflow || sum >= M {
+            sum.wrapping_sub(M)
+        } else {
+            sum
+        };
+        Self { value }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let value = if self.value >= rhs.value {
+            self.value - rhs.value
+        } else {
+            M - (rhs.value - self.value)
+        };
+        Self { value }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: mod_mul(self.value, rhs.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        match rhs.inverse() {
+            Some(inv) => Self {
+                value: mod_mul(self.value, inv.value, M),
+            },
+            None => panic!("{} is not invertible modulo {}", rhs.value, M),
+        }
+    }
+}
+
+// The forms with a u64 operand reduce it first and go through the ones above
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<const M: u64> $trait<u64> for ModInt<M> {
+            type Output = Self;
+
+            fn $method(self, rhs: u64) -> Self {
+                self.$method(Self::new(rhs))
+            }
+        }
+
+        impl<const M: u64> $assign_trait for ModInt<M> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+
+        impl<const M: u64> $assign_trait<u64> for ModInt<M> {
+            fn $assign_method(&mut self, rhs: u64) {
+                *self = self.$method(Self::new(rhs));
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add, AddAssign, add_assign);
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign);
+impl_scalar_op!(Div, div, DivAssign, div_assign);
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), |acc, x| acc + x)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |acc, x| acc * x)
+    }
+}
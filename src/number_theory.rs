@@ -1,3 +1,8 @@
+use crate::bigint::BigInt;
+use std::collections::HashMap;
+use std::ops::{Bound, Mul, RangeBounds, Sub};
+use std::sync::{RwLock, RwLockReadGuard};
+
 pub fn gcd(a: i64, b: i64) -> i64 {
     let mut x = a.abs();
     let mut y = b.abs();
@@ -44,85 +49,405 @@ pub fn lcm_multiple(numbers: &[i64]) -> i64 {
     numbers.iter().fold(numbers[0], |acc, &x| lcm(acc, x))
 }
 
-fn is_prime_small(n: u64) -> bool {
+pub fn gcd_big(a: &BigInt, b: &BigInt) -> BigInt {
+    a.gcd(b)
+}
+
+pub fn lcm_big(a: &BigInt, b: &BigInt) -> BigInt {
+    a.lcm(b)
+}
+
+// (g, x, y) with a x + b y = g = gcd(a, b)
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+// Inverse of a modulo m in [0, m), None when a and m are not coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        panic!("Modulus must be positive");
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m))
+}
+
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+pub fn mod_pow(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// Solves x ≡ r (mod m) for every (r, m) pair, moduli need not be coprime.
+// Returns (x, lcm of the moduli) with 0 <= x < lcm, or None when the system
+// has no solution or the lcm overflows i64.
+pub fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(r, m) in congruences {
+        if m <= 0 {
+            panic!("Modulus must be positive");
+        }
+
+        let m = m as i128;
+        let r = (r as i128).rem_euclid(m);
+
+        // x + modulus k ≡ r (mod m) is solvable iff g divides r - x
+        let (g, p, _) = extended_gcd(modulus as i64, m as i64);
+        let g = g as i128;
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let step = m / g;
+        let k = (diff / g % step * p as i128).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        if modulus > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(modulus);
+    }
+
+    Some((x as i64, modulus as i64))
+}
+
+// One strong probable prime round for odd n with n - 1 = d 2^s
+fn miller_rabin_round(n: u64, d: u64, s: u32, a: u64) -> bool {
+    let mut x = mod_pow(a, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = mod_mul(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn is_prime_u64(n: u64) -> bool {
     if n < 2 {
         return false;
     }
-    if n == 2 {
+    for p in MILLER_RABIN_BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    if n < 41 * 41 {
         return true;
     }
-    if n % 2 == 0 {
-        return false;
+
+    // The first twelve primes as bases are deterministic for n < 3.3e24
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    MILLER_RABIN_BASES
+        .iter()
+        .all(|&a| miller_rabin_round(n, d, s, a))
+}
+
+pub fn is_prime(n: i64) -> bool {
+    n > 1 && is_prime_u64(n as u64)
+}
+
+fn pow_mod_big(base: &BigInt, exp: &BigInt, m: &BigInt) -> BigInt {
+    let mut result = BigInt::from(1);
+    let mut base = base % m;
+    let mut exp = exp.clone();
+    while !exp.is_zero() {
+        if exp.is_odd() {
+            result = &result * &base % m;
+        }
+        base = &base * &base % m;
+        exp = exp >> 1;
     }
+    result
+}
 
-    let limit = (n as f64).sqrt() as u64;
-    for i in (3..=limit).step_by(2) {
-        if n % i == 0 {
+// Miller-Rabin with the first `rounds` prime bases. Deterministic below 2^64,
+// and below 3.3·10^24 with at least 13 rounds; beyond that the fixed bases make
+// it a heuristic, as composites passing any given set of bases can be built
+pub fn is_probable_prime(n: &BigInt, rounds: u32) -> bool {
+    if n.is_negative() {
+        return false;
+    }
+    if let Some(small) = n.to_u64() {
+        return is_prime_u64(small);
+    }
+    for p in MILLER_RABIN_BASES {
+        if (n % BigInt::from(p)).is_zero() {
             return false;
         }
     }
+
+    let n_minus_one = n - 1;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s as u32;
+
+    let bases = MILLER_RABIN_BASES
+        .iter()
+        .copied()
+        .chain(primes_in(41..))
+        .take(rounds.max(1) as usize);
+    'bases: for a in bases {
+        let mut x = pow_mod_big(&BigInt::from(a), &d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
     true
 }
 
-pub fn is_prime(n: i64) -> bool {
-    if n <= 1 {
-        return false;
+pub(crate) fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
-    is_prime_small(n as u64)
+    a
 }
 
-pub fn factor_integer(n: i64) -> Vec<(i64, i32)> {
-    if n == 0 {
-        return vec![];
+pub(crate) fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
+    a
+}
 
-    let mut num = n.abs();
-    let mut factors = Vec::new();
+// Number of steps between gcds in Brent's variant of Pollard rho
+const RHO_BATCH: u64 = 128;
+
+// A nontrivial factor of an odd composite n, iterating x -> x² + c
+fn pollard_brent(n: u64) -> u64 {
+    let mut c = 1u64;
+    loop {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+
+        let (mut x, mut y, mut ys) = (2u64, 2u64, 2u64);
+        let (mut q, mut g, mut r) = (1u64, 1u64, 1u64);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..RHO_BATCH.min(r - k) {
+                    y = f(y);
+                    q = mod_mul(q, x.abs_diff(y), n);
+                }
+                g = gcd_u64(q, n);
+                k += RHO_BATCH;
+            }
+            r *= 2;
+        }
+
+        // The batch overshot, step back one at a time from its start
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd_u64(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
 
-    if num == 1 {
-        return vec![(1, 1)];
+        if g != n {
+            return g;
+        }
+        c += 1;
     }
+}
 
-    let mut count = 0;
-    while num % 2 == 0 {
-        num /= 2;
-        count += 1;
+fn split_u64(n: u64, primes: &mut Vec<u64>) {
+    if n == 1 {
+        return;
     }
-    if count > 0 {
-        factors.push((2, count));
+    if is_prime_u64(n) {
+        primes.push(n);
+        return;
     }
+    let d = pollard_brent(n);
+    split_u64(d, primes);
+    split_u64(n / d, primes);
+}
 
-    let mut factor = 3;
-    while factor * factor <= num {
-        count = 0;
-        while num % factor == 0 {
-            num /= factor;
-            count += 1;
+fn collect_powers<T: Ord>(mut primes: Vec<T>) -> Vec<(T, i32)> {
+    primes.sort_unstable();
+    let mut factors: Vec<(T, i32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, count)) if *last == p => *count += 1,
+            _ => factors.push((p, 1)),
         }
-        if count > 0 {
-            factors.push((factor, count));
+    }
+    factors
+}
+
+pub fn factor_integer_u64(n: u64) -> Vec<(u64, i32)> {
+    if n == 0 {
+        return vec![];
+    }
+
+    // Strip small factors by trial division, they are cheap and make rho slower
+    let mut num = n;
+    let mut primes = Vec::new();
+    for p in (2..100).filter(|&p| p == 2 || p % 2 == 1) {
+        while num.is_multiple_of(p) {
+            num /= p;
+            primes.push(p);
         }
-        factor += 2;
     }
 
-    if num > 1 {
-        factors.push((num, 1));
+    split_u64(num, &mut primes);
+    collect_powers(primes)
+}
+
+pub fn factor_integer(n: i64) -> Vec<(i64, i32)> {
+    factor_integer_u64(n.unsigned_abs())
+        .into_iter()
+        .map(|(p, k)| (p as i64, k))
+        .collect()
+}
+
+fn pollard_brent_big(n: &BigInt) -> BigInt {
+    let mut c = 1u64;
+    loop {
+        let f = |x: &BigInt| (x * x + c as i64) % n;
+        let diff = |a: &BigInt, b: &BigInt| (a - b).abs();
+
+        let two = BigInt::from(2);
+        let (mut x, mut y, mut ys) = (two.clone(), two.clone(), two);
+        let (mut q, mut g, mut r) = (BigInt::from(1), BigInt::from(1), 1u64);
+        while g.is_one() {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                for _ in 0..RHO_BATCH.min(r - k) {
+                    y = f(&y);
+                    q = &q * &diff(&x, &y) % n;
+                }
+                g = q.gcd(n);
+                k += RHO_BATCH;
+            }
+            r *= 2;
+        }
+
+        if &g == n {
+            loop {
+                ys = f(&ys);
+                g = diff(&x, &ys).gcd(n);
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+
+        if &g != n {
+            return g;
+        }
+        c += 1;
     }
+}
 
-    factors
+fn split_big(n: BigInt, primes: &mut Vec<BigInt>) {
+    if let Some(small) = n.to_u64() {
+        primes.extend(
+            factor_integer_u64(small)
+                .into_iter()
+                .flat_map(|(p, k)| std::iter::repeat_n(BigInt::from(p), k as usize)),
+        );
+        return;
+    }
+    if is_probable_prime(&n, 20) {
+        primes.push(n);
+        return;
+    }
+    let d = pollard_brent_big(&n);
+    let rest = &n / &d;
+    split_big(d, primes);
+    split_big(rest, primes);
 }
 
-pub fn divisors(n: i64) -> Vec<i64> {
-    if n == 0 {
+pub fn factor_integer_big(n: &BigInt) -> Vec<(BigInt, i32)> {
+    if n.is_zero() {
         return vec![];
     }
 
-    let num = n.abs();
-    if num == 1 {
-        return vec![1];
+    let mut num = n.abs();
+    let mut primes = Vec::new();
+    for p in (2..1000u64).filter(|&p| p == 2 || p % 2 == 1) {
+        if num.to_u64().is_some() {
+            break;
+        }
+        let divisor = BigInt::from(p);
+        loop {
+            let (q, r) = num.div_rem(&divisor);
+            if !r.is_zero() {
+                break;
+            }
+            num = q;
+            primes.push(divisor.clone());
+        }
+    }
+
+    split_big(num, &mut primes);
+    collect_powers(primes)
+}
+
+pub fn divisors(n: i64) -> Vec<i64> {
+    if n == 0 {
+        return vec![];
     }
 
-    let factors = factor_integer(num);
+    let factors = factor_integer(n);
     let mut divs = vec![1];
 
     for (prime, power) in factors {
@@ -143,6 +468,356 @@ pub fn divisors(n: i64) -> Vec<i64> {
     divs
 }
 
+// The multiplicative functions below look at |n| and are 0 at n = 0
+
+pub fn euler_phi(n: i64) -> i64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factor_integer(n)
+        .into_iter()
+        .map(|(p, k)| (p - 1) * p.pow(k as u32 - 1))
+        .product()
+}
+
+// Exponent of the multiplicative group modulo n
+pub fn carmichael_lambda(n: i64) -> i64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factor_integer(n)
+        .into_iter()
+        .map(|(p, k)| match (p, k) {
+            (2, 1) => 1,
+            (2, 2) => 2,
+            (2, _) => 1 << (k - 2),
+            _ => (p - 1) * p.pow(k as u32 - 1),
+        })
+        .fold(1, lcm)
+}
+
+pub fn moebius_mu(n: i64) -> i32 {
+    if n == 0 {
+        return 0;
+    }
+
+    let factors = factor_integer(n);
+    if factors.iter().any(|&(_, k)| k > 1) {
+        return 0;
+    }
+
+    if factors.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+pub fn liouville_lambda(n: i64) -> i32 {
+    if n == 0 {
+        return 0;
+    }
+
+    if prime_omega(n) % 2 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+// Sum of the kth powers of the divisors of n, None once it overflows i64
+pub fn divisor_sigma(k: u32, n: i64) -> Option<i64> {
+    if n == 0 {
+        return Some(0);
+    }
+
+    factor_integer(n).into_iter().try_fold(1i64, |acc, (p, a)| {
+        let q = p.checked_pow(k)?;
+        let term = (0..a).try_fold(1i64, |sum, _| sum.checked_mul(q)?.checked_add(1))?;
+        acc.checked_mul(term)
+    })
+}
+
+// Never overflows, as n has fewer than 2^17 divisors
+pub fn divisor_count(n: i64) -> i64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factor_integer(n)
+        .into_iter()
+        .map(|(_, a)| a as i64 + 1)
+        .product()
+}
+
+// Product of the distinct primes dividing n
+pub fn radical(n: i64) -> i64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factor_integer(n).into_iter().map(|(p, _)| p).product()
+}
+
+// Number of distinct prime factors, ω(n)
+pub fn prime_nu(n: i64) -> i32 {
+    factor_integer(n).len() as i32
+}
+
+// Number of prime factors counted with multiplicity, Ω(n)
+pub fn prime_omega(n: i64) -> i32 {
+    factor_integer(n).into_iter().map(|(_, k)| k).sum()
+}
+
+pub fn jacobi_symbol(a: i64, n: i64) -> i32 {
+    if n <= 0 || n % 2 == 0 {
+        panic!("Jacobi symbol needs an odd positive modulus");
+    }
+
+    let mut a = a.rem_euclid(n) as u64;
+    let mut n = n as u64;
+    let mut result = 1;
+
+    while a != 0 {
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        // (2/n) = -1 exactly when n ≡ 3, 5 (mod 8)
+        if twos % 2 == 1 && matches!(n % 8, 3 | 5) {
+            result = -result;
+        }
+        // Quadratic reciprocity flips the sign when both are 3 (mod 4)
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        (a, n) = (n % a, a);
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+pub fn legendre_symbol(a: i64, p: i64) -> i32 {
+    if p == 2 || !is_prime(p) {
+        panic!("Legendre symbol needs an odd prime modulus");
+    }
+
+    jacobi_symbol(a, p)
+}
+
+// Extension of the Jacobi symbol to every integer n
+pub fn kronecker_symbol(a: i64, n: i64) -> i32 {
+    if n == 0 {
+        return if a == 1 || a == -1 { 1 } else { 0 };
+    }
+
+    let mut result = 1;
+    if n < 0 && a < 0 {
+        result = -1;
+    }
+
+    let mut n = n.unsigned_abs();
+    let twos = n.trailing_zeros();
+    n >>= twos;
+    if twos > 0 {
+        if a % 2 == 0 {
+            return 0;
+        }
+        // (a/2) = -1 exactly when a ≡ 3, 5 (mod 8)
+        if twos % 2 == 1 && matches!(a.rem_euclid(8), 3 | 5) {
+            result = -result;
+        }
+    }
+
+    if n == 1 {
+        return result;
+    }
+    let a = a.rem_euclid(n as i64);
+    result * jacobi_symbol(a, n as i64)
+}
+
+// Smaller square root of a modulo the prime p by Tonelli-Shanks, None when a
+// is not a quadratic residue; the other root is p minus this one
+pub fn mod_sqrt(a: i64, p: i64) -> Option<i64> {
+    if p < 2 || !is_prime(p) {
+        panic!("Modular square root needs a prime modulus");
+    }
+
+    let a = a.rem_euclid(p) as u64;
+    let p = p as u64;
+    if a == 0 || p == 2 {
+        return Some(a as i64);
+    }
+    if jacobi_symbol(a as i64, p as i64) != 1 {
+        return None;
+    }
+
+    // p - 1 = q 2^s with q odd, and z any non-residue
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..p)
+        .find(|&z| jacobi_symbol(z as i64, p as i64) == -1)
+        .unwrap();
+
+    let mut m = s;
+    let mut c = mod_pow(z, q, p);
+    let mut t = mod_pow(a, q, p);
+    let mut r = mod_pow(a, q.div_ceil(2), p);
+
+    while t != 1 {
+        // Least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mod_mul(t2, t2, p);
+            i += 1;
+        }
+
+        let b = mod_pow(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mod_mul(b, b, p);
+        t = mod_mul(t, c, p);
+        r = mod_mul(r, b, p);
+    }
+
+    Some(r.min(p - r) as i64)
+}
+
+// Least k > 0 with a^k ≡ 1 (mod n), None when a and n are not coprime
+pub fn multiplicative_order(a: i64, n: i64) -> Option<i64> {
+    if n <= 0 {
+        panic!("Modulus must be positive");
+    }
+    if gcd(a, n) != 1 {
+        return None;
+    }
+
+    // The order divides λ(n), strip the prime factors it does not need
+    let a = a.rem_euclid(n) as u64;
+    let mut order = carmichael_lambda(n);
+    for (q, _) in factor_integer(order) {
+        while order % q == 0 && mod_pow(a, (order / q) as u64, n as u64) == 1 % n as u64 {
+            order /= q;
+        }
+    }
+
+    Some(order)
+}
+
+// Smallest primitive root modulo n, None unless n is 1, 2, 4, p^k or 2 p^k
+pub fn primitive_root(n: i64) -> Option<i64> {
+    if n <= 0 {
+        panic!("Modulus must be positive");
+    }
+    if n <= 4 {
+        return Some([0, 1, 2, 3][n as usize - 1]);
+    }
+
+    let odd = factor_integer(if n % 2 == 0 { n / 2 } else { n });
+    if odd.len() != 1 || odd[0].0 == 2 {
+        return None;
+    }
+
+    let phi = euler_phi(n);
+    let factors = factor_integer(phi);
+    (2..n).find(|&g| {
+        gcd(g, n) == 1
+            && factors
+                .iter()
+                .all(|&(q, _)| mod_pow(g as u64, (phi / q) as u64, n as u64) != 1)
+    })
+}
+
+// Baby-step giant-step for the x < order with g^x ≡ h (mod n)
+fn baby_step_giant_step(g: u64, h: u64, order: u64, n: u64) -> Option<u64> {
+    let m = order.isqrt() + 1;
+    let mut table = HashMap::new();
+    let mut baby = 1 % n;
+    for j in 0..m {
+        table.entry(baby).or_insert(j);
+        baby = mod_mul(baby, g, n);
+    }
+
+    let giant = mod_inverse(mod_pow(g, m, n) as i64, n as i64)? as u64;
+    let mut gamma = h;
+    for i in 0..m {
+        if let Some(&j) = table.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = mod_mul(gamma, giant, n);
+    }
+
+    None
+}
+
+// Least x >= 0 with a^x ≡ b (mod n), None when there is none. The common
+// factors of a and n are divided out first, the rest is solved by
+// Pohlig-Hellman over the prime power factors of the order of a.
+pub fn discrete_log(a: i64, b: i64, n: i64) -> Option<i64> {
+    if n <= 0 {
+        panic!("Modulus must be positive");
+    }
+
+    let mut n = n as u64;
+    let a = a.rem_euclid(n as i64) as u64;
+    let mut b = b.rem_euclid(n as i64) as u64;
+
+    // a^x = b with gcd(a, n) = g > 1 forces g | b for x > 0, reducing to
+    // (a / g) a^(x - 1) = b / g (mod n / g)
+    let mut scale = 1 % n;
+    let mut shift = 0;
+    loop {
+        if scale == b % n {
+            return Some(shift);
+        }
+        let g = gcd_u64(a, n);
+        if g == 1 {
+            break;
+        }
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+        n /= g;
+        b /= g;
+        scale = mod_mul(scale % n, a / g, n);
+        shift += 1;
+    }
+
+    if n == 1 {
+        return Some(shift);
+    }
+
+    let target = mod_mul(b, mod_inverse(scale as i64, n as i64)? as u64, n);
+    let order = multiplicative_order(a as i64, n as i64)? as u64;
+
+    let a_inv = mod_inverse(a as i64, n as i64)? as u64;
+    let mut congruences = Vec::new();
+    for (q, e) in factor_integer_u64(order) {
+        // Digits of x in base q modulo q^e, each from a subgroup of order q
+        let gamma = mod_pow(a, order / q, n);
+        let mut x = 0;
+        let mut q_power = 1;
+        for _ in 0..e {
+            let h = mod_mul(mod_pow(a_inv, x, n), target, n);
+            let h = mod_pow(h, order / (q_power * q), n);
+            x += baby_step_giant_step(gamma, h, q, n)? * q_power;
+            q_power *= q;
+        }
+        congruences.push((x as i64, q_power as i64));
+    }
+
+    let (x, _) = chinese_remainder(&congruences)?;
+    if mod_pow(a, x as u64, n) != target {
+        return None;
+    }
+
+    Some(x + shift)
+}
+
 fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> {
     let mut is_prime = vec![true; limit + 1];
     if limit == 0 {
@@ -163,63 +838,348 @@ fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> {
     is_prime
 }
 
-static mut SMALL_PRIMES: Option<Vec<i64>> = None;
-static mut PRIMES_COMPUTED_UP_TO: usize = 0;
+// Primes up to `limit`, shared between threads and grown on demand
+struct PrimeCache {
+    primes: Vec<u64>,
+    limit: u64,
+}
+
+static PRIME_CACHE: RwLock<PrimeCache> = RwLock::new(PrimeCache {
+    primes: Vec::new(),
+    limit: 0,
+});
+
+const INITIAL_SIEVE_LIMIT: u64 = 1 << 16;
+const SEGMENT_SIZE: u64 = 1 << 16;
+// Largest sieving prime bound the cache grows to for ranges far beyond it
+const MAX_SIEVING_LIMIT: u64 = 1 << 22;
+
+fn read_cache() -> RwLockReadGuard<'static, PrimeCache> {
+    PRIME_CACHE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+// Appends the primes in [lo, hi] to `out`, given every prime up to sqrt(hi)
+fn sieve_segmented(lo: u64, hi: u64, base: &[u64], out: &mut Vec<u64>) {
+    let mut segment_lo = lo.max(2);
+    while segment_lo <= hi {
+        let segment_hi = hi.min(segment_lo.saturating_add(SEGMENT_SIZE - 1));
+        let mut composite = vec![false; (segment_hi - segment_lo + 1) as usize];
+
+        for &p in base.iter().take_while(|&&p| p <= segment_hi / p) {
+            let Some(first) = segment_lo.div_ceil(p).checked_mul(p) else {
+                continue;
+            };
+            let mut multiple = (p * p).max(first);
+            while multiple <= segment_hi {
+                composite[(multiple - segment_lo) as usize] = true;
+                match multiple.checked_add(p) {
+                    Some(next) => multiple = next,
+                    None => break,
+                }
+            }
+        }
+
+        out.extend(
+            composite
+                .iter()
+                .enumerate()
+                .filter(|&(_, &c)| !c)
+                .map(|(i, _)| segment_lo + i as u64),
+        );
 
-fn ensure_primes_computed(up_to: usize) {
-    unsafe {
-        if PRIMES_COMPUTED_UP_TO < up_to {
-            let is_prime = sieve_of_eratosthenes(up_to);
-            SMALL_PRIMES = Some(
-                (2..=up_to)
-                    .filter(|&i| is_prime[i])
-                    .map(|i| i as i64)
-                    .collect(),
-            );
-            PRIMES_COMPUTED_UP_TO = up_to;
+        match segment_hi.checked_add(1) {
+            Some(next) => segment_lo = next,
+            None => break,
         }
     }
 }
 
-pub fn prime(n: i32) -> i64 {
-    if n <= 0 {
-        panic!("Prime index must be positive");
+// Extends the cache to cover `limit`, but never past MAX_SIEVING_LIMIT; each
+// step at most squares the bound so the cached primes always suffice as
+// sieving primes
+fn ensure_primes_computed(limit: u64) {
+    let limit = limit.min(MAX_SIEVING_LIMIT);
+    if read_cache().limit >= limit {
+        return;
     }
 
-    let upper_bound = if n < 6 {
-        15
-    } else {
-        let n_f = n as f64;
-        let ln_n = n_f.ln();
-        (n_f * (ln_n + ln_n.ln() - 1.0 + (ln_n.ln() - 2.0) / ln_n)) as usize
+    let mut cache = PRIME_CACHE.write().unwrap_or_else(|e| e.into_inner());
+    if cache.limit == 0 {
+        let is_prime = sieve_of_eratosthenes(INITIAL_SIEVE_LIMIT as usize);
+        cache.primes = (2..=INITIAL_SIEVE_LIMIT)
+            .filter(|&i| is_prime[i as usize])
+            .collect();
+        cache.limit = INITIAL_SIEVE_LIMIT;
+    }
+
+    while cache.limit < limit {
+        let lo = cache.limit + 1;
+        let hi = limit
+            .max(cache.limit.saturating_mul(2).min(MAX_SIEVING_LIMIT))
+            .min(cache.limit.saturating_mul(cache.limit));
+        let mut found = Vec::new();
+        sieve_segmented(lo, hi, &cache.primes, &mut found);
+        cache.primes.extend(found);
+        cache.limit = hi;
+    }
+}
+
+// Primes in [lo, hi], taken from the cache up to MAX_SIEVING_LIMIT, sieved
+// into a fresh buffer beyond it and tested one by one once the sieving primes
+// would go past the cache
+fn primes_between(lo: u64, hi: u64) -> Vec<u64> {
+    if hi <= MAX_SIEVING_LIMIT {
+        ensure_primes_computed(hi);
+        let cache = read_cache();
+        let start = cache.primes.partition_point(|&p| p < lo);
+        let end = cache.primes.partition_point(|&p| p <= hi);
+        return cache.primes[start..end].to_vec();
+    }
+
+    if hi.isqrt() > MAX_SIEVING_LIMIT {
+        return (lo..=hi).filter(|&n| is_prime_u64(n)).collect();
+    }
+
+    ensure_primes_computed(hi.isqrt());
+    let cache = read_cache();
+    let start = cache.primes.partition_point(|&p| p < lo);
+    let mut out = cache.primes[start..].to_vec();
+    sieve_segmented(lo.max(cache.limit + 1), hi, &cache.primes, &mut out);
+    out
+}
+
+pub struct Primes {
+    buffer: Vec<u64>,
+    position: usize,
+    next: Option<u64>,
+    end: u64,
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.position == self.buffer.len() {
+            let lo = self.next?;
+            if lo > self.end {
+                return None;
+            }
+            let hi = self.end.min(lo.saturating_add(SEGMENT_SIZE - 1));
+            self.buffer = primes_between(lo, hi);
+            self.position = 0;
+            self.next = hi.checked_add(1);
+        }
+
+        self.position += 1;
+        Some(self.buffer[self.position - 1])
+    }
+}
+
+pub fn primes() -> Primes {
+    primes_in(..)
+}
+
+pub fn primes_in<R: RangeBounds<u64>>(range: R) -> Primes {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => Some(s),
+        Bound::Excluded(&s) => s.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let (start, end) = match range.end_bound() {
+        Bound::Included(&e) => (start, e),
+        Bound::Excluded(&0) => (None, 0),
+        Bound::Excluded(&e) => (start, e - 1),
+        Bound::Unbounded => (start, u64::MAX),
     };
 
-    ensure_primes_computed(upper_bound.max(1000000));
+    Primes {
+        buffer: Vec::new(),
+        position: 0,
+        next: start,
+        end,
+    }
+}
+
+// Smallest prime greater than n, None past the largest 64-bit prime
+pub fn next_prime(n: u64) -> Option<u64> {
+    ensure_primes_computed(INITIAL_SIEVE_LIMIT);
+    {
+        let cache = read_cache();
+        if n < cache.limit {
+            let i = cache.primes.partition_point(|&p| p <= n);
+            if i < cache.primes.len() {
+                return Some(cache.primes[i]);
+            }
+        }
+    }
+
+    (n.checked_add(1)?..=u64::MAX).find(|&m| is_prime_u64(m))
+}
+
+// Largest prime less than n, None for n <= 2
+pub fn prev_prime(n: u64) -> Option<u64> {
+    ensure_primes_computed(INITIAL_SIEVE_LIMIT);
+    {
+        let cache = read_cache();
+        if n <= cache.limit + 1 {
+            let i = cache.primes.partition_point(|&p| p < n);
+            return i.checked_sub(1).map(|i| cache.primes[i]);
+        }
+    }
+
+    (2..n).rev().find(|&m| is_prime_u64(m))
+}
+
+// euler_phi(k) for every 0 <= k <= n, sieved with the cached primes
+pub fn totient_sieve(n: usize) -> Vec<i64> {
+    let mut phi: Vec<i64> = (0..=n as i64).collect();
+    for p in primes_in(..=n as u64) {
+        let p = p as usize;
+        for m in (p..=n).step_by(p) {
+            phi[m] -= phi[m] / p as i64;
+        }
+    }
+    phi
+}
 
-    unsafe {
-        if let Some(ref primes) = SMALL_PRIMES {
-            if (n as usize) <= primes.len() {
-                return primes[n as usize - 1];
+// moebius_mu(k) for every 0 <= k <= n, sieved with the cached primes
+pub fn mobius_sieve(n: usize) -> Vec<i32> {
+    let mut mu = vec![1; n + 1];
+    mu[0] = 0;
+    for p in primes_in(..=n as u64) {
+        let p = p as usize;
+        for m in (p..=n).step_by(p) {
+            mu[m] = -mu[m];
+        }
+        if let Some(square) = p.checked_mul(p) {
+            for m in (square..=n).step_by(square) {
+                mu[m] = 0;
             }
         }
     }
+    mu
+}
+
+// Lucy_Hedgehog's method: sum of weight(p) over primes p <= x in O(x^(3/4))
+// time, where initial(v) is the sum of weight(k) over 2 <= k <= v
+fn lucy_hedgehog<T>(x: u64, initial: impl Fn(u64) -> T, weight: impl Fn(u64) -> T) -> T
+where
+    T: Copy + PartialEq + Sub<Output = T> + Mul<Output = T>,
+{
+    let r = x.isqrt();
+    // small[v] = S(v) and large[i] = S(x / i) for 1 <= v, i <= r
+    let mut small: Vec<T> = (0..=r).map(|v| initial(v.max(1))).collect();
+    let mut large: Vec<T> = (0..=r).map(|i| initial(x / i.max(1))).collect();
+
+    for p in 2..=r {
+        if small[p as usize] == small[p as usize - 1] {
+            continue;
+        }
+
+        let below = small[p as usize - 1];
+        let w = weight(p);
+        let p2 = p * p;
+
+        for i in 1..=r.min(x / p2) {
+            let d = i * p;
+            let s = if d <= r {
+                large[d as usize]
+            } else {
+                small[(x / d) as usize]
+            };
+            large[i as usize] = large[i as usize] - w * (s - below);
+        }
+
+        for v in (p2..=r).rev() {
+            let s = small[(v / p) as usize];
+            small[v as usize] = small[v as usize] - w * (s - below);
+        }
+    }
+
+    large[1]
+}
+
+pub fn prime_pi(x: u64) -> u64 {
+    if x < 2 {
+        return 0;
+    }
+
+    if x <= MAX_SIEVING_LIMIT {
+        ensure_primes_computed(x);
+        return read_cache().primes.partition_point(|&p| p <= x) as u64;
+    }
 
-    panic!("Cannot compute prime({}) - need larger sieve", n);
+    lucy_hedgehog(x, |v| v - 1, |_| 1)
 }
 
-pub fn prime_pi(x: i32) -> i64 {
+// Sum of all primes up to x
+pub fn prime_sum(x: u64) -> u128 {
     if x < 2 {
         return 0;
     }
 
-    let limit = x as usize;
-    ensure_primes_computed(limit);
+    lucy_hedgehog(
+        x,
+        |v| {
+            let v = v as u128;
+            v * (v + 1) / 2 - 1
+        },
+        |p| p as u128,
+    )
+}
+
+// The nth prime, counting from 2 as the first. Large n are located by
+// counting primes up to an estimate and sieving locally from there.
+pub fn nth_prime(n: u64) -> u64 {
+    if n == 0 {
+        panic!("Prime index must be positive");
+    }
+
+    // Rosser's bound p(n) < n (ln n + ln ln n) for n >= 6
+    let upper_bound = if n < 6 {
+        11
+    } else {
+        let n_f = n as f64;
+        let ln_n = n_f.ln();
+        (n_f * (ln_n + ln_n.ln())) as u64
+    };
 
-    unsafe {
-        if let Some(ref primes) = SMALL_PRIMES {
-            return primes.iter().take_while(|&&p| p <= x as i64).count() as i64;
+    if upper_bound <= MAX_SIEVING_LIMIT {
+        ensure_primes_computed(upper_bound);
+        return read_cache().primes[n as usize - 1];
+    }
+
+    // Cipolla's asymptotic p(n) ~ n (ln n + ln ln n - 1 + (ln ln n - 2) / ln n)
+    let n_f = n as f64;
+    let ln_n = n_f.ln();
+    let ln_ln_n = ln_n.ln();
+    let estimate = (n_f * (ln_n + ln_ln_n - 1.0 + (ln_ln_n - 2.0) / ln_n)) as u64;
+
+    let count = prime_pi(estimate);
+    if count < n {
+        return primes_in(estimate + 1..)
+            .nth((n - count - 1) as usize)
+            .expect("Prime index out of range");
+    }
+
+    // Walk back from the estimate, skipping the primes past the nth
+    let mut skip = (count - n) as usize;
+    let mut hi = estimate;
+    loop {
+        let lo = hi.saturating_sub(SEGMENT_SIZE - 1);
+        let found = primes_between(lo, hi);
+        if skip < found.len() {
+            return found[found.len() - 1 - skip];
         }
+        skip -= found.len();
+        hi = lo - 1;
+    }
+}
+
+pub fn prime(n: i32) -> i64 {
+    if n <= 0 {
+        panic!("Prime index must be positive");
     }
 
-    panic!("Cannot compute prime_pi({}) - need larger sieve", x);
+    nth_prime(n as u64) as i64
 }
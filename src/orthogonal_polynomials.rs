@@ -0,0 +1,729 @@
+use crate::special::{cos_pi, log_gamma, sin_pi};
+use std::f64::consts::PI;
+
+const MAX_NEWTON_ITERS: usize = 100;
+const NEWTON_TOLERANCE: f64 = 1e-15;
+
+// Limit of a degree n polynomial as x → ±∞, given the sign of its leading coefficient
+fn at_infinity(n: u32, x: f64, leading_sign: f64) -> f64 {
+    if n == 0 {
+        return leading_sign;
+    }
+
+    let sign = if x < 0.0 && n % 2 == 1 { -1.0 } else { 1.0 };
+    leading_sign * sign * f64::INFINITY
+}
+
+// Sign of Π (a + k) for k in 0..n
+fn rising_sign(a: f64, n: u32) -> f64 {
+    (0..n).fold(1.0, |sign, k| sign * (a + k as f64).signum())
+}
+
+// Returns P_n(x) and P_n-1(x)
+fn legendre_pair(n: u32, x: f64) -> (f64, f64) {
+    let mut previous = 1.0;
+    let mut current = x;
+    if n == 0 {
+        return (previous, 0.0);
+    }
+
+    for k in 1..n {
+        let k = k as f64;
+        let next = ((2.0 * k + 1.0) * x * current - k * previous) / (k + 1.0);
+        previous = current;
+        current = next;
+    }
+
+    (current, previous)
+}
+
+pub fn legendre_p(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n, x, 1.0);
+    }
+
+    legendre_pair(n, x).0
+}
+
+pub fn legendre_p_derivative(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n - 1, x, 1.0);
+    }
+
+    let nf = n as f64;
+
+    // The recurrence for the derivative divides by x² - 1
+    if x.abs() == 1.0 {
+        let value = 0.5 * nf * (nf + 1.0);
+        return if x > 0.0 || n % 2 == 1 { value } else { -value };
+    }
+
+    let (p, previous) = legendre_pair(n, x);
+    nf * (x * p - previous) / (x * x - 1.0)
+}
+
+// Returns P_n^m(x) and P_n-1^m(x) for m ≤ n, including the Condon-Shortley phase
+fn assoc_legendre_pair(n: u32, m: u32, x: f64) -> (f64, f64) {
+    let mut pmm = 1.0;
+    if m > 0 {
+        let root = ((1.0 - x) * (1.0 + x)).sqrt();
+        let mut factor = 1.0;
+        for _ in 0..m {
+            pmm *= -factor * root;
+            factor += 2.0;
+        }
+    }
+
+    if n == m {
+        return (pmm, 0.0);
+    }
+
+    let mut previous = pmm;
+    let mut current = x * (2 * m + 1) as f64 * pmm;
+    for k in (m + 1)..n {
+        let next =
+            ((2 * k + 1) as f64 * x * current - (k + m) as f64 * previous) / (k - m + 1) as f64;
+        previous = current;
+        current = next;
+    }
+
+    (current, previous)
+}
+
+// Associated Legendre function P_n^m(x) on [-1, 1], with the Condon-Shortley phase (-1)^m
+pub fn assoc_legendre_p(n: u32, m: u32, x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+
+    if m > n {
+        return 0.0;
+    }
+
+    assoc_legendre_pair(n, m, x).0
+}
+
+pub fn assoc_legendre_p_derivative(n: u32, m: u32, x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+
+    if m > n {
+        return 0.0;
+    }
+
+    if m == 0 {
+        return legendre_p_derivative(n, x);
+    }
+
+    if x.abs() == 1.0 {
+        // P_n^m behaves like (1 - x²)^(m/2) at the endpoints
+        let (nf, sign) = (n as f64, if x > 0.0 { 1.0 } else { -1.0 });
+        return match m {
+            1 => {
+                if x > 0.0 || n.is_multiple_of(2) {
+                    f64::INFINITY
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+            2 => {
+                let parity = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+                -sign * parity * (nf - 1.0) * nf * (nf + 1.0) * (nf + 2.0) / 4.0
+            }
+            _ => 0.0,
+        };
+    }
+
+    let (p, previous) = assoc_legendre_pair(n, m, x);
+    (n as f64 * x * p - (n + m) as f64 * previous) / (x * x - 1.0)
+}
+
+// Runs the recurrence y_k+1 = 2x y_k - y_k-1 from y_0 = 1 and y_1 = first
+fn chebyshev_recurrence(n: u32, x: f64, first: f64) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+
+    let mut previous = 1.0;
+    let mut current = first;
+    for _ in 1..n {
+        let next = 2.0 * x * current - previous;
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+pub fn chebyshev_t(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n, x, 1.0);
+    }
+
+    chebyshev_recurrence(n, x, x)
+}
+
+pub fn chebyshev_u(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n, x, 1.0);
+    }
+
+    chebyshev_recurrence(n, x, 2.0 * x)
+}
+
+pub fn chebyshev_t_derivative(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() { f64::NAN } else { 0.0 };
+    }
+
+    n as f64 * chebyshev_u(n - 1, x)
+}
+
+pub fn chebyshev_u_derivative(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n - 1, x, 1.0);
+    }
+
+    let nf = n as f64;
+
+    if x.abs() == 1.0 {
+        let value = nf * (nf + 1.0) * (nf + 2.0) / 3.0;
+        return if x > 0.0 || n % 2 == 1 { value } else { -value };
+    }
+
+    ((nf + 1.0) * chebyshev_t(n + 1, x) - x * chebyshev_u(n, x)) / (x * x - 1.0)
+}
+
+// Physicists' Hermite polynomial H_n(x), orthogonal under e^(-x²)
+pub fn hermite_h(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n, x, 1.0);
+    }
+
+    if n == 0 {
+        return 1.0;
+    }
+
+    let mut previous = 1.0;
+    let mut current = 2.0 * x;
+    for k in 1..n {
+        let next = 2.0 * x * current - 2.0 * k as f64 * previous;
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+// Probabilists' Hermite polynomial He_n(x), orthogonal under e^(-x²/2)
+pub fn hermite_he(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x.is_infinite() {
+        return at_infinity(n, x, 1.0);
+    }
+
+    if n == 0 {
+        return 1.0;
+    }
+
+    let mut previous = 1.0;
+    let mut current = x;
+    for k in 1..n {
+        let next = x * current - k as f64 * previous;
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+pub fn hermite_h_derivative(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() { f64::NAN } else { 0.0 };
+    }
+
+    2.0 * n as f64 * hermite_h(n - 1, x)
+}
+
+pub fn hermite_he_derivative(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() { f64::NAN } else { 0.0 };
+    }
+
+    n as f64 * hermite_he(n - 1, x)
+}
+
+// Returns L_n^α(x) and L_n-1^α(x)
+fn laguerre_pair(n: u32, alpha: f64, x: f64) -> (f64, f64) {
+    let mut previous = 1.0;
+    let mut current = 1.0 + alpha - x;
+    if n == 0 {
+        return (previous, 0.0);
+    }
+
+    for k in 1..n {
+        let k = k as f64;
+        let next = ((2.0 * k + 1.0 + alpha - x) * current - (k + alpha) * previous) / (k + 1.0);
+        previous = current;
+        current = next;
+    }
+
+    (current, previous)
+}
+
+pub fn laguerre_l(n: u32, x: f64) -> f64 {
+    generalized_laguerre_l(n, 0.0, x)
+}
+
+pub fn generalized_laguerre_l(n: u32, alpha: f64, x: f64) -> f64 {
+    if x.is_nan() || alpha.is_nan() {
+        return f64::NAN;
+    }
+
+    // The leading coefficient is (-1)^n / n!
+    if x.is_infinite() {
+        return at_infinity(n, x, if n.is_multiple_of(2) { 1.0 } else { -1.0 });
+    }
+
+    laguerre_pair(n, alpha, x).0
+}
+
+pub fn laguerre_l_derivative(n: u32, x: f64) -> f64 {
+    generalized_laguerre_l_derivative(n, 0.0, x)
+}
+
+pub fn generalized_laguerre_l_derivative(n: u32, alpha: f64, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() || alpha.is_nan() {
+            f64::NAN
+        } else {
+            0.0
+        };
+    }
+
+    -generalized_laguerre_l(n - 1, alpha + 1.0, x)
+}
+
+// Returns P_n^(α,β)(x) and P_n-1^(α,β)(x)
+fn jacobi_pair(n: u32, alpha: f64, beta: f64, x: f64) -> (f64, f64) {
+    let mut previous = 1.0;
+    let mut current = 0.5 * (alpha - beta + (alpha + beta + 2.0) * x);
+    if n == 0 {
+        return (previous, 0.0);
+    }
+
+    let ab = alpha + beta;
+    for k in 2..=n {
+        let k = k as f64;
+        let c = 2.0 * k + ab;
+        let a = 2.0 * k * (k + ab) * (c - 2.0);
+        let b = (c - 1.0) * (alpha * alpha - beta * beta + c * (c - 2.0) * x);
+        let d = 2.0 * (k - 1.0 + alpha) * (k - 1.0 + beta) * c;
+        let next = (b * current - d * previous) / a;
+        previous = current;
+        current = next;
+    }
+
+    (current, previous)
+}
+
+pub fn jacobi_p(n: u32, alpha: f64, beta: f64, x: f64) -> f64 {
+    if x.is_nan() || alpha.is_nan() || beta.is_nan() {
+        return f64::NAN;
+    }
+
+    // The leading coefficient is (n + α + β + 1)_n / (2^n n!)
+    if x.is_infinite() {
+        return at_infinity(n, x, rising_sign(n as f64 + alpha + beta + 1.0, n));
+    }
+
+    jacobi_pair(n, alpha, beta, x).0
+}
+
+pub fn jacobi_p_derivative(n: u32, alpha: f64, beta: f64, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() || alpha.is_nan() || beta.is_nan() {
+            f64::NAN
+        } else {
+            0.0
+        };
+    }
+
+    0.5 * (n as f64 + alpha + beta + 1.0) * jacobi_p(n - 1, alpha + 1.0, beta + 1.0, x)
+}
+
+pub fn gegenbauer_c(n: u32, lambda: f64, x: f64) -> f64 {
+    if x.is_nan() || lambda.is_nan() {
+        return f64::NAN;
+    }
+
+    // The leading coefficient is 2^n (λ)_n / n!
+    if x.is_infinite() {
+        return at_infinity(n, x, rising_sign(lambda, n));
+    }
+
+    if n == 0 {
+        return 1.0;
+    }
+
+    let mut previous = 1.0;
+    let mut current = 2.0 * lambda * x;
+    for k in 1..n {
+        let k = k as f64;
+        let next =
+            (2.0 * (k + lambda) * x * current - (k + 2.0 * lambda - 1.0) * previous) / (k + 1.0);
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+pub fn gegenbauer_c_derivative(n: u32, lambda: f64, x: f64) -> f64 {
+    if n == 0 {
+        return if x.is_nan() || lambda.is_nan() {
+            f64::NAN
+        } else {
+            0.0
+        };
+    }
+
+    2.0 * lambda * gegenbauer_c(n - 1, lambda + 1.0, x)
+}
+
+// Eigenvalues of the symmetric tridiagonal Jacobi matrix of a three-term recurrence, in
+// ascending order, by the implicit QL method. They start the Newton iterations of the
+// Gauss rules, which would otherwise need a separate asymptotic guess for every family
+fn golub_welsch_nodes(mut diagonal: Vec<f64>, off_diagonal: Vec<f64>) -> Vec<f64> {
+    const MAX_QL_ITERS: usize = 60;
+
+    let n = diagonal.len();
+    let mut e = off_diagonal;
+    e.push(0.0);
+
+    for l in 0..n {
+        for _ in 0..MAX_QL_ITERS {
+            let mut m = l;
+            while m + 1 < n {
+                let scale = diagonal[m].abs() + diagonal[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * scale {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+
+            let mut g = (diagonal[l + 1] - diagonal[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = diagonal[m] - diagonal[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut deflated = false;
+
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    diagonal[i + 1] -= p;
+                    e[m] = 0.0;
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = diagonal[i + 1] - p;
+                r = (diagonal[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                diagonal[i + 1] = g + p;
+                g = c * r - b;
+            }
+
+            if !deflated {
+                diagonal[l] -= p;
+                e[l] = g;
+                e[m] = 0.0;
+            }
+        }
+    }
+
+    diagonal.sort_by(f64::total_cmp);
+    diagonal
+}
+
+// Gauss quadrature rules return the nodes in ascending order together with their weights
+
+// Weight 1 on [-1, 1]
+pub fn gauss_legendre(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut nodes = vec![0.0; n];
+    let mut weights = vec![0.0; n];
+    let nf = n as f64;
+
+    for i in 0..n.div_ceil(2) {
+        let mut z = (PI * (i as f64 + 0.75) / (nf + 0.5)).cos();
+        let mut derivative = 1.0;
+
+        for _ in 0..MAX_NEWTON_ITERS {
+            let (p, previous) = legendre_pair(n as u32, z);
+            derivative = nf * (z * p - previous) / (z * z - 1.0);
+            let dz = p / derivative;
+            z -= dz;
+            if dz.abs() <= NEWTON_TOLERANCE {
+                break;
+            }
+        }
+
+        // The middle node of an odd rule is exactly zero
+        if 2 * i + 1 == n {
+            z = 0.0;
+            derivative = legendre_p_derivative(n as u32, 0.0);
+        }
+
+        let weight = 2.0 / ((1.0 - z * z) * derivative * derivative);
+        nodes[i] = -z;
+        nodes[n - 1 - i] = z;
+        weights[i] = weight;
+        weights[n - 1 - i] = weight;
+    }
+
+    (nodes, weights)
+}
+
+// Weight 1 / √(1 - x²) on [-1, 1]
+pub fn gauss_chebyshev_t(n: usize) -> (Vec<f64>, Vec<f64>) {
+    // -cos(π(2i + 1) / 2n) written so that the nodes come out exactly symmetric
+    let nf = n as f64;
+    let nodes = (0..n)
+        .map(|i| sin_pi((2.0 * i as f64 + 1.0 - nf) / (2.0 * nf)))
+        .collect();
+    (nodes, vec![PI / nf; n])
+}
+
+// Weight √(1 - x²) on [-1, 1]
+pub fn gauss_chebyshev_u(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let nf = n as f64;
+    let angles: Vec<f64> = (1..=n)
+        .map(|i| (2.0 * i as f64 - nf - 1.0) / (2.0 * (nf + 1.0)))
+        .collect();
+    let nodes = angles.iter().map(|&a| sin_pi(a)).collect();
+    let weights = angles
+        .iter()
+        .map(|&a| {
+            let c = cos_pi(a);
+            PI / (nf + 1.0) * c * c
+        })
+        .collect();
+    (nodes, weights)
+}
+
+// Weight e^(-x²) on (-∞, ∞)
+pub fn gauss_hermite(n: usize) -> (Vec<f64>, Vec<f64>) {
+    const PI_POWER_MINUS_QUARTER: f64 = 0.7511255444649425;
+
+    let off_diagonal = (1..n).map(|k| (0.5 * k as f64).sqrt()).collect();
+    let mut nodes = golub_welsch_nodes(vec![0.0; n], off_diagonal);
+    let mut weights = vec![0.0; n];
+    let nf = n as f64;
+
+    // Polish the non-negative half and mirror it
+    for i in n / 2..n {
+        let mut z = if 2 * i + 1 == n { 0.0 } else { nodes[i] };
+
+        // Orthonormal recurrence, which keeps the values in range for large n
+        let mut derivative = 1.0;
+        for _ in 0..MAX_NEWTON_ITERS {
+            let mut previous = 0.0;
+            let mut p = PI_POWER_MINUS_QUARTER;
+            for j in 1..=n {
+                let j = j as f64;
+                let next = z * (2.0 / j).sqrt() * p - ((j - 1.0) / j).sqrt() * previous;
+                previous = p;
+                p = next;
+            }
+            derivative = (2.0 * nf).sqrt() * previous;
+            let dz = p / derivative;
+            if !dz.is_finite() || z == 0.0 {
+                break;
+            }
+            z -= dz;
+            if dz.abs() <= NEWTON_TOLERANCE * z.abs().max(1.0) {
+                break;
+            }
+        }
+
+        // The polynomial only overflows this far out, where the weight underflows anyway
+        let weight = if derivative.is_finite() {
+            2.0 / (derivative * derivative)
+        } else {
+            0.0
+        };
+        nodes[n - 1 - i] = -z;
+        nodes[i] = z;
+        weights[i] = weight;
+        weights[n - 1 - i] = weight;
+    }
+
+    (nodes, weights)
+}
+
+// Weight e^(-x²/2) on (-∞, ∞)
+pub fn gauss_hermite_e(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let (nodes, weights) = gauss_hermite(n);
+    let scale = std::f64::consts::SQRT_2;
+    (
+        nodes.into_iter().map(|x| scale * x).collect(),
+        weights.into_iter().map(|w| scale * w).collect(),
+    )
+}
+
+// Weight e^(-x) on [0, ∞)
+pub fn gauss_laguerre(n: usize) -> (Vec<f64>, Vec<f64>) {
+    gauss_generalized_laguerre(n, 0.0)
+}
+
+// Weight x^α e^(-x) on [0, ∞) for α > -1
+pub fn gauss_generalized_laguerre(n: usize, alpha: f64) -> (Vec<f64>, Vec<f64>) {
+    if alpha.is_nan() || alpha <= -1.0 {
+        return (vec![f64::NAN; n], vec![f64::NAN; n]);
+    }
+
+    let diagonal = (0..n).map(|k| 2.0 * k as f64 + alpha + 1.0).collect();
+    let off_diagonal = (1..n)
+        .map(|k| (k as f64 * (k as f64 + alpha)).sqrt())
+        .collect();
+    let mut nodes = golub_welsch_nodes(diagonal, off_diagonal);
+    let mut weights = vec![0.0; n];
+    let nf = n as f64;
+    let scale = (log_gamma(nf + alpha + 1.0) - log_gamma(nf + 1.0)).exp();
+
+    for (z, weight) in nodes.iter_mut().zip(weights.iter_mut()) {
+        let mut derivative = 1.0;
+        for _ in 0..MAX_NEWTON_ITERS {
+            let (p, previous) = laguerre_pair(n as u32, alpha, *z);
+            derivative = (nf * p - (nf + alpha) * previous) / *z;
+            let dz = p / derivative;
+            if !dz.is_finite() {
+                break;
+            }
+            *z -= dz;
+            if dz.abs() <= NEWTON_TOLERANCE * *z {
+                break;
+            }
+        }
+
+        // The polynomial only overflows this far out, where the weight underflows anyway
+        *weight = if derivative.is_finite() {
+            scale / (*z * derivative * derivative)
+        } else {
+            0.0
+        };
+    }
+
+    (nodes, weights)
+}
+
+// Weight (1 - x)^α (1 + x)^β on [-1, 1] for α, β > -1
+pub fn gauss_jacobi(n: usize, alpha: f64, beta: f64) -> (Vec<f64>, Vec<f64>) {
+    if alpha.is_nan() || beta.is_nan() || alpha <= -1.0 || beta <= -1.0 {
+        return (vec![f64::NAN; n], vec![f64::NAN; n]);
+    }
+
+    let ab = alpha + beta;
+    let diagonal = (0..n)
+        .map(|k| {
+            let c = 2.0 * k as f64 + ab;
+            if k == 0 {
+                (beta - alpha) / (ab + 2.0)
+            } else {
+                (beta * beta - alpha * alpha) / (c * (c + 2.0))
+            }
+        })
+        .collect();
+    let off_diagonal = (1..n)
+        .map(|k| {
+            let k = k as f64;
+            let c = 2.0 * k + ab;
+            // For k = 1 the factor k + α + β cancels against c - 1
+            let numerator = if k == 1.0 {
+                4.0 * (1.0 + alpha) * (1.0 + beta)
+            } else {
+                4.0 * k * (k + alpha) * (k + beta) * (k + ab) / (c - 1.0)
+            };
+            (numerator / (c * c * (c + 1.0))).sqrt()
+        })
+        .collect();
+    let mut nodes = golub_welsch_nodes(diagonal, off_diagonal);
+    let mut weights = vec![0.0; n];
+    let nf = n as f64;
+    let scale = (log_gamma(nf + alpha + 1.0) + log_gamma(nf + beta + 1.0)
+        - log_gamma(nf + 1.0)
+        - log_gamma(nf + ab + 1.0)
+        + (ab + 1.0) * std::f64::consts::LN_2)
+        .exp();
+    let c = 2.0 * nf + ab;
+
+    for (z, weight) in nodes.iter_mut().zip(weights.iter_mut()) {
+        let mut derivative = 1.0;
+        for _ in 0..MAX_NEWTON_ITERS {
+            let (p, previous) = jacobi_pair(n as u32, alpha, beta, *z);
+            derivative = (nf * (alpha - beta - c * *z) * p
+                + 2.0 * (nf + alpha) * (nf + beta) * previous)
+                / (c * (1.0 - *z * *z));
+            let dz = p / derivative;
+            if !dz.is_finite() {
+                break;
+            }
+            *z -= dz;
+            if dz.abs() <= NEWTON_TOLERANCE {
+                break;
+            }
+        }
+
+        *weight = scale / ((1.0 - *z * *z) * derivative * derivative);
+    }
+
+    (nodes, weights)
+}
+
+// Weight (1 - x²)^(λ - 1/2) on [-1, 1] for λ > -1/2
+pub fn gauss_gegenbauer(n: usize, lambda: f64) -> (Vec<f64>, Vec<f64>) {
+    gauss_jacobi(n, lambda - 0.5, lambda - 0.5)
+}
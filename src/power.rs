@@ -1,55 +1,57 @@
-pub fn sqrt(z: f64) -> f64 {
+use crate::float::Float;
+
+pub fn sqrt<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 0.0;
+    if z == T::ZERO {
+        return T::ZERO;
     }
 
-    if z < 0.0 {
-        return f64::NAN;
+    if z < T::ZERO {
+        return T::NAN;
     }
 
     if z.is_infinite() && z.is_sign_positive() {
-        return f64::INFINITY;
+        return T::INFINITY;
     }
 
     z.sqrt()
 }
 
-pub fn power(z: f64, a: f64) -> f64 {
+pub fn power<T: Float>(z: T, a: T) -> T {
     if z.is_nan() || a.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if a == 0.0 {
-        return 1.0;
+    if a == T::ZERO {
+        return T::ONE;
     }
 
-    if z == 0.0 {
-        if a > 0.0 {
-            return 0.0;
-        } else if a < 0.0 {
-            return f64::INFINITY;
+    if z == T::ZERO {
+        if a > T::ZERO {
+            return T::ZERO;
+        } else if a < T::ZERO {
+            return T::INFINITY;
         }
     }
 
-    if z == 1.0 {
-        return 1.0;
+    if z == T::ONE {
+        return T::ONE;
     }
 
-    if a.fract() == 0.0 && a.abs() < 1e10 {
-        let n = a as i64;
+    if a.fract() == T::ZERO && a.abs() < T::from_f64(1e10) {
+        let n = a.to_f64() as i64;
         return integer_power(z, n);
     }
 
-    if z < 0.0 && a.fract() != 0.0 {
-        return f64::NAN;
+    if z < T::ZERO && a.fract() != T::ZERO {
+        return T::NAN;
     }
 
-    if z < 0.0 && a.fract() == 0.0 {
-        let n = a as i64;
+    if z < T::ZERO && a.fract() == T::ZERO {
+        let n = a.to_f64() as i64;
         let is_odd = n % 2 != 0;
         let abs_result = (-z).powf(a);
         return if is_odd { -abs_result } else { abs_result };
@@ -58,20 +60,20 @@ pub fn power(z: f64, a: f64) -> f64 {
     z.powf(a)
 }
 
-fn integer_power(z: f64, n: i64) -> f64 {
+fn integer_power<T: Float>(z: T, n: i64) -> T {
     if n == 0 {
-        return 1.0;
+        return T::ONE;
     }
 
-    if z == 0.0 {
-        return if n > 0 { 0.0 } else { f64::INFINITY };
+    if z == T::ZERO {
+        return if n > 0 { T::ZERO } else { T::INFINITY };
     }
 
     if n < 0 {
-        return 1.0 / integer_power(z, -n);
+        return T::ONE / integer_power(z, -n);
     }
 
-    let mut result = 1.0;
+    let mut result = T::ONE;
     let mut base = z;
     let mut exp = n;
 
@@ -86,28 +88,28 @@ fn integer_power(z: f64, n: i64) -> f64 {
     result
 }
 
-pub fn square(z: f64) -> f64 {
+pub fn square<T: Float>(z: T) -> T {
     z * z
 }
 
-pub fn cube(z: f64) -> f64 {
+pub fn cube<T: Float>(z: T) -> T {
     z * z * z
 }
 
-pub fn nth_root(z: f64, n: i32) -> f64 {
+pub fn nth_root<T: Float>(z: T, n: i32) -> T {
     if n == 0 {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 0.0;
+    if z == T::ZERO {
+        return T::ZERO;
     }
 
-    if z < 0.0 {
+    if z < T::ZERO {
         return if n % 2 == 0 {
-            f64::NAN
+            T::NAN
         } else {
-            -(-z).powf(1.0 / n as f64)
+            -(-z).powf(T::ONE / T::from_f64(n as f64))
         };
     }
 
@@ -119,78 +121,77 @@ pub fn nth_root(z: f64, n: i32) -> f64 {
         return z.cbrt();
     }
 
-    z.powf(1.0 / n as f64)
+    z.powf(T::ONE / T::from_f64(n as f64))
 }
 
-pub fn cbrt(z: f64) -> f64 {
+pub fn cbrt<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 || z.is_infinite() {
+    if z == T::ZERO || z.is_infinite() {
         return z;
     }
 
     z.cbrt()
 }
 
-pub fn exp(z: f64) -> f64 {
+pub fn exp<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 1.0;
+    if z == T::ZERO {
+        return T::ONE;
     }
 
     if z.is_infinite() {
         return if z.is_sign_positive() {
-            f64::INFINITY
+            T::INFINITY
         } else {
-            0.0
+            T::ZERO
         };
     }
 
     z.exp()
 }
 
-pub fn exp2(z: f64) -> f64 {
+pub fn exp2<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 1.0;
+    if z == T::ZERO {
+        return T::ONE;
     }
 
     if z.is_infinite() {
         return if z.is_sign_positive() {
-            f64::INFINITY
+            T::INFINITY
         } else {
-            0.0
+            T::ZERO
         };
     }
 
     z.exp2()
 }
 
-pub fn exp10(z: f64) -> f64 {
+pub fn exp10<T: Float>(z: T) -> T {
     if z.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if z == 0.0 {
-        return 1.0;
+    if z == T::ZERO {
+        return T::ONE;
     }
 
     if z.is_infinite() {
         return if z.is_sign_positive() {
-            f64::INFINITY
+            T::INFINITY
         } else {
-            0.0
+            T::ZERO
         };
     }
 
-    const LN_10: f64 = std::f64::consts::LN_10;
-    (z * LN_10).exp()
+    (z * T::LN_10).exp()
 }
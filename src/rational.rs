@@ -0,0 +1,612 @@
+use crate::bigint::BigInt;
+use crate::number_theory::{gcd_u128, gcd_u64};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+// The integer types a Rational can be built on. Everything goes through owned
+// values and clones so that BigInt fits alongside the primitives; overflow of a
+// primitive behaves as it would for the primitive itself.
+pub trait Integer:
+    Clone
+    + Debug
+    + Display
+    + FromStr
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_i64(n: i64) -> Self;
+    fn from_bigint(n: &BigInt) -> Option<Self>;
+    fn to_bigint(&self) -> BigInt;
+    fn gcd(&self, other: &Self) -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+}
+
+macro_rules! impl_integer {
+    ($t:ty, $to:ident, $gcd:ident) => {
+        impl Integer for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_i64(n: i64) -> Self {
+                n as $t
+            }
+
+            fn from_bigint(n: &BigInt) -> Option<Self> {
+                n.$to()
+            }
+
+            fn to_bigint(&self) -> BigInt {
+                BigInt::from(*self)
+            }
+
+            // On the magnitudes, so that MIN has one. The only gcd that does not
+            // fit back is -MIN, from MIN and 0 or MIN, and dividing those by MIN
+            // reduces them just the same
+            fn gcd(&self, other: &Self) -> Self {
+                let g = $gcd(self.unsigned_abs(), other.unsigned_abs());
+                <$t>::try_from(g).unwrap_or(<$t>::MIN)
+            }
+        }
+    };
+}
+
+impl_integer!(i64, to_i64, gcd_u64);
+impl_integer!(i128, to_i128, gcd_u128);
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::ZERO
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn from_i64(n: i64) -> Self {
+        BigInt::from(n)
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        Some(n.clone())
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        BigInt::gcd(self, other)
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        BigInt::is_negative(self)
+    }
+}
+
+// numer / denom in lowest terms with a positive denominator, so that equal
+// values have equal representations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i64> {
+    numer: T,
+    denom: T,
+}
+
+pub type BigRational = Rational<BigInt>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError;
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rational number")
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+impl<T: Integer> Rational<T> {
+    pub fn new(numer: T, denom: T) -> Self {
+        if denom.is_zero() {
+            panic!("Denominator must be nonzero");
+        }
+
+        let g = numer.gcd(&denom);
+        let (numer, denom) = (numer / g.clone(), denom / g);
+        if denom.is_negative() {
+            Self::from_parts(-numer, -denom)
+        } else {
+            Self::from_parts(numer, denom)
+        }
+    }
+
+    // Already in lowest terms with a positive denominator
+    fn from_parts(numer: T, denom: T) -> Self {
+        Self { numer, denom }
+    }
+
+    pub fn from_integer(n: T) -> Self {
+        Self::from_parts(n, T::one())
+    }
+
+    pub fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn into_parts(self) -> (T, T) {
+        (self.numer, self.denom)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numer.is_negative()
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        if self.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        if self.is_negative() {
+            Self::from_parts(-self.denom.clone(), -self.numer.clone())
+        } else {
+            Self::from_parts(self.denom.clone(), self.numer.clone())
+        }
+    }
+
+    pub fn pow(&self, exponent: i32) -> Self {
+        let base = if exponent < 0 {
+            self.recip()
+        } else {
+            self.clone()
+        };
+
+        // Powers of coprime parts stay coprime, computed by square and multiply
+        let mut numer = T::one();
+        let mut denom = T::one();
+        let mut numer_square = base.numer;
+        let mut denom_square = base.denom;
+        let mut e = exponent.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {
+                numer = numer * numer_square.clone();
+                denom = denom * denom_square.clone();
+            }
+            e >>= 1;
+            if e > 0 {
+                numer_square = numer_square.clone() * numer_square;
+                denom_square = denom_square.clone() * denom_square;
+            }
+        }
+        Self::from_parts(numer, denom)
+    }
+
+    pub fn floor(&self) -> T {
+        let q = self.numer.clone() / self.denom.clone();
+        if self.is_negative() && !self.is_integer() {
+            q - T::one()
+        } else {
+            q
+        }
+    }
+
+    pub fn ceil(&self) -> T {
+        -(-self.clone()).floor()
+    }
+
+    pub fn trunc(&self) -> T {
+        self.numer.clone() / self.denom.clone()
+    }
+
+    // Nearest integer, halves rounded away from zero
+    pub fn round(&self) -> T {
+        let half = Self::from_parts(T::one(), T::from_i64(2));
+        if self.is_negative() {
+            (self.clone() - half).ceil()
+        } else {
+            (self.clone() + half).floor()
+        }
+    }
+
+    // self minus its truncation, with the sign of self
+    pub fn fract(&self) -> Self {
+        Self::from_parts(self.numer.clone() % self.denom.clone(), self.denom.clone())
+    }
+
+    // Nearest f64 from the top 64 bits of the quotient
+    pub fn to_f64(&self) -> f64 {
+        let numer = self.numer.to_bigint();
+        let denom = self.denom.to_bigint();
+        if numer.is_zero() {
+            return 0.0;
+        }
+
+        // Scale so that the integer quotient carries 64 significant bits, with
+        // a sticky lowest bit for a nonzero remainder so that the truncated
+        // quotient cannot land on a false halfway case when rounded to 53 bits
+        let shift = 64 + denom.bits() as i64 - numer.bits() as i64;
+        let (quotient, remainder) = if shift >= 0 {
+            (numer.abs() << shift as u32).div_rem(&denom)
+        } else {
+            numer.abs().div_rem(&(&denom << (-shift) as u32))
+        };
+        let quotient = if !remainder.is_zero() && quotient.is_even() {
+            quotient + BigInt::from(1)
+        } else {
+            quotient
+        };
+        let quotient = if numer.is_negative() {
+            -quotient
+        } else {
+            quotient
+        };
+
+        // Below 2^-1021 the spacing is fixed at 2^-1074, so rounding to 53 bits
+        // first would round subnormals twice. Round once to a multiple of
+        // 2^-1074 from the exact remainder instead.
+        if quotient.bits() as i64 - shift <= -1021 {
+            let (quotient, remainder) = (numer.abs() << 1074).div_rem(&denom);
+            let twice = remainder << 1;
+            let rounded = if twice > denom || (twice == denom && quotient.is_odd()) {
+                quotient + BigInt::from(1)
+            } else {
+                quotient
+            };
+            let magnitude = rounded.to_f64() * f64::from_bits(1);
+            return if numer.is_negative() {
+                -magnitude
+            } else {
+                magnitude
+            };
+        }
+
+        // Split the power of two so neither factor overflows on its own
+        let half = (-shift / 2) as i32;
+        quotient.to_f64() * 2f64.powi(half) * 2f64.powi(-shift as i32 - half)
+    }
+
+    // The exact value of x, None when it is not finite or does not fit in T
+    pub fn from_f64(x: f64) -> Option<Self> {
+        let exact = exact_f64(x)?;
+        Some(Self::from_parts(
+            T::from_bigint(&exact.numer)?,
+            T::from_bigint(&exact.denom)?,
+        ))
+    }
+
+    // The closest rational to x with denominator at most max_denominator, found
+    // from the continued fraction of the exact value of x and its semiconvergents
+    pub fn approximate(x: f64, max_denominator: T) -> Option<Self> {
+        if max_denominator < T::one() {
+            panic!("Maximum denominator must be positive");
+        }
+
+        let exact = exact_f64(x)?;
+        let best = limit_denominator(&exact, &max_denominator.to_bigint());
+        Some(Self::from_parts(
+            T::from_bigint(&best.numer)?,
+            T::from_bigint(&best.denom)?,
+        ))
+    }
+
+    // The full, finite continued fraction [a0; a1, a2, ...] of self, with the
+    // last term greater than 1 unless it is the only one
+    pub fn continued_fraction(&self) -> Vec<T> {
+        let mut terms = Vec::new();
+        let (mut numer, mut denom) = (self.numer.clone(), self.denom.clone());
+        let a = self.floor();
+        numer = numer - a.clone() * denom.clone();
+        terms.push(a);
+
+        while !numer.is_zero() {
+            (numer, denom) = (denom, numer);
+            let a = numer.clone() / denom.clone();
+            numer = numer - a.clone() * denom.clone();
+            terms.push(a);
+        }
+
+        terms
+    }
+}
+
+// The successive convergents p_k / q_k of the continued fraction [a0; a1, ...]
+pub fn convergents<T: Integer>(terms: &[T]) -> Vec<Rational<T>> {
+    let (mut p0, mut q0) = (T::one(), T::zero());
+    let (mut p1, mut q1) = (T::zero(), T::one());
+    let mut result = Vec::with_capacity(terms.len());
+
+    for a in terms {
+        let p = a.clone() * p0.clone() + p1;
+        let q = a.clone() * q0.clone() + q1;
+        (p1, q1) = (p0, q0);
+        (p0, q0) = (p.clone(), q.clone());
+        result.push(Rational::new(p, q));
+    }
+
+    result
+}
+
+// The first n terms of the continued fraction of the exact value of x, ending
+// early when the expansion terminates or a term does not fit in i64
+pub fn continued_fraction(x: f64, n: usize) -> Vec<i64> {
+    let Some(exact) = exact_f64(x) else {
+        return Vec::new();
+    };
+
+    exact
+        .continued_fraction()
+        .iter()
+        .take(n)
+        .map_while(|a| a.to_i64())
+        .collect()
+}
+
+// x = m 2^e exactly, None for infinities and NaN
+fn exact_f64(x: f64) -> Option<BigRational> {
+    if !x.is_finite() {
+        return None;
+    }
+
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, exponent - 1075)
+    };
+
+    let mut numer = BigInt::from(mantissa);
+    if x < 0.0 {
+        numer = -numer;
+    }
+
+    Some(if exponent >= 0 {
+        BigRational::from_integer(numer << exponent as u32)
+    } else {
+        BigRational::new(numer, BigInt::from(1) << (-exponent) as u32)
+    })
+}
+
+fn limit_denominator(x: &BigRational, max_denominator: &BigInt) -> BigRational {
+    if x.denom <= *max_denominator {
+        return x.clone();
+    }
+
+    let (mut p0, mut q0) = (BigInt::ZERO, BigInt::from(1));
+    let (mut p1, mut q1) = (BigInt::from(1), BigInt::ZERO);
+    let (mut numer, mut denom) = (x.numer.clone(), x.denom.clone());
+
+    loop {
+        let a = BigRational::new(numer.clone(), denom.clone()).floor();
+        let q2 = &q0 + &(&a * &q1);
+        if q2 > *max_denominator {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1.clone(), q1, &p0 + &(&a * &p1), q2);
+        (numer, denom) = (denom.clone(), numer - a * denom);
+    }
+
+    // The last convergent and the largest semiconvergent below the bound
+    let k = (max_denominator - &q0) / &q1;
+    let semiconvergent = BigRational::new(&p0 + &(&k * &p1), &q0 + &(&k * &q1));
+    let convergent = BigRational::new(p1, q1);
+
+    if (convergent.clone() - x.clone()).abs() <= (semiconvergent.clone() - x.clone()).abs() {
+        convergent
+    } else {
+        semiconvergent
+    }
+}
+
+impl<T: Integer> Default for Rational<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: Integer> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+// Accepts "n", "n/d" and decimals such as "-1.25"
+impl<T: Integer> FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| s.parse::<T>().map_err(|_| ParseRationalError);
+
+        if let Some((numer, denom)) = s.split_once('/') {
+            let denom = parse(denom)?;
+            if denom.is_zero() {
+                return Err(ParseRationalError);
+            }
+            return Ok(Self::new(parse(numer)?, denom));
+        }
+
+        if let Some((whole, fraction)) = s.split_once('.') {
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseRationalError);
+            }
+            let scale = parse(&format!("1{}", "0".repeat(fraction.len())))?;
+            let digits = parse(&format!("{}{}", whole, fraction))?;
+            return Ok(Self::new(digits, scale));
+        }
+
+        Ok(Self::from_integer(parse(s)?))
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.numer.clone() * other.denom.clone();
+        let rhs = other.numer.clone() * self.denom.clone();
+        lhs.cmp(&rhs)
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_parts(-self.numer, self.denom)
+    }
+}
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Over lcm(b, d) rather than b d to keep the intermediates small
+        let g = self.denom.gcd(&rhs.denom);
+        let left = rhs.denom.clone() / g.clone();
+        let right = self.denom.clone() / g;
+        Self::new(
+            self.numer * left.clone() + rhs.numer * right,
+            self.denom * left,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Cancelling across first leaves the product in lowest terms
+        let g1 = self.numer.gcd(&rhs.denom);
+        let g2 = rhs.numer.gcd(&self.denom);
+        Self::from_parts(
+            (self.numer / g1.clone()) * (rhs.numer / g2.clone()),
+            (self.denom / g2) * (rhs.denom / g1),
+        )
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+// Remainder of truncated division, with the sign of self as for integers
+impl<T: Integer> Rem for Rational<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let q = (self.clone() / rhs.clone()).trunc();
+        self - rhs * Self::from_integer(q)
+    }
+}
+
+macro_rules! impl_assign {
+    ($assign_trait:ident, $assign_method:ident, $method:ident) => {
+        impl<T: Integer> $assign_trait for Rational<T> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.clone().$method(rhs);
+            }
+        }
+    };
+}
+
+impl_assign!(AddAssign, add_assign, add);
+impl_assign!(SubAssign, sub_assign, sub);
+impl_assign!(MulAssign, mul_assign, mul);
+impl_assign!(DivAssign, div_assign, div);
+impl_assign!(RemAssign, rem_assign, rem);
+
+impl<T: Integer> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<T: Integer> Product for Rational<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
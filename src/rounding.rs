@@ -1,49 +1,51 @@
-pub fn floor(z: f64) -> f64 {
+use crate::float::Float;
+
+pub fn floor<T: Float>(z: T) -> T {
     if z.is_nan() || z.is_infinite() {
         return z;
     }
     z.floor()
 }
 
-pub fn ceiling(z: f64) -> f64 {
+pub fn ceiling<T: Float>(z: T) -> T {
     if z.is_nan() || z.is_infinite() {
         return z;
     }
     z.ceil()
 }
 
-pub fn round(z: f64) -> f64 {
+pub fn round<T: Float>(z: T) -> T {
     if z.is_nan() || z.is_infinite() {
         return z;
     }
     z.round()
 }
 
-pub fn integer_part(z: f64) -> f64 {
+pub fn integer_part<T: Float>(z: T) -> T {
     if z.is_nan() || z.is_infinite() {
         return z;
     }
     z.trunc()
 }
 
-pub fn fractional_part(z: f64) -> f64 {
+pub fn fractional_part<T: Float>(z: T) -> T {
     if z.is_nan() || z.is_infinite() {
-        return f64::NAN;
+        return T::NAN;
     }
     z - z.trunc()
 }
 
-pub fn modulo(m: f64, n: f64) -> f64 {
+pub fn modulo<T: Float>(m: T, n: T) -> T {
     if m.is_nan() || n.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if n == 0.0 {
-        return f64::NAN;
+    if n == T::ZERO {
+        return T::NAN;
     }
 
     if m.is_infinite() {
-        return f64::NAN;
+        return T::NAN;
     }
 
     if n.is_infinite() {
@@ -51,36 +53,36 @@ pub fn modulo(m: f64, n: f64) -> f64 {
     }
 
     let result = m % n;
-    if result.signum() != n.signum() && result != 0.0 {
+    if result.signum() != n.signum() && result != T::ZERO {
         result + n
     } else {
         result
     }
 }
 
-pub fn quotient(m: f64, n: f64) -> f64 {
+pub fn quotient<T: Float>(m: T, n: T) -> T {
     if m.is_nan() || n.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if n == 0.0 {
-        return if m == 0.0 {
-            f64::NAN
+    if n == T::ZERO {
+        return if m == T::ZERO {
+            T::NAN
         } else {
-            f64::INFINITY * m.signum()
+            T::INFINITY * m.signum()
         };
     }
 
     if m.is_infinite() {
         return if n.is_infinite() {
-            f64::NAN
+            T::NAN
         } else {
-            f64::INFINITY * m.signum() * n.signum()
+            T::INFINITY * m.signum() * n.signum()
         };
     }
 
     if n.is_infinite() {
-        return 0.0;
+        return T::ZERO;
     }
 
     floor(m / n)
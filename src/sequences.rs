@@ -1,3 +1,5 @@
+use crate::bigint::BigInt;
+
 pub fn fibonacci(n: i32) -> i64 {
     if n == 0 {
         return 0;
@@ -58,3 +60,44 @@ pub fn lucas(n: i32) -> i64 {
 
     b
 }
+
+// (F(n), F(n + 1)) by fast doubling: F(2k) = F(k) (2 F(k + 1) - F(k)) and
+// F(2k + 1) = F(k)² + F(k + 1)²
+fn fibonacci_pair(n: u32) -> (BigInt, BigInt) {
+    let mut a = BigInt::ZERO;
+    let mut b = BigInt::from(1);
+
+    for bit in (0..32 - n.leading_zeros()).rev() {
+        let c = &a * &(&b + &b - &a);
+        let d = &a * &a + &b * &b;
+        if (n >> bit) & 1 == 0 {
+            a = c;
+            b = d;
+        } else {
+            b = &c + &d;
+            a = d;
+        }
+    }
+
+    (a, b)
+}
+
+pub fn fibonacci_big(n: i32) -> BigInt {
+    let (f, _) = fibonacci_pair(n.unsigned_abs());
+    if n < 0 && n % 2 == 0 {
+        -f
+    } else {
+        f
+    }
+}
+
+pub fn lucas_big(n: i32) -> BigInt {
+    // L(n) = 2 F(n + 1) - F(n)
+    let (f, g) = fibonacci_pair(n.unsigned_abs());
+    let l = &g + &g - f;
+    if n < 0 && n % 2 != 0 {
+        -l
+    } else {
+        l
+    }
+}
@@ -0,0 +1,387 @@
+use super::{bessel_ik_scaled, bessel_jy};
+use crate::float::Float;
+use std::f64::consts::PI;
+
+const MAX_ITERS: usize = 100;
+const EPSILON: f64 = 1e-17;
+
+// Ai(0), -Ai'(0), and √3 for Bi = √3 (c1 f + c2 g)
+const AI_0: f64 = 0.3550280538878172;
+const AI_PRIME_0: f64 = 0.2588194037928068;
+const SQRT_3: f64 = 1.7320508075688772;
+const FRAC_1_SQRT_3: f64 = 0.5773502691896257;
+const FRAC_1_SQRT_PI: f64 = 0.5641895835477563;
+
+// 2/3 as an unevaluated sum
+const TWO_THIRDS: f64 = 0.6666666666666666;
+const TWO_THIRDS_LO: f64 = 3.700743415417188e-17;
+
+// Largest |x| for which the Maclaurin series is used
+const SERIES_MAX_X: f64 = 1.0;
+
+// Smallest ζ for which the oscillatory asymptotic expansion reaches full precision
+const ASYMPTOTIC_MIN_ZETA: f64 = 18.0;
+const ASYMPTOTIC_MIN_ZETA_F32: f64 = 9.0;
+
+// ζ = 2/3 x^(3/2) for x > 0 as an unevaluated sum, so the phase stays exact for large x
+fn zeta_parts<T: Float>(x: T) -> (T, T) {
+    let root = x.sqrt();
+    let root_lo = (-root).mul_add(root, x) / (T::TWO * root);
+
+    let power = x * root;
+    let power_lo = x.mul_add(root, -power) + x * root_lo;
+
+    // Rounding 2/3 to T moves its error into the low part
+    let two_thirds = T::from_f64(TWO_THIRDS);
+    let two_thirds_lo = T::from_f64(TWO_THIRDS - two_thirds.to_f64() + TWO_THIRDS_LO);
+
+    let zeta = power * two_thirds;
+    let zeta_lo = power.mul_add(two_thirds, -zeta) + power * two_thirds_lo + power_lo * two_thirds;
+    (zeta, zeta_lo)
+}
+
+// Ai = c1 f - c2 g and Bi = √3 (c1 f + c2 g), with f and g the two Maclaurin series
+fn airy_series<T: Float>(x: T) -> (T, T, T, T) {
+    let epsilon = T::tolerance(EPSILON);
+    let cube = x * x * x;
+
+    let mut f_term = T::ONE;
+    let mut g_term = x;
+    let mut f_prime_term = T::HALF * x * x;
+    let mut g_prime_term = T::ONE;
+    let (mut f, mut g) = (f_term, g_term);
+    let (mut f_prime, mut g_prime) = (f_prime_term, g_prime_term);
+
+    for k in 0..MAX_ITERS {
+        let k = T::from_f64(k as f64);
+        f_term *=
+            cube / ((T::from_f64(3.0) * k + T::TWO) * (T::from_f64(3.0) * k + T::from_f64(3.0)));
+        g_term *= cube
+            / ((T::from_f64(3.0) * k + T::from_f64(3.0))
+                * (T::from_f64(3.0) * k + T::from_f64(4.0)));
+        f_prime_term *= cube
+            / ((T::from_f64(3.0) * k + T::from_f64(3.0))
+                * (T::from_f64(3.0) * k + T::from_f64(5.0)));
+        g_prime_term *=
+            cube / ((T::from_f64(3.0) * k + T::ONE) * (T::from_f64(3.0) * k + T::from_f64(3.0)));
+
+        f += f_term;
+        g += g_term;
+        f_prime += f_prime_term;
+        g_prime += g_prime_term;
+
+        if f_term.abs() <= epsilon * f.abs() && g_term.abs() <= epsilon * g.abs() {
+            break;
+        }
+    }
+
+    (
+        T::from_f64(AI_0) * f - T::from_f64(AI_PRIME_0) * g,
+        T::from_f64(AI_0) * f_prime - T::from_f64(AI_PRIME_0) * g_prime,
+        T::from_f64(SQRT_3) * (T::from_f64(AI_0) * f + T::from_f64(AI_PRIME_0) * g),
+        T::from_f64(SQRT_3) * (T::from_f64(AI_0) * f_prime + T::from_f64(AI_PRIME_0) * g_prime),
+    )
+}
+
+// e^ζ Ai, e^ζ Ai', e^-ζ Bi and e^-ζ Bi' for x > 0 through the modified Bessel functions
+fn airy_positive_scaled<T: Float>(x: T, zeta: T) -> (T, T, T, T) {
+    let (i_third, k_third) = bessel_ik_scaled(T::from_f64(1.0 / 3.0), zeta);
+    let (i_two_thirds, k_two_thirds) = bessel_ik_scaled(T::TWO / T::from_f64(3.0), zeta);
+
+    // I_-ν + I_ν = 2 I_ν + (2/π) sin(νπ) K_ν, and sin(π/3) = sin(2π/3) = √3/2
+    let decay = (-T::TWO * zeta).exp() * T::from_f64(SQRT_3) * T::FRAC_1_PI;
+    let root = (x / T::from_f64(3.0)).sqrt();
+
+    (
+        T::FRAC_1_PI * root * k_third,
+        -T::FRAC_1_PI * x * T::from_f64(FRAC_1_SQRT_3) * k_two_thirds,
+        root * (T::TWO * i_third + decay * k_third),
+        x * T::from_f64(FRAC_1_SQRT_3) * (T::TWO * i_two_thirds + decay * k_two_thirds),
+    )
+}
+
+// Ai(-x), Ai'(-x), Bi(-x) and Bi'(-x) for x > 0 through J and Y of orders 1/3 and 2/3
+fn airy_negative_bessel<T: Float>(x: T, zeta: T) -> (T, T, T, T) {
+    let (j_third, y_third) = bessel_jy(T::from_f64(1.0 / 3.0), zeta);
+    let (j_two_thirds, y_two_thirds) = bessel_jy(T::TWO / T::from_f64(3.0), zeta);
+    let half_root = T::HALF * x.sqrt();
+
+    (
+        half_root * (j_third - T::from_f64(FRAC_1_SQRT_3) * y_third),
+        T::HALF * x * (j_two_thirds + T::from_f64(FRAC_1_SQRT_3) * y_two_thirds),
+        -half_root * (T::from_f64(FRAC_1_SQRT_3) * j_third + y_third),
+        T::HALF * x * (T::from_f64(FRAC_1_SQRT_3) * j_two_thirds - y_two_thirds),
+    )
+}
+
+// Ai(-x), Ai'(-x), Bi(-x) and Bi'(-x) for large x from the modulus and phase expansions
+fn airy_negative_asymptotic<T: Float>(x: T, zeta: T, zeta_lo: T) -> (T, T, T, T) {
+    let epsilon = T::tolerance(EPSILON);
+
+    // P, Q for the functions and R, S for the derivatives, alternating in sign by pairs
+    let (mut p, mut q, mut r, mut s) = (T::ONE, T::ZERO, T::ONE, T::ZERO);
+    let mut u = T::ONE;
+    let mut power = T::ONE;
+    let mut previous = T::ONE;
+    for k in 1..MAX_ITERS {
+        let kf = T::from_f64(k as f64);
+        u *= (T::from_f64(6.0) * kf - T::from_f64(5.0))
+            * (T::from_f64(6.0) * kf - T::from_f64(3.0))
+            * (T::from_f64(6.0) * kf - T::ONE)
+            / (T::from_f64(216.0) * kf * (T::TWO * kf - T::ONE));
+        power /= zeta;
+        let term = u * power;
+        if term.abs() <= epsilon || term.abs() > previous {
+            break;
+        }
+        previous = term.abs();
+
+        let v_term = -(T::from_f64(6.0) * kf + T::ONE) / (T::from_f64(6.0) * kf - T::ONE) * term;
+        let sign = if (k / 2) % 2 == 0 { T::ONE } else { -T::ONE };
+
+        if k % 2 == 0 {
+            p += sign * term;
+            r += sign * v_term;
+        } else {
+            q += sign * term;
+            s += sign * v_term;
+        }
+    }
+
+    // θ = ζ - π/4, with the low part of ζ folded in by the angle addition formulas
+    let (sin_hi, cos_hi) = zeta.sin_cos();
+    let (sin_delta, cos_delta) = (zeta_lo - T::FRAC_PI_4).sin_cos();
+    let sin = sin_hi * cos_delta + cos_hi * sin_delta;
+    let cos = cos_hi * cos_delta - sin_hi * sin_delta;
+
+    let quarter = x.sqrt().sqrt();
+    let amplitude = T::from_f64(FRAC_1_SQRT_PI) / quarter;
+    let derivative_amplitude = T::from_f64(FRAC_1_SQRT_PI) * quarter;
+
+    (
+        amplitude * (cos * p + sin * q),
+        derivative_amplitude * (sin * r - cos * s),
+        amplitude * (cos * q - sin * p),
+        derivative_amplitude * (cos * r + sin * s),
+    )
+}
+
+// Ai, Ai', Bi and Bi' at a finite x, with Ai, Ai' multiplied by e^ζ and Bi, Bi'
+// by e^-ζ when x > 0; the second value is ζ = 2/3 x^(3/2) as (hi, lo), or zero
+fn airy_scaled<T: Float>(x: T) -> ((T, T, T, T), (T, T)) {
+    if x.abs() <= T::from_f64(SERIES_MAX_X) {
+        let (ai, ai_prime, bi, bi_prime) = airy_series(x);
+        if x <= T::ZERO {
+            return ((ai, ai_prime, bi, bi_prime), (T::ZERO, T::ZERO));
+        }
+        let (zeta, zeta_lo) = zeta_parts(x);
+        let grow = (zeta + zeta_lo).exp();
+        let shrink = T::ONE / grow;
+        return (
+            (ai * grow, ai_prime * grow, bi * shrink, bi_prime * shrink),
+            (zeta, zeta_lo),
+        );
+    }
+
+    let (zeta, zeta_lo) = zeta_parts(x.abs());
+    if x > T::ZERO {
+        return (airy_positive_scaled(x, zeta), (zeta, zeta_lo));
+    }
+
+    let values = if zeta >= T::by_precision(ASYMPTOTIC_MIN_ZETA_F32, ASYMPTOTIC_MIN_ZETA) {
+        airy_negative_asymptotic(-x, zeta, zeta_lo)
+    } else {
+        airy_negative_bessel(-x, zeta)
+    };
+    (values, (T::ZERO, T::ZERO))
+}
+
+// e^(-ζ) for the (hi, lo) parts of ζ
+fn decay<T: Float>(zeta: T, zeta_lo: T) -> T {
+    (-zeta).exp() * (-zeta_lo).exp()
+}
+
+// value e^ζ without overflowing before the product for ζ up to twice the exp limit
+fn grow<T: Float>(value: T, zeta: T, zeta_lo: T) -> T {
+    let half = (T::HALF * zeta).exp();
+    value * half * (zeta_lo.exp() * half)
+}
+
+pub fn airy<T: Float>(x: T) -> (T, T, T, T) {
+    (
+        airy_ai(x),
+        airy_ai_derivative(x),
+        airy_bi(x),
+        airy_bi_derivative(x),
+    )
+}
+
+pub fn airy_ai<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return T::ZERO;
+    }
+
+    let ((ai, _, _, _), (zeta, zeta_lo)) = airy_scaled(x);
+    ai * decay(zeta, zeta_lo)
+}
+
+pub fn airy_ai_derivative<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        // The oscillations grow like |x|^(1/4) on the negative axis
+        return if x > T::ZERO { -T::ZERO } else { T::NAN };
+    }
+
+    let ((_, ai_prime, _, _), (zeta, zeta_lo)) = airy_scaled(x);
+    ai_prime * decay(zeta, zeta_lo)
+}
+
+pub fn airy_bi<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x > T::ZERO { T::INFINITY } else { T::ZERO };
+    }
+
+    let ((_, _, bi, _), (zeta, zeta_lo)) = airy_scaled(x);
+    grow(bi, zeta, zeta_lo)
+}
+
+pub fn airy_bi_derivative<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x > T::ZERO { T::INFINITY } else { T::NAN };
+    }
+
+    let ((_, _, _, bi_prime), (zeta, zeta_lo)) = airy_scaled(x);
+    grow(bi_prime, zeta, zeta_lo)
+}
+
+// e^ζ Ai(x) for x > 0 and Ai(x) otherwise
+pub fn airy_ai_scaled<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return T::ZERO;
+    }
+
+    airy_scaled(x).0 .0
+}
+
+pub fn airy_ai_derivative_scaled<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x > T::ZERO { T::NEG_INFINITY } else { T::NAN };
+    }
+
+    airy_scaled(x).0 .1
+}
+
+// e^-ζ Bi(x) for x > 0 and Bi(x) otherwise
+pub fn airy_bi_scaled<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return T::ZERO;
+    }
+
+    airy_scaled(x).0 .2
+}
+
+pub fn airy_bi_derivative_scaled<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x > T::ZERO { T::INFINITY } else { T::NAN };
+    }
+
+    airy_scaled(x).0 .3
+}
+
+// T(t) ~ t^(2/3) (1 + 5/48 t^-2 - 5/36 t^-4 + ...), summed while the terms shrink
+fn zero_asymptotic<T: Float>(t: T) -> T {
+    const COEFFICIENTS: [f64; 6] = [
+        1.0,
+        5.0 / 48.0,
+        -5.0 / 36.0,
+        77125.0 / 82944.0,
+        -108056875.0 / 6967296.0,
+        162375596875.0 / 334430208.0,
+    ];
+
+    let inverse_square = T::ONE / (t * t);
+    let mut sum = T::ZERO;
+    let mut power = T::ONE;
+    let mut previous = T::INFINITY;
+    for c in COEFFICIENTS {
+        let term = T::from_f64(c) * power;
+        if term.abs() >= previous {
+            break;
+        }
+        sum += term;
+        previous = term.abs();
+        power *= inverse_square;
+    }
+
+    t.powf(T::TWO / T::from_f64(3.0)) * sum
+}
+
+// Newton's method on f/f' from the asymptotic estimate of the zero
+fn refine_zero<T: Float>(mut x: T, f: impl Fn(T) -> (T, T)) -> T {
+    for _ in 0..MAX_ITERS {
+        let (value, derivative) = f(x);
+        let step = value / derivative;
+        x -= step;
+        if step.abs() <= T::from_f64(4.0) * T::EPSILON * x.abs() {
+            break;
+        }
+    }
+
+    x
+}
+
+// The k-th zero of Ai, counting from the one closest to the origin
+pub fn airy_ai_zero<T: Float>(k: u32) -> T {
+    if k == 0 {
+        return T::NAN;
+    }
+
+    let guess = -zero_asymptotic(T::from_f64(3.0 * PI / 8.0 * (4.0 * k as f64 - 1.0)));
+    refine_zero(guess, |x| {
+        let ((ai, ai_prime, _, _), _) = airy_scaled(x);
+        (ai, ai_prime)
+    })
+}
+
+pub fn airy_bi_zero<T: Float>(k: u32) -> T {
+    if k == 0 {
+        return T::NAN;
+    }
+
+    let guess = -zero_asymptotic(T::from_f64(3.0 * PI / 8.0 * (4.0 * k as f64 - 3.0)));
+    refine_zero(guess, |x| {
+        let ((_, _, bi, bi_prime), _) = airy_scaled(x);
+        (bi, bi_prime)
+    })
+}
@@ -0,0 +1,494 @@
+use crate::float::Float;
+
+const MAX_ITERS: usize = 64;
+const EPSILON: f64 = 1e-16;
+
+// Once every argument is within these relative distances of their mean the
+// truncated Taylor series of the Carlson duplication are exact to double precision,
+// or to single precision for the larger f32 values
+const RF_TOLERANCE: (f64, f64) = (0.08, 0.0025);
+const RD_TOLERANCE: (f64, f64) = (0.05, 0.0015);
+const RC_TOLERANCE: (f64, f64) = (0.04, 0.0012);
+
+fn tolerance<T: Float>(tolerance: (f64, f64)) -> T {
+    T::by_precision(tolerance.0, tolerance.1)
+}
+
+fn has_two_zeros<T: Float>(x: T, y: T, z: T) -> bool {
+    (x == T::ZERO) as u8 + (y == T::ZERO) as u8 + (z == T::ZERO) as u8 >= 2
+}
+
+pub fn carlson_rf<T: Float>(x: T, y: T, z: T) -> T {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < T::ZERO || y < T::ZERO || z < T::ZERO {
+        return T::NAN;
+    }
+
+    if has_two_zeros(x, y, z) {
+        return T::INFINITY;
+    }
+
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return T::ZERO;
+    }
+
+    let (mut x, mut y, mut z) = (x, y, z);
+    let (mut mean, mut dx, mut dy, mut dz);
+
+    let mut iterations = 0;
+    loop {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        x = T::from_f64(0.25) * (x + lambda);
+        y = T::from_f64(0.25) * (y + lambda);
+        z = T::from_f64(0.25) * (z + lambda);
+
+        mean = (x + y + z) / T::from_f64(3.0);
+        dx = (mean - x) / mean;
+        dy = (mean - y) / mean;
+        dz = (mean - z) / mean;
+
+        iterations += 1;
+        if dx.abs().max(dy.abs()).max(dz.abs()) <= tolerance(RF_TOLERANCE)
+            || iterations >= MAX_ITERS
+        {
+            break;
+        }
+    }
+
+    let e2 = dx * dy - dz * dz;
+    let e3 = dx * dy * dz;
+    (T::ONE
+        + (e2 / T::from_f64(24.0) - T::from_f64(0.1) - T::from_f64(3.0 / 44.0) * e3) * e2
+        + e3 / T::from_f64(14.0))
+        / mean.sqrt()
+}
+
+pub fn carlson_rd<T: Float>(x: T, y: T, z: T) -> T {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < T::ZERO || y < T::ZERO || z < T::ZERO {
+        return T::NAN;
+    }
+
+    if z == T::ZERO || (x == T::ZERO && y == T::ZERO) {
+        return T::INFINITY;
+    }
+
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return T::ZERO;
+    }
+
+    let (mut x, mut y, mut z) = (x, y, z);
+    let (mut mean, mut dx, mut dy, mut dz);
+    let mut sum = T::ZERO;
+    let mut scale = T::ONE;
+
+    let mut iterations = 0;
+    loop {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        sum += scale / (sz * (z + lambda));
+        scale *= T::from_f64(0.25);
+        x = T::from_f64(0.25) * (x + lambda);
+        y = T::from_f64(0.25) * (y + lambda);
+        z = T::from_f64(0.25) * (z + lambda);
+
+        mean = T::from_f64(0.2) * (x + y + T::from_f64(3.0) * z);
+        dx = (mean - x) / mean;
+        dy = (mean - y) / mean;
+        dz = (mean - z) / mean;
+
+        iterations += 1;
+        if dx.abs().max(dy.abs()).max(dz.abs()) <= tolerance(RD_TOLERANCE)
+            || iterations >= MAX_ITERS
+        {
+            break;
+        }
+    }
+
+    let ea = dx * dy;
+    let eb = dz * dz;
+    let ec = ea - eb;
+    let ed = ea - T::from_f64(6.0) * eb;
+    let ee = ed + T::TWO * ec;
+
+    let series = T::ONE
+        + ed * (-T::from_f64(3.0 / 14.0) + T::from_f64(9.0 / 88.0) * ed
+            - T::from_f64(9.0 / 52.0) * dz * ee)
+        + dz * (ee / T::from_f64(6.0)
+            + dz * (-T::from_f64(9.0 / 22.0) * ec
+                + dz * T::from_f64(3.0) / T::from_f64(26.0) * ea));
+
+    T::from_f64(3.0) * sum + scale * series / (mean * mean.sqrt())
+}
+
+pub fn carlson_rc<T: Float>(x: T, y: T) -> T {
+    if x.is_nan() || y.is_nan() || x < T::ZERO {
+        return T::NAN;
+    }
+
+    if y == T::ZERO {
+        return T::INFINITY;
+    }
+
+    if x.is_infinite() || y.is_infinite() {
+        return T::ZERO;
+    }
+
+    // Cauchy principal value for negative y
+    let (mut x, mut y, weight) = if y > T::ZERO {
+        (x, y, T::ONE)
+    } else {
+        (x - y, -y, x.sqrt() / (x - y).sqrt())
+    };
+
+    let (mut mean, mut s);
+    let mut iterations = 0;
+    loop {
+        let lambda = T::TWO * x.sqrt() * y.sqrt() + y;
+        x = T::from_f64(0.25) * (x + lambda);
+        y = T::from_f64(0.25) * (y + lambda);
+
+        mean = (x + T::TWO * y) / T::from_f64(3.0);
+        s = (y - mean) / mean;
+
+        iterations += 1;
+        if s.abs() <= tolerance(RC_TOLERANCE) || iterations >= MAX_ITERS {
+            break;
+        }
+    }
+
+    let series = T::ONE
+        + s * s
+            * (T::from_f64(0.3)
+                + s * (T::from_f64(1.0 / 7.0)
+                    + s * (T::from_f64(0.375) + s * T::from_f64(9.0) / T::from_f64(22.0))));
+    weight * series / mean.sqrt()
+}
+
+pub fn carlson_rj<T: Float>(x: T, y: T, z: T, p: T) -> T {
+    if x.is_nan()
+        || y.is_nan()
+        || z.is_nan()
+        || p.is_nan()
+        || x < T::ZERO
+        || y < T::ZERO
+        || z < T::ZERO
+    {
+        return T::NAN;
+    }
+
+    if p == T::ZERO || has_two_zeros(x, y, z) {
+        return T::INFINITY;
+    }
+
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() || p.is_infinite() {
+        return T::ZERO;
+    }
+
+    // For negative p the Cauchy principal value is rebuilt from RJ at a
+    // positive p and the RC, RF terms of Carlson's transformation
+    let (mut x, mut y, mut z, mut p, principal) = if p > T::ZERO {
+        (x, y, z, p, None)
+    } else {
+        let low = x.min(y).min(z);
+        let high = x.max(y).max(z);
+        let middle = x + y + z - low - high;
+        let a = T::ONE / (middle - p);
+        let b = a * (high - middle) * (middle - low);
+        let q = middle + b;
+        let rc = carlson_rc(low * high / middle, p * q / middle);
+        (low, middle, high, q, Some((a, b, rc)))
+    };
+
+    let (mut mean, mut dx, mut dy, mut dz, mut dp);
+    let mut sum = T::ZERO;
+    let mut scale = T::ONE;
+
+    let mut iterations = 0;
+    loop {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        let alpha = (p * (sx + sy + sz) + sx * sy * sz).powi(2);
+        let beta = p * (p + lambda).powi(2);
+        sum += scale * carlson_rc(alpha, beta);
+        scale *= T::from_f64(0.25);
+        x = T::from_f64(0.25) * (x + lambda);
+        y = T::from_f64(0.25) * (y + lambda);
+        z = T::from_f64(0.25) * (z + lambda);
+        p = T::from_f64(0.25) * (p + lambda);
+
+        mean = T::from_f64(0.2) * (x + y + z + T::TWO * p);
+        dx = (mean - x) / mean;
+        dy = (mean - y) / mean;
+        dz = (mean - z) / mean;
+        dp = (mean - p) / mean;
+
+        iterations += 1;
+        let error = dx.abs().max(dy.abs()).max(dz.abs()).max(dp.abs());
+        if error <= tolerance(RD_TOLERANCE) || iterations >= MAX_ITERS {
+            break;
+        }
+    }
+
+    let ea = dx * (dy + dz) + dy * dz;
+    let eb = dx * dy * dz;
+    let ec = dp * dp;
+    let ed = ea - T::from_f64(3.0) * ec;
+    let ee = eb + T::TWO * dp * (ea - ec);
+
+    let series = T::ONE
+        + ed * (-T::from_f64(3.0 / 14.0) + T::from_f64(9.0 / 88.0) * ed
+            - T::from_f64(9.0 / 52.0) * ee)
+        + eb * (T::from_f64(1.0 / 6.0)
+            + dp * (-T::from_f64(3.0 / 11.0) + dp * T::from_f64(3.0) / T::from_f64(26.0)))
+        + dp * ea * (T::from_f64(1.0 / 3.0) - dp * T::from_f64(3.0) / T::from_f64(22.0))
+        - dp * ec / T::from_f64(3.0);
+    let value = T::from_f64(3.0) * sum + scale * series / (mean * mean.sqrt());
+
+    match principal {
+        // RF is invariant under the duplication steps, so the reduced arguments can be used
+        Some((a, b, rc)) => a * (b * value + T::from_f64(3.0) * (rc - carlson_rf(x, y, z))),
+        None => value,
+    }
+}
+
+pub fn elliptic_k<T: Float>(m: T) -> T {
+    if m.is_nan() || m > T::ONE {
+        return T::NAN;
+    }
+
+    if m == T::ONE {
+        return T::INFINITY;
+    }
+
+    if m == T::NEG_INFINITY {
+        return T::ZERO;
+    }
+
+    carlson_rf(T::ZERO, T::ONE - m, T::ONE)
+}
+
+pub fn elliptic_e<T: Float>(m: T) -> T {
+    if m.is_nan() || m > T::ONE {
+        return T::NAN;
+    }
+
+    if m == T::ONE {
+        return T::ONE;
+    }
+
+    if m == T::NEG_INFINITY {
+        return T::INFINITY;
+    }
+
+    let y = T::ONE - m;
+    if m < T::HALF {
+        return carlson_rf(T::ZERO, y, T::ONE)
+            - m / T::from_f64(3.0) * carlson_rd(T::ZERO, y, T::ONE);
+    }
+
+    // Near m = 1 the form above cancels, while this one only adds positive terms
+    if y < T::by_precision(1e-30, 1e-150) {
+        return T::ONE;
+    }
+    y / T::from_f64(3.0) * (carlson_rd(T::ZERO, y, T::ONE) + carlson_rd(T::ZERO, T::ONE, y))
+}
+
+// Splits φ = φ' + kπ with |φ'| <= π/2, returning sin φ', cos² φ' and k
+fn reduce_amplitude<T: Float>(phi: T) -> (T, T, T) {
+    let k = (phi / T::PI).round();
+    let reduced = phi - k * T::PI;
+    let cos = reduced.cos();
+    (reduced.sin(), cos * cos, k)
+}
+
+// 1 - m sin² φ, written so that it does not cancel for m <= 1
+fn delta_squared<T: Float>(sin: T, cos_squared: T, m: T) -> T {
+    if m <= T::ONE {
+        cos_squared + (T::ONE - m) * sin * sin
+    } else {
+        T::ONE - m * sin * sin
+    }
+}
+
+pub fn elliptic_f<T: Float>(phi: T, m: T) -> T {
+    if phi.is_nan() || m.is_nan() {
+        return T::NAN;
+    }
+
+    if phi.is_infinite() {
+        return if m <= T::ONE { phi } else { T::NAN };
+    }
+
+    let (sin, cos_squared, k) = reduce_amplitude(phi);
+    let delta = delta_squared(sin, cos_squared, m);
+    let value = sin * carlson_rf(cos_squared, delta, T::ONE);
+
+    if k == T::ZERO {
+        value
+    } else {
+        value + T::TWO * k * elliptic_k(m)
+    }
+}
+
+pub fn elliptic_e_incomplete<T: Float>(phi: T, m: T) -> T {
+    if phi.is_nan() || m.is_nan() {
+        return T::NAN;
+    }
+
+    if phi.is_infinite() {
+        return if m <= T::ONE { phi } else { T::NAN };
+    }
+
+    let (sin, cos_squared, k) = reduce_amplitude(phi);
+
+    // E(φ, 1) = sin φ, where both Carlson terms are infinite at φ = π/2
+    let value = if m == T::ONE {
+        sin
+    } else {
+        let delta = delta_squared(sin, cos_squared, m);
+        sin * carlson_rf(cos_squared, delta, T::ONE)
+            - m / T::from_f64(3.0) * sin.powi(3) * carlson_rd(cos_squared, delta, T::ONE)
+    };
+
+    if k == T::ZERO {
+        value
+    } else {
+        value + T::TWO * k * elliptic_e(m)
+    }
+}
+
+// Π(n, φ, m) = ∫ dθ / ((1 - n sin² θ) √(1 - m sin² θ)) over [0, φ], taken as
+// a principal value when 1 - n sin² θ changes sign
+pub fn elliptic_pi<T: Float>(n: T, phi: T, m: T) -> T {
+    if n.is_nan() || phi.is_nan() || m.is_nan() {
+        return T::NAN;
+    }
+
+    if phi.is_infinite() {
+        return if m <= T::ONE && n < T::ONE {
+            phi
+        } else {
+            T::NAN
+        };
+    }
+
+    let (sin, cos_squared, k) = reduce_amplitude(phi);
+    let delta = delta_squared(sin, cos_squared, m);
+    let value = sin * carlson_rf(cos_squared, delta, T::ONE)
+        + n / T::from_f64(3.0)
+            * sin.powi(3)
+            * carlson_rj(cos_squared, delta, T::ONE, T::ONE - n * sin * sin);
+
+    if k == T::ZERO {
+        return value;
+    }
+
+    let y = T::ONE - m;
+    let complete = carlson_rf(T::ZERO, y, T::ONE)
+        + n / T::from_f64(3.0) * carlson_rj(T::ZERO, y, T::ONE, T::ONE - n);
+    value + T::TWO * k * complete
+}
+
+// am(u | m) for m < 1 by the descending Landen transformation
+fn jacobi_amplitude_landen<T: Float>(u: T, m: T) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let mut a = [T::ZERO; MAX_ITERS];
+    let mut c = [T::ZERO; MAX_ITERS];
+
+    // Arithmetic-geometric mean of 1 and √(1 - m), with c_n = (a_{n-1} - b_{n-1}) / 2
+    // carried in a form that does not cancel
+    let mut b = (T::ONE - m).sqrt();
+    a[0] = T::ONE;
+    c[1] = m / (T::TWO * (T::ONE + b));
+    let mut n = 1;
+    loop {
+        a[n] = T::HALF * (a[n - 1] + b);
+        b = (a[n - 1] * b).sqrt();
+        if n > 1 {
+            c[n] = c[n - 1] * c[n - 1] / (T::from_f64(4.0) * a[n]);
+        }
+        if c[n].abs() <= epsilon * a[n] || n + 1 >= MAX_ITERS {
+            break;
+        }
+        n += 1;
+    }
+
+    let mut phi = T::TWO.powi(n as i32) * a[n] * u;
+    for k in (1..=n).rev() {
+        phi = T::HALF * (phi + (c[k] / a[k] * phi.sin()).asin());
+    }
+
+    phi
+}
+
+pub fn jacobi_amplitude<T: Float>(u: T, m: T) -> T {
+    if u.is_nan() || m.is_nan() {
+        return T::NAN;
+    }
+
+    if m == T::ZERO || u == T::ZERO {
+        return u;
+    }
+
+    if m == T::ONE {
+        return u.sinh().atan();
+    }
+
+    if u.is_infinite() {
+        return if m < T::ONE { u } else { T::NAN };
+    }
+
+    if m > T::ONE {
+        let (sn, cn, _) = jacobi_elliptic(u, m);
+        return sn.atan2(cn);
+    }
+
+    jacobi_amplitude_landen(u, m)
+}
+
+// (sn, cn, dn) of u for the parameter m
+pub fn jacobi_elliptic<T: Float>(u: T, m: T) -> (T, T, T) {
+    if u.is_nan() || m.is_nan() {
+        return (T::NAN, T::NAN, T::NAN);
+    }
+
+    if m == T::ONE {
+        let sech = T::ONE / u.cosh();
+        return (u.tanh(), sech, sech);
+    }
+
+    if u.is_infinite() {
+        return (T::NAN, T::NAN, T::NAN);
+    }
+
+    // Reciprocal parameter: sn(u | m) = sn(u √m | 1/m) / √m, with cn and dn swapped
+    if m > T::ONE {
+        let k = m.sqrt();
+        let (sn, cn, dn) = jacobi_elliptic(u * k, T::ONE / m);
+        return (sn / k, dn, cn);
+    }
+
+    let phi = jacobi_amplitude(u, m);
+    let (sn, cn) = phi.sin_cos();
+
+    // Both forms of dn² are sums of non-negative terms in their range
+    let dn = if m >= T::ZERO {
+        ((T::ONE - m) + m * cn * cn).sqrt()
+    } else {
+        (T::ONE - m * sn * sn).sqrt()
+    };
+
+    (sn, cn, dn)
+}
+
+pub fn jacobi_sn<T: Float>(u: T, m: T) -> T {
+    jacobi_elliptic(u, m).0
+}
+
+pub fn jacobi_cn<T: Float>(u: T, m: T) -> T {
+    jacobi_elliptic(u, m).1
+}
+
+pub fn jacobi_dn<T: Float>(u: T, m: T) -> T {
+    jacobi_elliptic(u, m).2
+}
@@ -0,0 +1,382 @@
+use super::evaluate_polynomial;
+use crate::float::Float;
+
+const FRAC_1_SQRT_PI: f64 = 0.5641895835477563;
+
+// erf(x) / x for |x| < 0.5, in s = 8x² - 1
+const ERF_SMALL: [f64; 10] = [
+    1.0830752376761712,
+    -0.04364205888669792,
+    0.0016130716680617073,
+    -4.76233693446849e-05,
+    1.1516037795233198e-06,
+    -2.3472291635294847e-08,
+    4.127025151938976e-10,
+    -6.374417573691971e-12,
+    8.777607481006789e-14,
+    -1.0893429375776461e-15,
+];
+
+// erfcx(x) for 0.5 ≤ x < 1.5, in s = 2(x - 1)
+const ERFCX_1: [f64; 19] = [
+    0.427583576155807,
+    -0.13660600739194928,
+    0.03859289034297711,
+    -0.00990337111766582,
+    0.0023482685134556744,
+    -0.0005206834090758812,
+    0.00010890847460878921,
+    -2.163331855782933e-05,
+    4.1026148424254695e-06,
+    -7.460049524743092e-07,
+    1.3053025037981643e-07,
+    -2.2042891187870488e-08,
+    3.6018445414775295e-09,
+    -5.707977183947575e-10,
+    8.787810267386302e-11,
+    -1.3113911274672565e-11,
+    1.9160170411014948e-12,
+    -3.000816310876132e-13,
+    4.1644124346136074e-14,
+];
+
+// erfcx(x) for 1.5 ≤ x < 2.5, in s = 2(x - 2)
+const ERFCX_2: [f64; 17] = [
+    0.25539567631050575,
+    -0.0533982309267448,
+    0.010450688150881637,
+    -0.0019325797205364107,
+    0.00034004615859202107,
+    -5.7239508616337115e-05,
+    9.257343676999363e-06,
+    -1.443580996570628e-06,
+    2.176887318152635e-07,
+    -3.182366011581627e-08,
+    4.519701266061009e-09,
+    -6.247858207594854e-10,
+    8.419550792602017e-11,
+    -1.105585983819779e-11,
+    1.4220522208718966e-12,
+    -1.9104751081971532e-13,
+    2.3499047873035464e-14,
+];
+
+// erfcx(x) for 2.5 ≤ x < 4, in s = (x - 3.25) / 0.75
+const ERFCX_3: [f64; 18] = [
+    0.16633534842682188,
+    -0.035399551740877784,
+    0.0072772261216977096,
+    -0.0014493394550703822,
+    0.00028033738586056206,
+    -5.277242617691007e-05,
+    9.685663578991724e-06,
+    -1.7359099278349668e-06,
+    3.0422633431083685e-07,
+    -5.219948051641917e-08,
+    8.778199113732748e-09,
+    -1.4482351987155056e-09,
+    2.3463906011051353e-10,
+    -3.735309642847633e-11,
+    5.819888713808294e-12,
+    -8.968734999574514e-13,
+    1.5097136751153333e-13,
+    -2.2501970380435168e-14,
+];
+
+// x erfcx(x) for 4 ≤ x < 12, in s = 36 / x² - 1.25
+const ERFCX_4: [f64; 15] = [
+    0.5548651678943861,
+    -0.007111530504106027,
+    0.0002579350483056683,
+    -1.4773467640351607e-05,
+    1.1266622189710338e-06,
+    -1.0540662054773118e-07,
+    1.1532823747380756e-08,
+    -1.4304530818933614e-09,
+    1.968044534396904e-10,
+    -2.9565000754184544e-11,
+    4.7891652232229026e-12,
+    -8.201332546812239e-13,
+    1.5010320738046456e-13,
+    -3.4652945297521234e-14,
+    7.117265677189695e-15,
+];
+
+// Evaluates a fit table, dropping the trailing terms that are below f32 rounding
+fn evaluate_fit<T: Float>(coefficients: &[f64], single_terms: usize, s: T) -> T {
+    let terms = T::series_terms(single_terms, coefficients.len());
+    evaluate_polynomial(&coefficients[..terms], s)
+}
+
+// erfcx(x) for x ≥ 0.5
+fn erfcx_positive<T: Float>(x: T) -> T {
+    if x < T::from_f64(1.5) {
+        return evaluate_fit(&ERFCX_1, 12, T::TWO * (x - T::ONE));
+    }
+
+    if x < T::from_f64(2.5) {
+        return evaluate_fit(&ERFCX_2, 11, T::TWO * (x - T::TWO));
+    }
+
+    if x < T::from_f64(4.0) {
+        return evaluate_fit(&ERFCX_3, 11, (x - T::from_f64(3.25)) / T::from_f64(0.75));
+    }
+
+    if x < T::from_f64(12.0) {
+        let s = T::from_f64(36.0) / (x * x) - T::from_f64(1.25);
+        return evaluate_fit(&ERFCX_4, 7, s) / x;
+    }
+
+    // Asymptotic series, its terms are below rounding well before they start to diverge
+    let y = T::HALF / (x * x);
+    let mut term = T::ONE;
+    let mut sum = T::ONE;
+    let mut n = T::ONE;
+    while term.abs() > T::EPSILON * sum {
+        term *= -(T::TWO * n - T::ONE) * y;
+        sum += term;
+        n += T::ONE;
+    }
+
+    T::from_f64(FRAC_1_SQRT_PI) / x * sum
+}
+
+// erfc(x) for x ≥ 0.5
+fn erfc_positive<T: Float>(x: T) -> T {
+    if x >= T::by_precision(10.1, 27.3) {
+        return T::ZERO;
+    }
+
+    // x² = hi + lo exactly, so the exponential doesn't inherit the rounding of x²
+    let hi = x * x;
+    let lo = x.mul_add(x, -hi);
+    let scaled = erfcx_positive(x) * (T::ONE - lo);
+
+    if hi < T::by_precision(87.0, 700.0) {
+        scaled * (-hi).exp()
+    } else {
+        // Round only once when the result is subnormal
+        let split = T::from_f64(40.0);
+        scaled * (split - hi).exp() * (-split).exp()
+    }
+}
+
+pub fn erf<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return x.signum();
+    }
+
+    if x.abs() < T::HALF {
+        return x * evaluate_fit(&ERF_SMALL, 6, T::from_f64(8.0) * x * x - T::ONE);
+    }
+
+    x.signum() * (T::ONE - erfc_positive(x.abs()))
+}
+
+pub fn erfc<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::ZERO
+        } else {
+            T::TWO
+        };
+    }
+
+    if x < -T::HALF {
+        return T::TWO - erfc_positive(-x);
+    }
+
+    if x < T::HALF {
+        return T::ONE - erf(x);
+    }
+
+    erfc_positive(x)
+}
+
+// Scaled complementary error function e^(x²) erfc(x)
+pub fn erfcx<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::ZERO
+        } else {
+            T::INFINITY
+        };
+    }
+
+    if x >= T::HALF {
+        return erfcx_positive(x);
+    }
+
+    if x > -T::HALF {
+        return (x * x).exp() * (T::ONE - erf(x));
+    }
+
+    let hi = x * x;
+    let lo = x.mul_add(x, -hi);
+    T::TWO * hi.exp() * (T::ONE + lo) - erfcx_positive(-x)
+}
+
+pub fn erf_inv<T: Float>(p: T) -> T {
+    const MAX_ITERS: usize = 32;
+
+    if p.is_nan() || p.abs() > T::ONE {
+        return T::NAN;
+    }
+
+    if p.abs() == T::ONE {
+        return p * T::INFINITY;
+    }
+
+    if p == T::ZERO {
+        return p;
+    }
+
+    if p.abs() > T::HALF {
+        return p.signum() * erfc_inv_tail(T::ONE - p.abs());
+    }
+
+    // Start from the Maclaurin series of the inverse and polish with Halley's method
+    let p2 = p * p;
+    let mut x = T::HALF / T::from_f64(FRAC_1_SQRT_PI)
+        * p
+        * (T::ONE
+            + T::PI / T::from_f64(12.0) * p2
+            + T::from_f64(7.0) * T::PI * T::PI / T::from_f64(480.0) * p2 * p2);
+
+    for _ in 0..MAX_ITERS {
+        let f = erf(x) - p;
+        let ratio = f / (T::FRAC_2_SQRT_PI * (-x * x).exp());
+        let dx = ratio / (T::ONE + x * ratio);
+        x -= dx;
+
+        if dx.abs() <= T::EPSILON * x.abs() {
+            break;
+        }
+    }
+
+    x
+}
+
+pub fn erfc_inv<T: Float>(q: T) -> T {
+    if q.is_nan() || q < T::ZERO || q > T::TWO {
+        return T::NAN;
+    }
+
+    if q == T::ZERO {
+        return T::INFINITY;
+    }
+
+    if q == T::TWO {
+        return T::NEG_INFINITY;
+    }
+
+    if q < T::HALF {
+        erfc_inv_tail(q)
+    } else if q <= T::from_f64(1.5) {
+        erf_inv(T::ONE - q)
+    } else {
+        -erfc_inv_tail(T::TWO - q)
+    }
+}
+
+// Solves erfc(x) = q for 0 < q ≤ 0.5
+fn erfc_inv_tail<T: Float>(q: T) -> T {
+    const MAX_ITERS: usize = 32;
+    const WINITZKI_A: f64 = 0.147;
+
+    let a = T::from_f64(WINITZKI_A);
+
+    // ln(1 - p²) where p = 1 - q
+    let log_q = q.ln();
+    let l = log_q + (T::TWO - q).ln();
+    let b = T::TWO / (T::PI * a) + T::HALF * l;
+    let mut x = ((b * b - l / a).sqrt() - b).sqrt();
+
+    // Halley's method on ln erfc(x) - ln q, which stays well scaled down to subnormal q
+    for _ in 0..MAX_ITERS {
+        let scaled = erfcx(x);
+        let g = scaled.ln() - x * x - log_q;
+        let dg = -T::FRAC_2_SQRT_PI / scaled;
+        let ratio = g / dg;
+        let dx = ratio / (T::ONE + T::HALF * ratio * (dg + T::TWO * x));
+        x -= dx;
+
+        if dx.abs() <= T::EPSILON * x {
+            break;
+        }
+    }
+
+    x
+}
+
+// Dawson's integral F(x) = e^(-x²) ∫₀ˣ e^(t²) dt
+pub fn dawson<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return T::ZERO.copysign(x);
+    }
+
+    let ax = x.abs();
+
+    let value = if ax < T::from_f64(0.2) {
+        let x2 = ax * ax;
+        let mut term = ax;
+        let mut sum = ax;
+        let mut n = T::ONE;
+        while term.abs() > T::EPSILON * sum {
+            term *= -T::TWO * x2 / (T::TWO * n + T::ONE);
+            sum += term;
+            n += T::ONE;
+        }
+        sum
+    } else if ax < T::from_f64(12.0) {
+        dawson_rybicki(ax)
+    } else {
+        let y = T::HALF / (ax * ax);
+        let mut term = T::ONE;
+        let mut sum = T::ONE;
+        let mut n = T::ONE;
+        while term > T::EPSILON * sum {
+            term *= (T::TWO * n - T::ONE) * y;
+            sum += term;
+            n += T::ONE;
+        }
+        T::HALF / ax * sum
+    };
+
+    value.copysign(x)
+}
+
+// Rybicki's sampling formula, exact up to terms of order e^(-(π / 2h)²). The
+// step and the number of samples are chosen so that both errors are below rounding
+fn dawson_rybicki<T: Float>(x: T) -> T {
+    let h = T::by_precision(0.35, 0.1875);
+    let samples = T::series_terms(13, 37) as i32;
+
+    let n0 = T::TWO * (T::HALF * x / h).round();
+    let offset = x - n0 * h;
+
+    let mut sum = T::ZERO;
+    for n in (-samples..=samples).step_by(2) {
+        let n = T::from_f64(n as f64);
+        let d = offset - n * h;
+        sum += (-d * d).exp() / (n + n0);
+    }
+
+    T::from_f64(FRAC_1_SQRT_PI) * sum
+}
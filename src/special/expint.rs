@@ -0,0 +1,464 @@
+use super::{cos_pi, sin_pi};
+use crate::complex::Complex;
+use crate::float::Float;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const MAX_ITERS: usize = 10_000;
+const EPSILON: f64 = 1e-16;
+const EULER_GAMMA: f64 = 0.5772156649015329;
+
+// Zeros of Ei, Ci and Chi split into a leading double and a correction
+const EI_ROOT: (f64, f64) = (0.3725074107813666, 1.3140183414386028e-17);
+const CI_ROOT: (f64, f64) = (0.6165054856207163, -4.209920321433186e-17);
+const CI_ROOT_2: (f64, f64) = (3.3841804225511862, 1.972125462363411e-16);
+const CHI_ROOT: (f64, f64) = (0.5238225713898644, -2.6671382575502846e-17);
+// Zero of li, where ln x is the zero of Ei
+const LI_ROOT: (f64, f64) = (1.451369234883381, -4.82713642696033e-17);
+
+// Beyond this the power series of Ei, Shi and Chi give way to the asymptotic expansion,
+// which reaches f32 precision much earlier
+const EI_SERIES_MAX_X: f64 = 40.0;
+const EI_SERIES_MAX_X_F32: f64 = 20.0;
+const CISI_SERIES_MAX_X: f64 = 4.0;
+const FRESNEL_SERIES_MAX_X: f64 = 1.5;
+
+// x - r for a root stored as hi + lo, with hi rounded to T and its rounding moved into lo
+fn offset_from<T: Float>(x: T, root: (f64, f64)) -> T {
+    let hi = T::from_f64(root.0);
+    let lo = T::from_f64(root.0 - hi.to_f64() + root.1);
+    (x - hi) - lo
+}
+
+fn ei_series_max_x<T: Float>() -> T {
+    T::by_precision(EI_SERIES_MAX_X_F32, EI_SERIES_MAX_X)
+}
+
+// For f(x) = γ + ln x + Σ c_k x^(pk) with c_k = σ^k / (pk (pk)!) and a zero at r, evaluates
+// f(x) = ln(x / r) + (u - w) Σ c_k (u^k - w^k) / (u - w) with u = x^p, w = r^p and dx = x - r.
+// Factoring out u - w removes the cancellation next to the zero
+fn log_series_about_root<T: Float>(x: T, dx: T, root: (f64, f64), power: u32, sign: T) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let hi = T::from_f64(root.0);
+    let (u, w, du) = if power == 1 {
+        (x, hi, dx)
+    } else {
+        (x * x, hi * hi, dx * (x + hi))
+    };
+
+    let p = T::from_f64(power as f64);
+    let factorial = (1..=power).map(f64::from).product::<f64>();
+    let coefficient = sign / (p * T::from_f64(factorial));
+
+    // term = c_k (u^k - w^k) / (u - w) and weighted = c_k w^k carry the coefficient
+    // along, so that the powers never overflow on their own
+    let mut term = coefficient;
+    let mut weighted = coefficient;
+    let mut sum = coefficient;
+
+    for k in 1..MAX_ITERS {
+        let k = T::from_f64(k as f64);
+        let mut ratio = sign * k / (k + T::ONE);
+        for j in 1..=power {
+            ratio /= p * k + T::from_f64(j as f64);
+        }
+        weighted *= ratio * w;
+        term = ratio * u * term + weighted;
+        sum += term;
+        if term.abs() <= epsilon * sum.abs() {
+            break;
+        }
+    }
+
+    let log = if dx.abs() < T::HALF * hi {
+        (dx / hi).ln_1p()
+    } else {
+        (x / hi).ln()
+    };
+    log + du * sum
+}
+
+// e^x / x Σ k! / x^k, multiplied by `scale` before the second half of the exponential
+// is applied so that values just below the overflow threshold survive
+fn ei_asymptotic<T: Float>(x: T, scale: T) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let mut term = T::ONE;
+    let mut sum = T::ONE;
+    for k in 1..MAX_ITERS {
+        let next = term * T::from_f64(k as f64) / x;
+        if next >= term || next < epsilon * sum {
+            break;
+        }
+        term = next;
+        sum += term;
+    }
+
+    let half = (T::HALF * x).exp();
+    half * (half * scale * sum / x)
+}
+
+pub fn expint_en<T: Float>(n: u32, x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    // E_0(x) = e^-x / x is real for negative x too
+    if n == 0 {
+        return if x == T::NEG_INFINITY {
+            x
+        } else {
+            (-x).exp() / x
+        };
+    }
+
+    if x < T::ZERO {
+        return T::NAN;
+    }
+
+    if x == T::ZERO {
+        return if n > 1 {
+            T::ONE / T::from_f64((n - 1) as f64)
+        } else {
+            T::INFINITY
+        };
+    }
+
+    if x.is_infinite() {
+        return T::ZERO;
+    }
+
+    let epsilon = T::tolerance(EPSILON);
+    let m = T::from_f64((n - 1) as f64);
+    let n_float = T::from_f64(n as f64);
+
+    if x > T::ONE {
+        // Modified Lentz evaluation of the continued fraction only finds the depth at which it
+        // converges; evaluating bottom-up from there avoids the rounding Lentz accumulates
+        let mut b = x + n_float;
+        let mut c = T::EPSILON / T::MIN_POSITIVE;
+        let mut d = T::ONE / b;
+        let mut depth = MAX_ITERS;
+
+        for i in 1..MAX_ITERS {
+            let i_float = T::from_f64(i as f64);
+            let a = -i_float * (m + i_float);
+            b += T::TWO;
+            d = T::ONE / (a * d + b);
+            c = b + a / c;
+            if (c * d - T::ONE).abs() < epsilon {
+                depth = i + 4;
+                break;
+            }
+        }
+
+        let mut tail = T::ZERO;
+        for i in (1..=depth).rev() {
+            let i = T::from_f64(i as f64);
+            tail = -i * (m + i) / (x + n_float + T::TWO * i + tail);
+        }
+        let h = T::ONE / (x + n_float + tail);
+
+        return h * (-x).exp();
+    }
+
+    let euler_gamma = T::from_f64(EULER_GAMMA);
+    let mut sum = if n > 1 {
+        T::ONE / m
+    } else {
+        -x.ln() - euler_gamma
+    };
+    let mut factor = T::ONE;
+
+    for i in 1..MAX_ITERS {
+        factor *= -x / T::from_f64(i as f64);
+        let delta = if i != n as usize - 1 {
+            -factor / (T::from_f64(i as f64) - m)
+        } else {
+            let psi = (1..n).fold(-euler_gamma, |acc, k| acc + T::from_f64(k as f64).recip());
+            factor * (psi - x.ln())
+        };
+        sum += delta;
+        if delta.abs() < epsilon * sum.abs() {
+            break;
+        }
+    }
+
+    sum
+}
+
+pub fn expint_e1<T: Float>(x: T) -> T {
+    expint_en(1, x)
+}
+
+pub fn expint_ei<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if x < T::ZERO {
+        return -expint_e1(-x);
+    }
+
+    if x.is_infinite() {
+        return T::INFINITY;
+    }
+
+    if x <= ei_series_max_x() {
+        return log_series_about_root(x, offset_from(x, EI_ROOT), EI_ROOT, 1, T::ONE);
+    }
+
+    ei_asymptotic(x, T::ONE)
+}
+
+// Logarithmic integral li(x) = Ei(ln x)
+pub fn li<T: Float>(x: T) -> T {
+    if x.is_nan() || x < T::ZERO {
+        return T::NAN;
+    }
+
+    if x == T::ZERO {
+        return T::ZERO;
+    }
+
+    if x == T::ONE {
+        return T::NEG_INFINITY;
+    }
+
+    // Near its zero, ln(x / μ) is the offset of ln x from the zero of Ei
+    let dx = offset_from(x, LI_ROOT);
+    let root = T::from_f64(LI_ROOT.0);
+    if dx.abs() < T::HALF * root {
+        let log = (dx / root).ln_1p();
+        return log_series_about_root(x.ln(), log, EI_ROOT, 1, T::ONE);
+    }
+
+    expint_ei(x.ln())
+}
+
+// Si(x) and Ci(x) for x > 0 from the continued fraction of E1(ix). Complex is
+// double precision only, so this is evaluated in f64 for every T
+fn cisi_continued_fraction(x: f64) -> (f64, f64) {
+    let mut b = Complex::new(1.0, x);
+    let mut c = Complex::new(f64::EPSILON / f64::MIN_POSITIVE, 0.0);
+    let mut d = b.recip();
+    let mut h = d;
+
+    for i in 2..MAX_ITERS {
+        let a = -(((i - 1) * (i - 1)) as f64);
+        b += 2.0;
+        d = (d * a + b).recip();
+        c = b + c.recip() * a;
+        let delta = c * d;
+        h *= delta;
+        if (delta.re - 1.0).abs() + delta.im.abs() < EPSILON {
+            break;
+        }
+    }
+
+    let h = Complex::new(x.cos(), -x.sin()) * h;
+    (FRAC_PI_2 + h.im, -h.re)
+}
+
+// Sine integral Si(x) = ∫₀ˣ sin(t) / t dt
+pub fn si<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return T::FRAC_PI_2.copysign(x);
+    }
+
+    let ax = x.abs();
+
+    let value = if ax <= T::from_f64(CISI_SERIES_MAX_X) {
+        let epsilon = T::tolerance(EPSILON);
+        let x2 = ax * ax;
+        let mut power = ax;
+        let mut sum = ax;
+        for k in 1..MAX_ITERS {
+            let n = T::from_f64((2 * k + 1) as f64);
+            power *= -x2 / (n * (n - T::ONE));
+            let term = power / n;
+            sum += term;
+            if term.abs() <= epsilon * sum.abs() {
+                break;
+            }
+        }
+        sum
+    } else {
+        T::from_f64(cisi_continued_fraction(ax.to_f64()).0)
+    };
+
+    value.copysign(x)
+}
+
+// Cosine integral Ci(x) = γ + ln x + ∫₀ˣ (cos(t) - 1) / t dt
+pub fn ci<T: Float>(x: T) -> T {
+    if x.is_nan() || x < T::ZERO {
+        return T::NAN;
+    }
+
+    if x == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if x.is_infinite() {
+        return T::ZERO;
+    }
+
+    if x <= T::from_f64(CISI_SERIES_MAX_X) {
+        let root = if x < T::TWO { CI_ROOT } else { CI_ROOT_2 };
+        return log_series_about_root(x, offset_from(x, root), root, 2, -T::ONE);
+    }
+
+    T::from_f64(cisi_continued_fraction(x.to_f64()).1)
+}
+
+// Hyperbolic sine integral Shi(x) = ∫₀ˣ sinh(t) / t dt
+pub fn shi<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return x;
+    }
+
+    let ax = x.abs();
+
+    let value = if ax <= ei_series_max_x() {
+        let epsilon = T::tolerance(EPSILON);
+        let x2 = ax * ax;
+        let mut power = ax;
+        let mut sum = ax;
+        for k in 1..MAX_ITERS {
+            let n = T::from_f64((2 * k + 1) as f64);
+            power *= x2 / (n * (n - T::ONE));
+            let term = power / n;
+            sum += term;
+            if term <= epsilon * sum {
+                break;
+            }
+        }
+        sum
+    } else {
+        // E1(x) is far below the rounding of Ei(x) here
+        ei_asymptotic(ax, T::HALF)
+    };
+
+    value.copysign(x)
+}
+
+// Hyperbolic cosine integral Chi(x) = γ + ln x + ∫₀ˣ (cosh(t) - 1) / t dt
+pub fn chi<T: Float>(x: T) -> T {
+    if x.is_nan() || x < T::ZERO {
+        return T::NAN;
+    }
+
+    if x == T::ZERO {
+        return T::NEG_INFINITY;
+    }
+
+    if x.is_infinite() {
+        return T::INFINITY;
+    }
+
+    if x <= ei_series_max_x() {
+        return log_series_about_root(x, offset_from(x, CHI_ROOT), CHI_ROOT, 2, T::ONE);
+    }
+
+    ei_asymptotic(x, T::HALF)
+}
+
+// Fresnel integrals S(x) = ∫₀ˣ sin(πt² / 2) dt and C(x) = ∫₀ˣ cos(πt² / 2) dt
+pub fn fresnel<T: Float>(x: T) -> (T, T) {
+    if x.is_nan() {
+        return (T::NAN, T::NAN);
+    }
+
+    if x.is_infinite() {
+        return (T::HALF.copysign(x), T::HALF.copysign(x));
+    }
+
+    let ax = x.abs();
+
+    let (s, c) = if ax < T::from_f64(FRESNEL_SERIES_MAX_X) {
+        fresnel_series(ax)
+    } else {
+        let (s, c) = fresnel_continued_fraction(ax.to_f64());
+        (T::from_f64(s), T::from_f64(c))
+    };
+
+    (s.copysign(x), c.copysign(x))
+}
+
+pub fn fresnel_s<T: Float>(x: T) -> T {
+    fresnel(x).0
+}
+
+pub fn fresnel_c<T: Float>(x: T) -> T {
+    fresnel(x).1
+}
+
+fn fresnel_series<T: Float>(x: T) -> (T, T) {
+    let epsilon = T::tolerance(EPSILON);
+    let t = T::FRAC_PI_2 * x * x;
+    let mut power = x;
+    let mut s = T::ZERO;
+    let mut c = x;
+
+    // Terms alternate between S and C, with signs +S, -C, -S, +C
+    for k in 1..MAX_ITERS {
+        power *= t / T::from_f64(k as f64);
+        let term = power / T::from_f64((2 * k + 1) as f64);
+        let sign = if k % 4 < 2 { T::ONE } else { -T::ONE };
+        if k % 2 == 1 {
+            s += sign * term;
+        } else {
+            c += sign * term;
+        }
+        if term < epsilon * s.abs().min(c.abs()) {
+            break;
+        }
+    }
+
+    (s, c)
+}
+
+// Like the Si/Ci continued fraction this runs on f64 Complex values for every T
+fn fresnel_continued_fraction(x: f64) -> (f64, f64) {
+    // x² = hi + lo exactly, so the phase πx² / 2 is reduced without losing the rounding of x²
+    let hi = x * x;
+    let lo = x.mul_add(x, -hi);
+
+    let mut b = Complex::new(1.0, -PI * hi);
+    let mut c = Complex::new(f64::EPSILON / f64::MIN_POSITIVE, 0.0);
+    let mut d = b.recip();
+    let mut h = d;
+    let mut n = -1.0;
+
+    for _ in 2..MAX_ITERS {
+        n += 2.0;
+        let a = -n * (n + 1.0);
+        b += 4.0;
+        d = (d * a + b).recip();
+        c = b + c.recip() * a;
+        let delta = c * d;
+        h *= delta;
+        if (delta.re - 1.0).abs() + delta.im.abs() < EPSILON {
+            break;
+        }
+    }
+    h *= Complex::new(x, -x);
+
+    let delta = FRAC_PI_2 * lo;
+    let (sin, cos) = (sin_pi(0.5 * hi), cos_pi(0.5 * hi));
+    let phase = Complex::new(cos - sin * delta, sin + cos * delta);
+    let value = Complex::new(0.5, 0.5) * (1.0 - phase * h);
+
+    (value.im, value.re)
+}
@@ -0,0 +1,566 @@
+use super::{evaluate_polynomial, sin_pi};
+use crate::float::Float;
+
+const LANCZOS_G: f64 = 6.02468004077673;
+
+const LANCZOS_NUM: [f64; 13] = [
+    23531376880.41076,
+    42919803642.6491,
+    35711959237.35567,
+    17921034426.03721,
+    6039542586.352028,
+    1439720407.3117216,
+    248874557.86205417,
+    31426415.585400194,
+    2876370.6289353725,
+    186056.26539522348,
+    8071.672002365816,
+    210.82427775157936,
+    2.5066282746310002,
+];
+
+const LANCZOS_DENOM: [f64; 13] = [
+    0.0,
+    39916800.0,
+    120543840.0,
+    150917976.0,
+    105258076.0,
+    45995730.0,
+    13339535.0,
+    2637558.0,
+    357423.0,
+    32670.0,
+    1925.0,
+    66.0,
+    1.0,
+];
+
+// Taylor coefficients of ln Γ(2 + z)
+const LOG_GAMMA_AT_2: [f64; 30] = [
+    0.42278433509846713,
+    0.3224670334241132,
+    -0.0673523010531981,
+    0.020580808427784546,
+    -0.007385551028673986,
+    0.0028905103307415234,
+    -0.001192753911703261,
+    0.0005096695247430425,
+    -0.00022315475845357939,
+    9.945751278180853e-05,
+    -4.492623673813314e-05,
+    2.050721277567069e-05,
+    -9.439488275268397e-06,
+    4.374866789907488e-06,
+    -2.039215753801366e-06,
+    9.55141213040742e-07,
+    -4.492469198764566e-07,
+    2.1207184805554665e-07,
+    -1.0043224823968099e-07,
+    4.7698101693639804e-08,
+    -2.2711094608943164e-08,
+    1.0838659214896955e-08,
+    -5.183475041970047e-09,
+    2.4836745438024785e-09,
+    -1.1921401405860912e-09,
+    5.731367241678862e-10,
+    -2.7595228851242334e-10,
+    1.330476437424449e-10,
+    -6.4229645638381e-11,
+    3.1044247747322276e-11,
+];
+
+const DIGAMMA_ROOT_HI: f64 = 1.4616321449683622;
+const DIGAMMA_ROOT_LO: f64 = 9.549995429965697e-17;
+
+// Taylor coefficients of ψ around its positive root
+const DIGAMMA_AT_ROOT: [f64; 40] = [
+    0.9676722454476212,
+    -0.4427631689835921,
+    0.258499760955651,
+    -0.16394270544240652,
+    0.10782405069126237,
+    -0.07219956125645471,
+    0.04880428816414311,
+    -0.03316112647484736,
+    0.022597648232218104,
+    -0.01542476590494896,
+    0.010538791616612175,
+    -0.007204534386356869,
+    0.004926781395729853,
+    -0.003369801655439328,
+    0.002305126326734928,
+    -0.0015769367714301972,
+    0.0010788252019162967,
+    -0.0007380709389960052,
+    0.000504953265834602,
+    -0.0003454680251063077,
+    0.00023635601564027053,
+    -0.00016170622091974803,
+    0.0001106337276874741,
+    -7.569179582195066e-05,
+    5.178575795222081e-05,
+    -3.5430070947659604e-05,
+    2.424006611860132e-05,
+    -1.6584242271854135e-05,
+    1.134638458466385e-05,
+    -7.762817668462094e-06,
+    5.3110609208898636e-06,
+    -3.6336507898010456e-06,
+    2.486022733129538e-06,
+    -1.7008538854332607e-06,
+    1.1636675363548843e-06,
+    -7.96142543124197e-07,
+    5.446941930669446e-07,
+    -3.7266161283438227e-07,
+    2.549626552021554e-07,
+    -1.7443695117727745e-07,
+];
+
+// B_2k / (2k (2k - 1))
+const STIRLING: [f64; 8] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+    -3617.0 / 122400.0,
+];
+
+// B_2k / 2k
+const DIGAMMA_ASYMPTOTIC: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 120.0,
+    1.0 / 252.0,
+    -1.0 / 240.0,
+    1.0 / 132.0,
+    -691.0 / 32760.0,
+    1.0 / 12.0,
+];
+
+const HALF_LN_2PI: f64 = 0.9189385332046728;
+
+// Γ overflows beyond these arguments
+const MAX_GAMMA_ARG: f64 = 171.61447887182298;
+const MAX_GAMMA_ARG_F32: f64 = 35.04009;
+
+fn max_gamma_arg<T: Float>() -> T {
+    T::by_precision(MAX_GAMMA_ARG_F32, MAX_GAMMA_ARG)
+}
+
+fn lanczos_sum<T: Float>(x: T) -> T {
+    // Evaluate the rational function in 1/x for large x to avoid overflow
+    if x > T::ONE {
+        let z = x.recip();
+        let num = LANCZOS_NUM
+            .iter()
+            .fold(T::ZERO, |acc, &c| acc * z + T::from_f64(c));
+        let denom = LANCZOS_DENOM
+            .iter()
+            .fold(T::ZERO, |acc, &c| acc * z + T::from_f64(c));
+        num / denom
+    } else {
+        evaluate_polynomial(&LANCZOS_NUM, x) / evaluate_polynomial(&LANCZOS_DENOM, x)
+    }
+}
+
+// Exact a + b - sum for sum = fl(a + b); Γ is steep enough for large arguments
+// that this rounding alone can cost hundreds of ULPs
+fn sum_rounding_error<T: Float>(a: T, b: T, sum: T) -> T {
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (a - a_virtual) + (b - b_virtual)
+}
+
+pub(crate) fn is_nonpositive_integer<T: Float>(x: T) -> bool {
+    x <= T::ZERO && x.fract() == T::ZERO
+}
+
+// Stirling's correction term ln Γ(x) - [(x - 1/2) ln x - x + ln √(2π)], for x >= 10
+pub(crate) fn stirling_correction<T: Float>(x: T) -> T {
+    let z = (x * x).recip();
+    evaluate_polynomial(&STIRLING[..T::series_terms(3, STIRLING.len())], z) / x
+}
+
+pub fn gamma<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::INFINITY
+        } else {
+            T::NAN
+        };
+    }
+
+    if x == T::ZERO {
+        return x.recip();
+    }
+
+    if is_nonpositive_integer(x) {
+        return T::NAN;
+    }
+
+    if x > max_gamma_arg() {
+        return T::INFINITY;
+    }
+
+    if x.fract() == T::ZERO && x <= T::from_f64(30.0) {
+        return (2..x.to_f64() as i64).fold(T::ONE, |acc, k| acc * T::from_f64(k as f64));
+    }
+
+    if x < T::HALF {
+        let s = sin_pi(x);
+        let y = T::ONE - x;
+        let error = sum_rounding_error(T::ONE, -x, y);
+        if y > max_gamma_arg() {
+            let log_value = T::PI.ln() - s.abs().ln() - log_gamma(y) - digamma(y) * error;
+            return log_value.exp().copysign(s);
+        }
+        return T::PI / (s * gamma(y)) * (-digamma(y) * error).exp();
+    }
+
+    let shift = T::from_f64(LANCZOS_G - 0.5);
+    let zgh = x + shift;
+    let sum = lanczos_sum(x);
+
+    // The rounding error of zgh gets amplified by the power, so fold it back in
+    let rounding_error = (x - (zgh - shift)) + (shift - (zgh - (zgh - shift)));
+    let correction = ((x - T::HALF) * rounding_error / zgh - rounding_error).exp();
+
+    // Split the power so that zgh^(x - 1/2) cannot overflow before the division
+    if x > T::by_precision(20.0, 140.0) {
+        let half = zgh.powf(T::HALF * x - T::from_f64(0.25));
+        return sum * correction * (half / zgh.exp()) * half;
+    }
+
+    sum * correction * zgh.powf(x - T::HALF) / zgh.exp()
+}
+
+pub fn factorial<T: Float>(x: T) -> T {
+    gamma(x + T::ONE)
+}
+
+pub fn log_gamma<T: Float>(x: T) -> T {
+    log_gamma_sign(x).0
+}
+
+pub fn log_gamma_sign<T: Float>(x: T) -> (T, T) {
+    if x.is_nan() {
+        return (T::NAN, T::NAN);
+    }
+
+    if x.is_infinite() {
+        return (T::INFINITY, T::ONE);
+    }
+
+    if is_nonpositive_integer(x) {
+        return (T::INFINITY, T::ONE.copysign(x));
+    }
+
+    if x < T::ZERO {
+        // Γ(x) alternates sign between consecutive negative integers
+        let sign = if x.floor() % T::TWO == T::ZERO {
+            T::ONE
+        } else {
+            -T::ONE
+        };
+        let y = T::ONE - x;
+        let error = sum_rounding_error(T::ONE, -x, y);
+        let value = T::PI.ln() - sin_pi(x).abs().ln() - log_gamma_positive(y) - digamma(y) * error;
+        return (value, sign);
+    }
+
+    (log_gamma_positive(x), T::ONE)
+}
+
+fn log_gamma_at_2<T: Float>(z: T) -> T {
+    evaluate_polynomial(
+        &LOG_GAMMA_AT_2[..T::series_terms(14, LOG_GAMMA_AT_2.len())],
+        z,
+    )
+}
+
+fn log_gamma_positive<T: Float>(x: T) -> T {
+    if x < T::HALF {
+        return log_gamma_at_2(x) * x - x.ln() - x.ln_1p();
+    }
+
+    if x < T::from_f64(1.5) {
+        let z = x - T::ONE;
+        return log_gamma_at_2(z) * z - x.ln();
+    }
+
+    if x < T::from_f64(2.5) {
+        let z = x - T::TWO;
+        return log_gamma_at_2(z) * z;
+    }
+
+    if x < T::from_f64(13.0) {
+        let mut y = x;
+        let mut product = T::ONE;
+        while y >= T::from_f64(2.5) {
+            y -= T::ONE;
+            product *= y;
+        }
+        let z = y - T::TWO;
+        return log_gamma_at_2(z) * z + product.ln();
+    }
+
+    (x - T::HALF) * x.ln() - x + T::from_f64(HALF_LN_2PI) + stirling_correction(x)
+}
+
+// ln Γ(1 + x) for small |x|, without rounding 1 + x first
+pub(crate) fn log_gamma1p<T: Float>(x: T) -> T {
+    if x.abs() < T::HALF {
+        return log_gamma_at_2(x) * x - x.ln_1p();
+    }
+
+    log_gamma(T::ONE + x)
+}
+
+// ln Γ(a) - ln Γ(a + delta) for a >= 10, without cancelling the two large logarithms
+fn log_gamma_delta_ratio_large<T: Float>(a: T, delta: T) -> T {
+    let b = a + delta;
+    -(a - T::HALF) * (delta / a).ln_1p() - delta * b.ln() + delta + stirling_correction(a)
+        - stirling_correction(b)
+}
+
+pub fn digamma<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::INFINITY
+        } else {
+            T::NAN
+        };
+    }
+
+    if is_nonpositive_integer(x) {
+        return T::NAN;
+    }
+
+    if x < T::ZERO {
+        return digamma(T::ONE - x) - T::PI / tan_pi(x);
+    }
+
+    if x >= T::from_f64(10.0) {
+        let z = (x * x).recip();
+        let terms = T::series_terms(3, DIGAMMA_ASYMPTOTIC.len());
+        return x.ln() - T::HALF / x - evaluate_polynomial(&DIGAMMA_ASYMPTOTIC[..terms], z) * z;
+    }
+
+    if x < T::ONE {
+        return digamma_near_root(x + T::ONE) - x.recip();
+    }
+
+    let mut y = x;
+    let mut correction = T::ZERO;
+    while y > T::TWO {
+        y -= T::ONE;
+        correction += y.recip();
+    }
+
+    digamma_near_root(y) + correction
+}
+
+// Valid on [1, 2]
+fn digamma_near_root<T: Float>(x: T) -> T {
+    let root_hi = T::from_f64(DIGAMMA_ROOT_HI);
+    let root_lo = T::from_f64(DIGAMMA_ROOT_HI - root_hi.to_f64() + DIGAMMA_ROOT_LO);
+    let z = (x - root_hi) - root_lo;
+    let terms = T::series_terms(18, DIGAMMA_AT_ROOT.len());
+    evaluate_polynomial(&DIGAMMA_AT_ROOT[..terms], z) * z
+}
+
+fn tan_pi<T: Float>(x: T) -> T {
+    let r = x - x.round();
+    (T::PI * r).tan()
+}
+
+pub fn trigamma<T: Float>(x: T) -> T {
+    polygamma(1, x)
+}
+
+pub fn polygamma<T: Float>(n: u32, x: T) -> T {
+    if n == 0 {
+        return digamma(x);
+    }
+
+    if x.is_nan() {
+        return T::NAN;
+    }
+
+    if x.is_infinite() {
+        return if x.is_sign_positive() {
+            T::ZERO
+        } else {
+            T::NAN
+        };
+    }
+
+    if is_nonpositive_integer(x) {
+        return T::NAN;
+    }
+
+    let sign = if n % 2 == 1 { T::ONE } else { -T::ONE };
+    let s = T::from_f64(n as f64 + 1.0);
+
+    // ψ⁽ⁿ⁾(x) = (-1)^(n+1) n! ζ(n + 1, x); the Hurwitz sum is computed with
+    // Euler-Maclaurin after shifting x far enough for the tail to converge
+    let min_shift = T::by_precision(8.0, 15.0);
+    let shift = (min_shift + T::from_f64(0.5 * n as f64) - x)
+        .max(T::ZERO)
+        .ceil()
+        .to_f64() as usize;
+    let mut sum = T::ZERO;
+    for k in 0..shift {
+        sum += (x + T::from_f64(k as f64)).powf(-s);
+    }
+
+    let a = x + T::from_f64(shift as f64);
+    let a_pow = a.powf(-s);
+    let mut tail = a * a_pow / (s - T::ONE) + T::HALF * a_pow;
+
+    // Euler-Maclaurin terms B_2j / (2j)! s (s+1) ... (s + 2j - 2) a^(-s - 2j + 1)
+    const BERNOULLI_OVER_FACTORIAL: [f64; 8] = [
+        1.0 / 12.0,
+        -1.0 / 720.0,
+        1.0 / 30240.0,
+        -1.0 / 1209600.0,
+        1.0 / 47900160.0,
+        -691.0 / 1307674368000.0,
+        1.0 / 74724249600.0,
+        -3617.0 / 1.067062284288e+16,
+    ];
+
+    let mut rising = s;
+    let mut a_term = a_pow / a;
+    for (j, &coefficient) in BERNOULLI_OVER_FACTORIAL.iter().enumerate() {
+        let term = T::from_f64(coefficient) * rising * a_term;
+        tail += term;
+        if term.abs() < T::EPSILON * tail.abs() {
+            break;
+        }
+        let j = T::from_f64(j as f64);
+        rising *= (s + T::TWO * j + T::ONE) * (s + T::TWO * j + T::TWO);
+        a_term /= a * a;
+    }
+
+    sign * factorial(T::from_f64(n as f64)) * (sum + tail)
+}
+
+pub fn beta<T: Float>(a: T, b: T) -> T {
+    if a.is_nan() || b.is_nan() {
+        return T::NAN;
+    }
+
+    if is_nonpositive_integer(a) || is_nonpositive_integer(b) {
+        return T::NAN;
+    }
+
+    if a > T::ZERO && b > T::ZERO && a + b < max_gamma_arg() {
+        let (large, small) = if a >= b { (a, b) } else { (b, a) };
+        let sum = large + small;
+        let error = sum_rounding_error(large, small, sum);
+        return gamma(small) * (gamma(large) / gamma(sum)) * (-digamma(sum) * error).exp();
+    }
+
+    let (value, sign) = log_beta_sign(a, b);
+    sign * value.exp()
+}
+
+pub fn log_beta<T: Float>(a: T, b: T) -> T {
+    log_beta_sign(a, b).0
+}
+
+fn log_beta_sign<T: Float>(a: T, b: T) -> (T, T) {
+    if a.is_nan() || b.is_nan() {
+        return (T::NAN, T::NAN);
+    }
+
+    let (large, small) = if a >= b { (a, b) } else { (b, a) };
+    let ten = T::from_f64(10.0);
+
+    if small > T::ZERO && large >= ten {
+        if small >= ten {
+            let sum = large + small;
+            let value = T::from_f64(HALF_LN_2PI)
+                - T::HALF * small.ln()
+                - (large - T::HALF) * (small / large).ln_1p()
+                + small * (small / sum).ln()
+                + stirling_correction(large)
+                + stirling_correction(small)
+                - stirling_correction(sum);
+            return (value, T::ONE);
+        }
+
+        return (
+            log_gamma(small) + log_gamma_delta_ratio_large(large, small),
+            T::ONE,
+        );
+    }
+
+    let (la, sa) = log_gamma_sign(a);
+    let (lb, sb) = log_gamma_sign(b);
+    let (lab, sab) = log_gamma_sign(a + b);
+    (la + lb - lab, sa * sb * sab)
+}
+
+pub fn pochhammer<T: Float>(x: T, n: T) -> T {
+    if x.is_nan() || n.is_nan() {
+        return T::NAN;
+    }
+
+    if n == T::ZERO {
+        return T::ONE;
+    }
+
+    if n.fract() == T::ZERO && n.abs() <= T::from_f64(100.0) {
+        let mut result = T::ONE;
+        if n > T::ZERO {
+            for k in 0..n.to_f64() as i64 {
+                result *= x + T::from_f64(k as f64);
+            }
+        } else {
+            for k in 1..=(-n).to_f64() as i64 {
+                result /= x - T::from_f64(k as f64);
+            }
+        }
+        return result;
+    }
+
+    let y = x + n;
+
+    if is_nonpositive_integer(x) {
+        return if is_nonpositive_integer(y) {
+            T::NAN
+        } else {
+            T::ZERO
+        };
+    }
+
+    if is_nonpositive_integer(y) {
+        return T::INFINITY;
+    }
+
+    if x > T::ZERO && y > T::ZERO && x < max_gamma_arg() && y < max_gamma_arg() {
+        let error = sum_rounding_error(x, n, y);
+        return gamma(y) / gamma(x) * (digamma(y) * error).exp();
+    }
+
+    let ten = T::from_f64(10.0);
+    if x >= ten && y >= ten {
+        return (-log_gamma_delta_ratio_large(x, n)).exp();
+    }
+
+    let (ly, sy) = log_gamma_sign(y);
+    let (lx, sx) = log_gamma_sign(x);
+    sy * sx * (ly - lx).exp()
+}
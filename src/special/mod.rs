@@ -0,0 +1,89 @@
+mod airy;
+mod bessel;
+mod elliptic;
+mod erf;
+mod expint;
+mod gamma;
+mod hypergeometric;
+mod incomplete;
+mod zeta;
+
+pub use airy::*;
+pub use bessel::*;
+pub use elliptic::*;
+pub use erf::*;
+pub use expint::*;
+pub use gamma::*;
+pub use hypergeometric::*;
+pub use incomplete::*;
+pub use zeta::*;
+
+use crate::float::Float;
+
+pub(crate) fn evaluate_polynomial<T: Float>(coefficients: &[f64], x: T) -> T {
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::ZERO, |acc, &c| acc * x + T::from_f64(c))
+}
+
+// sin(πx) with exact zeros at the integers
+pub(crate) fn sin_pi<T: Float>(x: T) -> T {
+    if x.fract() == T::ZERO {
+        return T::ZERO;
+    }
+
+    let r = x - T::TWO * (T::HALF * x).round();
+    let (r, sign) = if r < T::ZERO {
+        (-r, -T::ONE)
+    } else {
+        (r, T::ONE)
+    };
+
+    let value = if r <= T::from_f64(0.25) {
+        (T::PI * r).sin()
+    } else if r <= T::from_f64(0.75) {
+        (T::PI * (r - T::HALF)).cos()
+    } else {
+        (T::PI * (T::ONE - r)).sin()
+    };
+
+    sign * value
+}
+
+pub(crate) fn cos_pi<T: Float>(x: T) -> T {
+    sin_pi(x.abs() + T::HALF)
+}
+
+// Trapezoidal sums of g over [-tau_max, tau_max], halving the step until two
+// levels agree; double exponential substitutions make the error square per level
+pub(crate) fn double_exponential<T: Float>(g: impl Fn(T) -> T, tau_max: T) -> T {
+    let tolerance = T::by_precision(1e-4, 1e-9);
+
+    let mut h = T::HALF;
+    let mut sum = g(T::ZERO);
+    let mut k = T::ONE;
+    while k * h <= tau_max {
+        sum += g(k * h) + g(-k * h);
+        k += T::ONE;
+    }
+    let mut estimate = h * sum;
+
+    for _ in 0..10 {
+        h *= T::HALF;
+        let mut k = T::ONE;
+        while k * h <= tau_max {
+            sum += g(k * h) + g(-k * h);
+            k += T::TWO;
+        }
+
+        let next = h * sum;
+        let converged = (next - estimate).abs() <= tolerance * next.abs();
+        estimate = next;
+        if converged {
+            break;
+        }
+    }
+
+    estimate
+}
@@ -0,0 +1,433 @@
+use super::{cos_pi, double_exponential, gamma, log_gamma1p, log_gamma_sign, sin_pi};
+use crate::complex::{cexp, clog, Complex};
+use crate::float::Float;
+use std::f64::consts::{PI, TAU};
+
+const MAX_ITERS: usize = 10_000;
+const EPSILON: f64 = 1e-17;
+const LN_2PI: f64 = 1.8378770664093453;
+const PI_LO: f64 = 1.2246467991473532e-16;
+const FRAC_1_SQRT_PI: f64 = 0.5641895835477563;
+
+// Largest |ln(-z)| for which the expansion of Li_s around z = -1 is used
+const ETA_SERIES_MAX_LOG: f64 = 1.5;
+
+// B_2k / (2k)!, the Euler-Maclaurin correction coefficients
+const EULER_MACLAURIN: [f64; 15] = [
+    0.08333333333333333,
+    -0.001388888888888889,
+    3.306878306878307e-05,
+    -8.267195767195768e-07,
+    2.08767569878681e-08,
+    -5.284190138687493e-10,
+    1.3382536530684679e-11,
+    -3.3896802963225827e-13,
+    8.586062056277845e-15,
+    -2.174868698558062e-16,
+    5.5090028283602295e-18,
+    -1.3954464685812522e-19,
+    3.534707039629467e-21,
+    -8.953517427037546e-23,
+    2.267952452337683e-24,
+];
+
+// ζ(s, a) = Σ (a + k)^-s summed directly until a + k is large enough for the
+// Euler-Maclaurin tail to converge. With `regular` set the pole 1/(s - 1) is
+// left out, which keeps the rest accurate next to s = 1. For negative s the
+// direct terms grow and cancel against the tail, so accuracy drops there
+fn hurwitz_euler_maclaurin<T: Float>(s: T, a: T, regular: bool) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let threshold = s.abs().max(T::by_precision(5.0, 10.0));
+    let mut sum = T::ZERO;
+    let mut k = T::ZERO;
+
+    while a + k < threshold {
+        let term = (a + k).powf(-s);
+        sum += term;
+        k += T::ONE;
+
+        // For large s the remaining tail is bounded by the integral of x^-s
+        if s > T::ONE && term * (a + k) <= epsilon * sum * (s - T::ONE) {
+            return sum;
+        }
+    }
+
+    let w = a + k;
+    let w_s = w.powf(-s);
+    sum += T::HALF * w_s;
+    sum += if !regular {
+        w * w_s / (s - T::ONE)
+    } else if s == T::ONE {
+        -w.ln()
+    } else {
+        ((T::ONE - s) * w.ln()).exp_m1() / (s - T::ONE)
+    };
+
+    let w2 = w * w;
+    let mut factor = s * w_s / w;
+    for (j, &c) in EULER_MACLAURIN.iter().enumerate() {
+        let term = T::from_f64(c) * factor;
+        sum += term;
+        if term.abs() <= epsilon * sum.abs() {
+            break;
+        }
+        let m = T::from_f64(2.0 * j as f64 + 1.0);
+        factor *= (s + m) * (s + m + T::ONE) / w2;
+    }
+
+    sum
+}
+
+fn hurwitz_euler_maclaurin_complex(s: f64, a: Complex) -> Complex {
+    let power = |w: Complex| cexp(clog(w) * -s);
+    let threshold = s.abs().max(10.0);
+    let mut sum = Complex::ZERO;
+    let mut k = 0.0;
+
+    while (a + k).abs() < threshold {
+        sum += power(a + k);
+        k += 1.0;
+    }
+
+    let w = a + k;
+    let w_s = power(w);
+    sum += w * w_s / (s - 1.0) + w_s * 0.5;
+
+    let w2 = w * w;
+    let mut factor = w_s / w * s;
+    for (j, &c) in EULER_MACLAURIN.iter().enumerate() {
+        let term = factor * c;
+        sum += term;
+        if term.abs() <= EPSILON * sum.abs() {
+            break;
+        }
+        let m = 2.0 * j as f64 + 1.0;
+        factor *= w2.recip() * ((s + m) * (s + m + 1.0));
+    }
+
+    sum
+}
+
+// π^x with the rounding error of π folded back in
+fn pi_power<T: Float>(x: T) -> T {
+    let pi_lo = T::from_f64(PI - T::PI.to_f64() + PI_LO);
+    T::PI.powf(x) * (T::ONE + x * pi_lo / T::PI)
+}
+
+// ζ(s) = 2^s π^(s-1) sin(πs/2) Γ(1 - s) ζ(1 - s)
+fn zeta_reflection<T: Float>(s: T) -> T {
+    let t = T::ONE - s;
+    let sine = sin_pi(T::HALF * s);
+
+    // 1 - s is rounded, which matters next to the pole of ζ(1 - s) where
+    // the rounding error would be amplified by 1/(t - 1)
+    let zeta_t = if t < T::TWO {
+        hurwitz_euler_maclaurin(t, T::ONE, true) - s.recip()
+    } else {
+        zeta(t)
+    };
+
+    if t < T::by_precision(34.0, 170.0) {
+        return T::TWO.powf(s) * pi_power(s - T::ONE) * sine * gamma(t) * zeta_t;
+    }
+
+    // Γ(1 - s) overflows on its own, so split it with the duplication formula
+    let half = T::HALF * t;
+    let first = gamma(half) * pi_power(-half);
+    let second = gamma(half + T::HALF) * pi_power(-half);
+    sine * first * second * T::from_f64(FRAC_1_SQRT_PI) * zeta_t
+}
+
+pub fn zeta<T: Float>(s: T) -> T {
+    if s.is_nan() {
+        return T::NAN;
+    }
+
+    if s.is_infinite() {
+        return if s > T::ZERO { T::ONE } else { T::NAN };
+    }
+
+    if s == T::ONE {
+        return T::INFINITY;
+    }
+
+    // Trivial zeros at the negative even integers
+    if s < T::ZERO && (T::HALF * s).fract() == T::ZERO {
+        return T::ZERO;
+    }
+
+    if s.abs() < T::by_precision(1e-5, 1e-10) {
+        return -T::HALF - T::HALF * T::from_f64(LN_2PI) * s;
+    }
+
+    if s < T::HALF {
+        return zeta_reflection(s);
+    }
+
+    hurwitz_euler_maclaurin(s, T::ONE, false)
+}
+
+pub fn hurwitz_zeta<T: Float>(s: T, a: T) -> T {
+    if s.is_nan() || a.is_nan() || a <= T::ZERO {
+        return T::NAN;
+    }
+
+    if s == T::ONE {
+        return T::INFINITY;
+    }
+
+    if s.is_infinite() {
+        if s < T::ZERO {
+            return T::NAN;
+        }
+        return if a < T::ONE {
+            T::INFINITY
+        } else if a == T::ONE {
+            T::ONE
+        } else {
+            T::ZERO
+        };
+    }
+
+    if a.is_infinite() {
+        return if s > T::ONE { T::ZERO } else { T::NAN };
+    }
+
+    hurwitz_euler_maclaurin(s, a, false)
+}
+
+pub fn dirichlet_eta<T: Float>(s: T) -> T {
+    if s.is_nan() {
+        return T::NAN;
+    }
+
+    // The pole of ζ cancels against the zero of 1 - 2^(1-s)
+    if s == T::ONE {
+        return T::LN_2;
+    }
+
+    if s == T::INFINITY {
+        return T::ONE;
+    }
+
+    -((T::ONE - s) * T::LN_2).exp_m1() * zeta(s)
+}
+
+// Σ z^k / k^s for |z| < 1
+fn polylog_series<T: Float>(s: T, z: T) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let mut sum = T::ZERO;
+    let mut power = T::ONE;
+
+    for k in 1..=MAX_ITERS {
+        power *= z;
+        let term = power * T::from_f64(k as f64).powf(-s);
+        sum += term;
+        if term.abs() <= epsilon * sum.abs() {
+            break;
+        }
+    }
+
+    sum
+}
+
+// Sums Σ c_k μ^k / k! where c_k may vanish for every other k
+fn sum_log_series<T: Float>(mu: T, skip: Option<usize>, coefficient: impl Fn(T) -> T) -> T {
+    let epsilon = T::tolerance(EPSILON);
+    let mut sum = T::ZERO;
+    let mut power = T::ONE;
+    let mut previous_small = false;
+
+    for k in 0..MAX_ITERS {
+        if k > 0 {
+            power *= mu / T::from_f64(k as f64);
+        }
+        if skip == Some(k) {
+            continue;
+        }
+
+        let term = coefficient(T::from_f64(k as f64)) * power;
+        sum += term;
+
+        let small = term.abs() <= epsilon * sum.abs();
+        if small && previous_small {
+            break;
+        }
+        previous_small = small;
+    }
+
+    sum
+}
+
+// Li_s(e^μ) = Γ(1 - s) (-μ)^(s-1) + Σ ζ(s - k) μ^k / k!
+fn polylog_near_one<T: Float>(s: T, z: T) -> T {
+    let mu = z.ln();
+    let n = s.round();
+    let epsilon = s - n;
+
+    if n >= T::ONE && epsilon.abs() < T::from_f64(0.25) {
+        let m = n.to_f64() as usize - 1;
+
+        // Close to a positive integer the first term and the k = n - 1 term
+        // both grow like 1/(s - n), so their sum is formed with the poles cancelled
+        let difference = if epsilon == T::ZERO {
+            let harmonic = (1..=m).fold(T::ZERO, |acc, k| acc + T::from_f64(k as f64).recip());
+            harmonic - (-mu).ln()
+        } else {
+            let log_ratio = log_gamma1p(-epsilon) + epsilon * (-mu).ln()
+                - (1..=m).fold(T::ZERO, |acc, k| {
+                    acc + (epsilon / T::from_f64(k as f64)).ln_1p()
+                });
+            -log_ratio.exp_m1() / epsilon
+                + hurwitz_euler_maclaurin(s - T::from_f64(m as f64), T::ONE, true)
+        };
+
+        let singular = mu.powi(m as i32) / gamma(n) * difference;
+        return singular + sum_log_series(mu, Some(m), |k| zeta(s - k));
+    }
+
+    gamma(T::ONE - s) * (-mu).powf(s - T::ONE) + sum_log_series(mu, None, |k| zeta(s - k))
+}
+
+// Li_s(-e^μ) = -Σ η(s - k) μ^k / k!, convergent for |μ| < π
+fn polylog_near_minus_one<T: Float>(s: T, z: T) -> T {
+    let mu = (-z).ln();
+    -sum_log_series(mu, None, |k| dirichlet_eta(s - k))
+}
+
+// ∫ t^(s-1) / (e^(t-L) + 1) dt over t > 0 for s > 1, split at the step t = L
+fn fermi_dirac_integral<T: Float>(s: T, l: T) -> T {
+    // Past this the substituted integrands underflow, or overflow in f32
+    let tau_max = T::by_precision(3.2, 4.5);
+
+    let below = double_exponential(
+        |tau: T| {
+            let u = T::PI * tau.sinh();
+            let t = l / (T::ONE + (-u).exp());
+            let rest = l / (T::ONE + u.exp());
+            let jacobian =
+                l * T::PI * tau.cosh() / (T::from_f64(4.0) * (T::HALF * u).cosh().powi(2));
+            t.powf(s - T::ONE) / (T::ONE + (-rest).exp()) * jacobian
+        },
+        tau_max,
+    );
+
+    let above = double_exponential(
+        |tau: T| {
+            let v = (T::FRAC_PI_2 * tau.sinh()).exp();
+            let jacobian = v * T::FRAC_PI_2 * tau.cosh();
+            ((s - T::ONE) * (l + v).ln() - v).exp() / (T::ONE + (-v).exp()) * jacobian
+        },
+        tau_max,
+    );
+
+    below + above
+}
+
+// Li_s(z) for z < -1, through Li_s(1/z) and Jonquière's relation
+fn polylog_inversion<T: Float>(s: T, z: T) -> T {
+    let log = (-z).ln();
+
+    // The Hurwitz zeta in Jonquière's relation cancels badly for s > 1
+    if s > T::ONE && s.fract() != T::ZERO {
+        return -fermi_dirac_integral(s, log) / gamma(s);
+    }
+
+    let inverse = polylog(s, z.recip());
+
+    // Li_n(-x) + (-1)^n Li_n(-1/x) = -ln^n(x)/n! - 2 Σ ln^(n-2r)(x)/(n-2r)! η(2r)
+    if s.fract() == T::ZERO {
+        if s < T::ZERO {
+            return -cos_pi(s) * inverse;
+        }
+
+        let n = s.to_f64() as i32;
+        let mut sum = log.powi(n) / gamma(s + T::ONE);
+        for r in 1..=n / 2 {
+            let m = n - 2 * r;
+            sum += T::TWO * log.powi(m) / gamma(T::from_f64(m as f64 + 1.0))
+                * dirichlet_eta(T::from_f64(2.0 * r as f64));
+        }
+        return -cos_pi(s) * inverse - sum;
+    }
+
+    // Li_s(z) + e^(iπs) Li_s(1/z) = (2π)^s / Γ(s) e^(iπs/2) ζ(1 - s, 1/2 + ln(-z)/2πi),
+    // evaluated in f64 as Complex is double precision only
+    let (s, log) = (s.to_f64(), log.to_f64());
+    let a = Complex::new(0.5, -log / TAU);
+    let hurwitz = hurwitz_euler_maclaurin_complex(1.0 - s, a);
+    let (log_gamma_value, sign) = log_gamma_sign(s);
+    let magnitude = sign * (s * LN_2PI - log_gamma_value).exp();
+    let rotation = Complex::new(cos_pi(0.5 * s), sin_pi(0.5 * s));
+
+    T::from_f64(magnitude * (rotation * hurwitz).re) - cos_pi(T::from_f64(s)) * inverse
+}
+
+pub fn polylog<T: Float>(s: T, z: T) -> T {
+    if s.is_nan() || z.is_nan() {
+        return T::NAN;
+    }
+
+    if s.is_infinite() {
+        return if s > T::ZERO && z.abs() <= T::ONE {
+            z
+        } else {
+            T::NAN
+        };
+    }
+
+    if z == T::ZERO {
+        return z;
+    }
+
+    // The series diverges on the branch cut z > 1 and at z = 1 for s <= 1
+    if z >= T::ONE {
+        return if z == T::ONE && s > T::ONE {
+            zeta(s)
+        } else if z == T::ONE {
+            T::INFINITY
+        } else {
+            T::NAN
+        };
+    }
+
+    if z == T::NEG_INFINITY {
+        return if s > T::ZERO {
+            T::NEG_INFINITY
+        } else if s == T::ZERO {
+            -T::ONE
+        } else {
+            T::ZERO
+        };
+    }
+
+    if z == -T::ONE {
+        return -dirichlet_eta(s);
+    }
+
+    if s == T::ZERO {
+        return z / (T::ONE - z);
+    }
+
+    if s == T::ONE {
+        return -(-z).ln_1p();
+    }
+
+    // The alternating series cancels badly for negative s, where the terms
+    // first grow like k^-s, so those go through the expansion around z = -1
+    let max_log = T::from_f64(ETA_SERIES_MAX_LOG);
+    let near_minus_one = z < T::ZERO && s < -T::ONE && (-z).ln() >= -max_log;
+    if (z.abs() <= T::HALF && !near_minus_one) || (z.abs() < T::ONE && s > T::from_f64(20.0)) {
+        return polylog_series(s, z);
+    }
+
+    if z > T::ZERO {
+        return polylog_near_one(s, z);
+    }
+
+    if (-z).ln().abs() <= max_log {
+        return polylog_near_minus_one(s, z);
+    }
+
+    polylog_inversion(s, z)
+}
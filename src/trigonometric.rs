@@ -1,74 +1,76 @@
-pub fn sin(x: f64) -> f64 {
+use crate::float::Float;
+
+pub fn sin<T: Float>(x: T) -> T {
     x.sin()
 }
 
-pub fn cos(x: f64) -> f64 {
+pub fn cos<T: Float>(x: T) -> T {
     x.cos()
 }
 
-pub fn tan(x: f64) -> f64 {
+pub fn tan<T: Float>(x: T) -> T {
     x.tan()
 }
 
-pub fn cot(x: f64) -> f64 {
-    1.0 / x.tan()
+pub fn cot<T: Float>(x: T) -> T {
+    T::ONE / x.tan()
 }
 
-pub fn csc(x: f64) -> f64 {
-    1.0 / x.sin()
+pub fn csc<T: Float>(x: T) -> T {
+    T::ONE / x.sin()
 }
 
-pub fn sec(x: f64) -> f64 {
-    1.0 / x.cos()
+pub fn sec<T: Float>(x: T) -> T {
+    T::ONE / x.cos()
 }
 
-pub fn arcsin(x: f64) -> f64 {
-    if x < -1.0 || x > 1.0 {
-        return f64::NAN;
+pub fn arcsin<T: Float>(x: T) -> T {
+    if x < -T::ONE || x > T::ONE {
+        return T::NAN;
     }
     x.asin()
 }
 
-pub fn arccos(x: f64) -> f64 {
-    if x < -1.0 || x > 1.0 {
-        return f64::NAN;
+pub fn arccos<T: Float>(x: T) -> T {
+    if x < -T::ONE || x > T::ONE {
+        return T::NAN;
     }
     x.acos()
 }
 
-pub fn arctan(x: f64) -> f64 {
+pub fn arctan<T: Float>(x: T) -> T {
     x.atan()
 }
 
-pub fn arccot(x: f64) -> f64 {
-    (1.0 / x).atan()
+pub fn arccot<T: Float>(x: T) -> T {
+    (T::ONE / x).atan()
 }
 
-pub fn arccsc(x: f64) -> f64 {
-    if x.abs() < 1.0 {
-        return f64::NAN;
+pub fn arccsc<T: Float>(x: T) -> T {
+    if x.abs() < T::ONE {
+        return T::NAN;
     }
-    (1.0 / x).asin()
+    (T::ONE / x).asin()
 }
 
-pub fn arcsec(x: f64) -> f64 {
-    if x.abs() < 1.0 {
-        return f64::NAN;
+pub fn arcsec<T: Float>(x: T) -> T {
+    if x.abs() < T::ONE {
+        return T::NAN;
     }
-    (1.0 / x).acos()
+    (T::ONE / x).acos()
 }
 
-pub fn sinc(x: f64) -> f64 {
+pub fn sinc<T: Float>(x: T) -> T {
     if x.is_nan() {
-        return f64::NAN;
+        return T::NAN;
     }
 
-    if x == 0.0 {
-        return 1.0;
+    if x == T::ZERO {
+        return T::ONE;
     }
 
     if x.is_infinite() {
-        return 0.0;
+        return T::ZERO;
     }
 
     x.sin() / x
@@ -1,41 +1,43 @@
-fn normalize_phase(x: f64) -> f64 {
-    let t = x % 1.0;
-    if t < 0.0 {
-        t + 1.0
+use crate::float::Float;
+
+fn normalize_phase<T: Float>(x: T) -> T {
+    let t = x % T::ONE;
+    if t < T::ZERO {
+        t + T::ONE
     } else {
         t
     }
 }
 
-pub fn square_wave(x: f64) -> f64 {
+pub fn square_wave<T: Float>(x: T) -> T {
     let t = normalize_phase(x);
-    if t < 0.5 {
-        1.0
+    if t < T::HALF {
+        T::ONE
     } else {
-        -1.0
+        -T::ONE
     }
 }
 
-pub fn sawtooth(x: f64) -> f64 {
+pub fn sawtooth<T: Float>(x: T) -> T {
     let t = normalize_phase(x);
-    2.0 * t - 1.0
+    T::TWO * t - T::ONE
 }
 
-pub fn triangle_wave(x: f64) -> f64 {
+pub fn triangle_wave<T: Float>(x: T) -> T {
     let t = normalize_phase(x);
-    if t < 0.5 {
-        4.0 * t - 1.0
+    if t < T::HALF {
+        T::from_f64(4.0) * t - T::ONE
     } else {
-        3.0 - 4.0 * t
+        T::from_f64(3.0) - T::from_f64(4.0) * t
     }
 }
 
-pub fn pulse_wave(x: f64, duty: f64) -> f64 {
-    let duty_cycle = duty.max(0.0).min(1.0);
+pub fn pulse_wave<T: Float>(x: T, duty: T) -> T {
+    let duty_cycle = duty.max(T::ZERO).min(T::ONE);
     let t = normalize_phase(x);
     if t < duty_cycle {
-        1.0
+        T::ONE
     } else {
-        -1.0
+        -T::ONE
     }
 }
@@ -0,0 +1,184 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+
+#[test]
+fn test_airy_ai() {
+    assert_relative_eq!(airy_ai(0.0), 0.3550280538878172, max_relative = 1e-15);
+    assert_relative_eq!(airy_ai(0.5), 0.23169360648083348, max_relative = 1e-15);
+    assert_relative_eq!(airy_ai(2.0), 0.03492413042327438, max_relative = 1e-14);
+    assert_relative_eq!(airy_ai(10.0), 1.1047532552898686e-10, max_relative = 1e-14);
+    assert_relative_eq!(airy_ai(-3.0), -0.37881429367765806, max_relative = 1e-14);
+    assert_relative_eq!(airy_ai(-50.0), -0.1618814236123209, max_relative = 1e-14);
+
+    // The phase stays exact deep in the oscillatory region
+    assert_relative_eq!(airy_ai(-1000.0), 0.05597189577301992, max_relative = 1e-13);
+}
+
+#[test]
+fn test_airy_bi() {
+    assert_relative_eq!(airy_bi(0.0), 0.6149266274460007, max_relative = 1e-15);
+    assert_relative_eq!(airy_bi(0.5), 0.8542770431031554, max_relative = 1e-15);
+    assert_relative_eq!(airy_bi(2.0), 3.2980949999782148, max_relative = 1e-14);
+    assert_relative_eq!(airy_bi(10.0), 455641153.54822516, max_relative = 1e-14);
+    assert_relative_eq!(airy_bi(-3.0), -0.19828962637492653, max_relative = 1e-14);
+    assert_relative_eq!(airy_bi(-50.0), -0.13715015212882006, max_relative = 1e-14);
+    assert_relative_eq!(airy_bi(-1000.0), -0.08326457411708063, max_relative = 1e-13);
+
+    // Bi only overflows once the value itself does
+    assert!(airy_bi(100.0_f64).is_finite());
+    assert_eq!(airy_bi(200.0), f64::INFINITY);
+}
+
+#[test]
+fn test_airy_derivatives() {
+    assert_relative_eq!(
+        airy_ai_derivative(0.0),
+        -0.2588194037928068,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_ai_derivative(2.0),
+        -0.05309038443365363,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_ai_derivative(-50.0),
+        0.968989837276749,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_bi_derivative(0.0),
+        0.4482883573538264,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_bi_derivative(10.0),
+        1429236134.4828658,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_bi_derivative(-3.0),
+        -0.6756112226852585,
+        max_relative = 1e-14
+    );
+
+    let (ai, ai_prime, bi, bi_prime) = airy(0.5);
+    assert_relative_eq!(ai, 0.23169360648083348, max_relative = 1e-15);
+    assert_relative_eq!(ai_prime, -0.2249105326646839, max_relative = 1e-15);
+    assert_relative_eq!(bi, 0.8542770431031554, max_relative = 1e-15);
+    assert_relative_eq!(bi_prime, 0.5445725641405923, max_relative = 1e-15);
+}
+
+#[test]
+fn test_airy_wronskian() {
+    // Ai Bi' - Ai' Bi = 1/π
+    for &x in &[-30.0, -4.5, -0.7, 0.3, 1.5, 6.0] {
+        let (ai, ai_prime, bi, bi_prime) = airy(x);
+        assert_relative_eq!(
+            ai * bi_prime - ai_prime * bi,
+            std::f64::consts::FRAC_1_PI,
+            max_relative = 1e-13
+        );
+    }
+}
+
+#[test]
+fn test_airy_scaled() {
+    assert_relative_eq!(
+        airy_ai_scaled(5.0),
+        0.18700211893594343,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_ai_scaled(100.0),
+        0.08919692093633041,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_ai_derivative_scaled(100.0),
+        -0.8921920625040315,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_bi_scaled(100.0),
+        0.1784310111708354,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        airy_bi_derivative_scaled(5.0),
+        0.8318782591248014,
+        max_relative = 1e-14
+    );
+
+    // Nothing is scaled on the negative axis
+    assert_eq!(airy_ai_scaled(-3.0), airy_ai(-3.0));
+    assert_eq!(airy_bi_derivative_scaled(-3.0), airy_bi_derivative(-3.0));
+
+    // The scaled values stay representable far past the underflow of Ai
+    assert_eq!(airy_ai(1e4), 0.0);
+    assert_relative_eq!(
+        airy_ai_scaled(1e4),
+        0.028209476238902756,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_airy_zeros() {
+    assert_relative_eq!(
+        airy_ai_zero::<f64>(1),
+        -2.338107410459767,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_ai_zero::<f64>(2),
+        -4.08794944413097,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_ai_zero::<f64>(10),
+        -12.828776752865757,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_ai_zero::<f64>(1000),
+        -281.03151961252155,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_bi_zero::<f64>(1),
+        -1.173713222709128,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_bi_zero::<f64>(2),
+        -3.271093302836353,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_bi_zero::<f64>(10),
+        -12.386417138582738,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        airy_bi_zero::<f64>(1000),
+        -280.93781120341527,
+        max_relative = 1e-15
+    );
+
+    assert!(airy_ai_zero::<f64>(0).is_nan());
+    assert!(airy_ai(airy_ai_zero::<f64>(5)).abs() < 1e-15);
+}
+
+#[test]
+fn test_airy_edge_cases() {
+    assert!(airy_ai(f64::NAN).is_nan());
+    assert!(airy_bi_derivative_scaled(f64::NAN).is_nan());
+
+    assert_eq!(airy_ai(f64::INFINITY), 0.0);
+    assert_eq!(airy_ai(f64::NEG_INFINITY), 0.0);
+    assert_eq!(airy_bi(f64::INFINITY), f64::INFINITY);
+    assert_eq!(airy_bi(f64::NEG_INFINITY), 0.0);
+    assert!(airy_ai_derivative(f64::NEG_INFINITY).is_nan());
+    assert_eq!(airy_bi_derivative(f64::INFINITY), f64::INFINITY);
+}
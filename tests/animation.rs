@@ -101,7 +101,7 @@ fn test_elastic_boundary() {
 
 #[test]
 fn test_elastic_oscillation() {
-    let mid_values = [0.1, 0.3, 0.5, 0.7, 0.9];
+    let mid_values = [0.1_f64, 0.3, 0.5, 0.7, 0.9];
     for &x in &mid_values {
         let val = elastic(x);
         assert!(val.abs() > 0.0);
@@ -0,0 +1,277 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::PI;
+
+#[test]
+fn test_bessel_j_values() {
+    assert_relative_eq!(bessel_j(0.0, 1.0), 0.7651976865579666, max_relative = 1e-15);
+    assert_relative_eq!(
+        bessel_j(1.0, 2.5),
+        0.49709410246427405,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_j(2.5, 0.3),
+        0.0026053018556586676,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_j(5.0, 10.0),
+        -0.23406152818679363,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_j(0.5, 100.0),
+        -0.04040213271625212,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_j(10.0, 1e4),
+        0.007114312383354275,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        bessel_j(30.0, 25.0),
+        0.011809026124269015,
+        max_relative = 1e-14
+    );
+
+    // J_1/2 is elementary
+    for &x in &[0.1, 1.0, 7.5] {
+        assert_relative_eq!(
+            bessel_j(0.5, x),
+            (2.0 / (PI * x)).sqrt() * x.sin(),
+            max_relative = 1e-14
+        );
+    }
+}
+
+#[test]
+fn test_bessel_j_negative_order_and_argument() {
+    assert_relative_eq!(
+        bessel_j(-0.5, 2.0),
+        -0.23478571040624846,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_j(-3.0, 4.2),
+        -0.4343942763872008,
+        max_relative = 1e-15
+    );
+    assert_eq!(bessel_j(-3.0, 4.2), -bessel_j(3.0, 4.2));
+
+    assert_eq!(bessel_j(2.0, -1.5), bessel_j(2.0, 1.5));
+    assert_eq!(bessel_j(3.0, -1.5), -bessel_j(3.0, 1.5));
+    assert!(bessel_j(0.5, -1.5_f64).is_nan());
+}
+
+#[test]
+fn test_bessel_y_values() {
+    assert_relative_eq!(
+        bessel_y(0.0, 1.0),
+        0.08825696421567696,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(bessel_y(1.0, 2.5), 0.1459181379667858, max_relative = 1e-14);
+    assert_relative_eq!(bessel_y(2.5, 0.3), -49.30221696995155, max_relative = 1e-15);
+    assert_relative_eq!(
+        bessel_y(5.0, 10.0),
+        0.13540304768936232,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_y(0.5, 100.0),
+        -0.06880309146872808,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_y(-0.5, 2.0),
+        0.5130161365618278,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_y(-3.0, 4.2),
+        0.11182671687254792,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_y(30.0, 25.0),
+        -1.6575809094094003,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_bessel_wronskian() {
+    // J_ν+1 Y_ν - J_ν Y_ν+1 = 2 / (πx)
+    for &nu in &[0.0, 0.3, 2.0, 7.5, 40.0] {
+        for &x in &[0.5, 3.0, 18.0, 60.0, 500.0] {
+            let w =
+                bessel_j(nu + 1.0, x) * bessel_y(nu, x) - bessel_j(nu, x) * bessel_y(nu + 1.0, x);
+            assert_relative_eq!(w, 2.0 / (PI * x), max_relative = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_bessel_i_k_values() {
+    assert_relative_eq!(bessel_i(0.0, 1.0), 1.2660658777520084, max_relative = 1e-15);
+    assert_relative_eq!(bessel_i(1.0, 2.5), 2.5167162452886984, max_relative = 1e-15);
+    assert_relative_eq!(
+        bessel_i(2.5, 0.3),
+        0.0026390148935902732,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(bessel_i(5.0, 10.0), 777.18828640326, max_relative = 1e-14);
+    assert_relative_eq!(
+        bessel_i(0.0, 700.0),
+        1.5295933476718737e302,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(bessel_i(-0.5, 2.0), 2.122591620177637, max_relative = 1e-15);
+    assert_relative_eq!(bessel_i(-1.5, 3.0), 3.0689036507871, max_relative = 1e-14);
+    assert_eq!(bessel_i(-3.0, 4.2), bessel_i(3.0, 4.2));
+    assert_eq!(bessel_i(3.0, -4.2), -bessel_i(3.0, 4.2));
+
+    assert_relative_eq!(
+        bessel_k(0.0, 1.0),
+        0.42102443824070834,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_k(1.0, 2.5),
+        0.07389081634774707,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(bessel_k(2.5, 0.3), 75.15214016437488, max_relative = 1e-15);
+    assert_relative_eq!(
+        bessel_k(5.0, 10.0),
+        5.754184998531228e-5,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_k(0.5, 100.0),
+        4.6624238126346715e-45,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        bessel_k(0.0, 1e-10),
+        23.14178244559887,
+        max_relative = 1e-15
+    );
+    assert_eq!(bessel_k(-0.5, 2.0), bessel_k(0.5, 2.0));
+}
+
+#[test]
+fn test_bessel_scaled() {
+    assert_relative_eq!(
+        bessel_i_scaled(0.0, 1000.0),
+        0.012617240455891257,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_i_scaled(2.0, 1e6),
+        0.0003989415323849842,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_k_scaled(0.0, 1000.0),
+        0.03962832160075422,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        bessel_k_scaled(2.0, 1e6),
+        0.0012533164872805358,
+        max_relative = 1e-15
+    );
+
+    for &x in &[0.5_f64, 4.0, 30.0] {
+        assert_relative_eq!(
+            bessel_i_scaled(1.5, x),
+            bessel_i(1.5, x) * (-x).exp(),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            bessel_k_scaled(1.5, x),
+            bessel_k(1.5, x) * x.exp(),
+            max_relative = 1e-14
+        );
+    }
+
+    assert_eq!(bessel_i(0.0, 1000.0), f64::INFINITY);
+    assert_eq!(bessel_k(0.0, 1000.0), 0.0);
+}
+
+#[test]
+fn test_bessel_special_cases() {
+    assert_eq!(bessel_j(0.0, 0.0), 1.0);
+    assert_eq!(bessel_j(2.5, 0.0), 0.0);
+    assert_eq!(bessel_y(1.0, 0.0), f64::NEG_INFINITY);
+    assert_eq!(bessel_i(0.0, 0.0), 1.0);
+    assert_eq!(bessel_i(1.0, 0.0), 0.0);
+    assert_eq!(bessel_k(1.0, 0.0), f64::INFINITY);
+
+    assert_eq!(bessel_j(1.0, f64::INFINITY), 0.0);
+    assert_eq!(bessel_y(1.0, f64::INFINITY), 0.0);
+    assert_eq!(bessel_i(1.0, f64::INFINITY), f64::INFINITY);
+    assert_eq!(bessel_k(1.0, f64::INFINITY), 0.0);
+
+    // Underflow and overflow for orders far above the argument
+    assert_eq!(bessel_j(300.0, 1.0), 0.0);
+    assert_eq!(bessel_y(300.0, 1.0), f64::NEG_INFINITY);
+
+    assert!(bessel_j(f64::NAN, 1.0).is_nan());
+    assert!(bessel_y(1.0, -1.0_f64).is_nan());
+    assert!(bessel_k(1.0, -1.0_f64).is_nan());
+}
+
+#[test]
+fn test_spherical_bessel() {
+    assert_relative_eq!(
+        spherical_bessel_j(0, 2.0),
+        0.45464871341284085,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_bessel_j(1, 0.001),
+        0.0003333333000000012,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_bessel_j(3, 5.0),
+        0.229820618164296,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_bessel_j(10, 20.0),
+        0.03968669864462637,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(
+        spherical_bessel_y(0, 2.0),
+        0.2080734182735712,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_bessel_y(1, 0.5),
+        -4.469181324769897,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_bessel_y(3, 5.0),
+        -0.015442909912994204,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        spherical_bessel_y(10, 20.0),
+        -0.03684341049628996,
+        max_relative = 1e-14
+    );
+
+    assert_eq!(spherical_bessel_j(0, 0.0), 1.0);
+    assert_eq!(spherical_bessel_j(2, 0.0), 0.0);
+    assert_eq!(spherical_bessel_y(2, 0.0), f64::NEG_INFINITY);
+    assert_eq!(spherical_bessel_j(1, -2.0), -spherical_bessel_j(1, 2.0));
+    assert_eq!(spherical_bessel_y(1, -2.0), spherical_bessel_y(1, 2.0));
+}
@@ -0,0 +1,168 @@
+use rmath::*;
+
+fn big(s: &str) -> BigInt {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_bigint_parse_display() {
+    assert_eq!(big("0").to_string(), "0");
+    assert_eq!(big("-0"), BigInt::ZERO);
+    assert_eq!(big("+42"), BigInt::from(42));
+    assert_eq!(big("000123").to_string(), "123");
+    assert_eq!(
+        big("-1428571428571428571428571428571428571428").to_string(),
+        "-1428571428571428571428571428571428571428"
+    );
+
+    assert!("".parse::<BigInt>().is_err());
+    assert!("-".parse::<BigInt>().is_err());
+    assert!("12a3".parse::<BigInt>().is_err());
+    assert!("1 000".parse::<BigInt>().is_err());
+
+    assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+    assert_eq!(format!("{:+}", BigInt::from(7)), "+7");
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    let a = BigInt::from(2).pow(200) + 12345;
+    let b = BigInt::from(3).pow(70) - 1;
+
+    assert_eq!(
+        (&a * &b).to_string(),
+        "4022415811669964826000051984469584862371672888614697639529284824813221253584108068345918320408"
+    );
+    assert_eq!(&a + &b - &b, a);
+    assert_eq!(&b - &a, -(&a - &b));
+    assert_eq!(BigInt::from(u64::MAX) + 1, BigInt::from(1u128 << 64));
+    assert_eq!(BigInt::from(-5) * BigInt::ZERO, BigInt::ZERO);
+    assert!(!(BigInt::from(-5) * BigInt::ZERO).is_negative());
+
+    let mut acc = BigInt::from(1);
+    for i in 1..=30 {
+        acc *= i;
+    }
+    assert_eq!(acc.to_string(), "265252859812191058636308480000000");
+    assert_eq!((1..=30).map(BigInt::from).product::<BigInt>(), acc);
+    assert_eq!(
+        (1..=100).map(BigInt::from).sum::<BigInt>(),
+        BigInt::from(5050)
+    );
+}
+
+#[test]
+fn test_bigint_large_multiplication() {
+    // Large enough operands go through Karatsuba, check against (x + 1)^2
+    let x = BigInt::from(7).pow(2000) - 1;
+    let y = &x + 1;
+    assert_eq!(&y * &y, &x * &x + &x * 2 + 1);
+    assert_eq!(&y * &y, BigInt::from(7).pow(4000));
+    assert_eq!((&y * &y).isqrt(), y);
+}
+
+#[test]
+fn test_bigint_division() {
+    let a = BigInt::from(2).pow(200) + 12345;
+    let b = BigInt::from(3).pow(70) - 1;
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(q.to_string(), "641964928288915446533058609");
+    assert_eq!(r.to_string(), "1182511599879949051632295345848289");
+    assert_eq!(&q * &b + &r, a);
+
+    // Truncating division, like the primitive integers
+    for (x, y) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (0, 3)] {
+        let (q, r) = BigInt::from(x).div_rem(&BigInt::from(y));
+        assert_eq!(q, BigInt::from(x / y));
+        assert_eq!(r, BigInt::from(x % y));
+    }
+
+    assert_eq!(BigInt::from(100) / BigInt::from(7), BigInt::from(14));
+    assert_eq!(BigInt::from(100) % 7, BigInt::from(2));
+}
+
+#[test]
+fn test_bigint_shifts_and_bits() {
+    let one = BigInt::from(1);
+    assert_eq!(&one << 128, BigInt::from(u128::MAX) + 1);
+    assert_eq!((&one << 128).bits(), 129);
+    assert_eq!((&one << 100).trailing_zeros(), Some(100));
+    assert_eq!(BigInt::ZERO.trailing_zeros(), None);
+    assert_eq!(BigInt::from(u128::MAX) >> 64, BigInt::from(u64::MAX));
+
+    // Shifting a negative value right rounds toward negative infinity
+    assert_eq!(BigInt::from(-7) >> 1, BigInt::from(-4));
+    assert_eq!(BigInt::from(-8) >> 3, BigInt::from(-1));
+    assert_eq!(BigInt::from(-1) >> 10, BigInt::from(-1));
+    assert_eq!(BigInt::from(-3) << 2, BigInt::from(-12));
+}
+
+#[test]
+fn test_bigint_pow_isqrt() {
+    assert_eq!(
+        BigInt::from(3).pow(100).to_string(),
+        "515377520732011331036461129765621272702107522001"
+    );
+    assert_eq!(BigInt::from(-2).pow(3), BigInt::from(-8));
+    assert_eq!(BigInt::from(0).pow(0), BigInt::from(1));
+
+    let n = BigInt::from(10).pow(50) + 123;
+    assert_eq!(n.isqrt(), BigInt::from(10).pow(25));
+    assert_eq!(BigInt::from(99).isqrt(), BigInt::from(9));
+    assert_eq!(BigInt::from(100).isqrt(), BigInt::from(10));
+    assert_eq!(BigInt::ZERO.isqrt(), BigInt::ZERO);
+}
+
+#[test]
+fn test_bigint_conversions() {
+    assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!((BigInt::from(i64::MAX) + 1).to_i64(), None);
+    assert_eq!(BigInt::from(u64::MAX).to_u64(), Some(u64::MAX));
+    assert_eq!(BigInt::from(-1).to_u64(), None);
+    assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+    assert_eq!(BigInt::from(u128::MAX).to_u128(), Some(u128::MAX));
+
+    assert_eq!(BigInt::from(1u64 << 53).to_f64(), 9007199254740992.0);
+    // Ties round to even
+    assert_eq!(BigInt::from((1u64 << 53) + 1).to_f64(), 9007199254740992.0);
+    assert_eq!(BigInt::from((1u64 << 53) + 3).to_f64(), 9007199254740996.0);
+    assert_eq!(BigInt::from(-5).to_f64(), -5.0);
+    assert_eq!(BigInt::from(10).pow(300).to_f64(), 1e300);
+    assert_eq!(BigInt::from(10).pow(400).to_f64(), f64::INFINITY);
+}
+
+#[test]
+fn test_bigint_ordering_gcd() {
+    let mut values: Vec<BigInt> = [
+        "5",
+        "-100000000000000000000",
+        "0",
+        "-3",
+        "18446744073709551616",
+    ]
+    .iter()
+    .map(|s| big(s))
+    .collect();
+    values.sort();
+    let sorted: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    assert_eq!(
+        sorted,
+        [
+            "-100000000000000000000",
+            "-3",
+            "0",
+            "5",
+            "18446744073709551616"
+        ]
+    );
+
+    assert_eq!(BigInt::from(-3).signum(), -1);
+    assert_eq!(BigInt::ZERO.signum(), 0);
+    assert!(BigInt::from(-3).abs().is_positive());
+
+    let a = BigInt::from(2).pow(100) * 15;
+    let b = BigInt::from(2).pow(90) * 21;
+    assert_eq!(a.gcd(&b), BigInt::from(2).pow(90) * 3);
+    assert_eq!(a.lcm(&b), BigInt::from(2).pow(100) * 105);
+    assert_eq!((-&a).gcd(&b), a.gcd(&b));
+}
@@ -0,0 +1,205 @@
+use approx::assert_relative_eq;
+use rmath::*;
+
+#[test]
+fn test_factorials() {
+    assert_eq!(factorial_u64(0), Some(1));
+    assert_eq!(factorial_u64(10), Some(3628800));
+    assert_eq!(factorial_u64(20), Some(2432902008176640000));
+    assert_eq!(factorial_u64(21), None);
+
+    assert_eq!(factorial_big(20), BigInt::from(2432902008176640000u64));
+    assert_eq!(
+        factorial_big(30).to_string(),
+        "265252859812191058636308480000000"
+    );
+    assert_eq!(factorial_big(100).to_string().len(), 158);
+    assert_eq!(
+        factorial_big(100) % BigInt::from(10i64).pow(24),
+        BigInt::ZERO
+    );
+
+    assert_eq!(double_factorial(0), Some(1));
+    assert_eq!(double_factorial(1), Some(1));
+    assert_eq!(double_factorial(8), Some(384));
+    assert_eq!(double_factorial(9), Some(945));
+    assert_eq!(double_factorial(33), Some(6332659870762850625));
+    assert_eq!(double_factorial(34), None);
+}
+
+#[test]
+fn test_binomial_multinomial() {
+    assert_eq!(binomial(0, 0), Some(1));
+    assert_eq!(binomial(5, 2), Some(10));
+    assert_eq!(binomial(5, 7), Some(0));
+    assert_eq!(binomial(1_000_000_000, 2), Some(499999999500000000));
+    assert_eq!(binomial(67, 33), Some(14226520737620288370));
+    assert_eq!(binomial(68, 34), None);
+    assert_eq!(binomial(u64::MAX, 1), Some(u64::MAX));
+
+    assert_eq!(binomial_big(67, 33), BigInt::from(14226520737620288370u64));
+    assert_eq!(binomial_big(3, 5), BigInt::ZERO);
+    assert_eq!(
+        binomial_big(100, 50).to_string(),
+        "100891344545564193334812497256"
+    );
+
+    assert_eq!(multinomial(&[]), Some(1));
+    assert_eq!(multinomial(&[2, 3, 4]), Some(1260));
+    assert_eq!(multinomial(&[1, 1, 1, 1]), Some(24));
+    assert_eq!(multinomial(&[30, 30, 30]), None);
+}
+
+#[test]
+fn test_stirling_numbers() {
+    let s1: Vec<i64> = (0..=4).map(|k| stirling_s1(4, k).unwrap()).collect();
+    assert_eq!(s1, vec![0, -6, 11, -6, 1]);
+    assert_eq!(stirling_s1(10, 3), Some(-1172700));
+    assert_eq!(stirling_s1(20, 5), Some(-371384787345228000));
+    assert_eq!(stirling_s1(0, 0), Some(1));
+    assert_eq!(stirling_s1(3, 5), Some(0));
+    assert_eq!(stirling_s1(30, 10), None);
+
+    let s2: Vec<u64> = (0..=5).map(|k| stirling_s2(5, k).unwrap()).collect();
+    assert_eq!(s2, vec![0, 1, 15, 25, 10, 1]);
+    assert_eq!(stirling_s2(10, 3), Some(9330));
+    assert_eq!(stirling_s2(25, 12), Some(362262620784874680));
+    assert_eq!(stirling_s2(200, 200), Some(1));
+    assert_eq!(stirling_s2(200, 199), Some(19900));
+    assert_eq!(stirling_s2(200, 100), None);
+
+    assert_eq!(
+        stirling_s1_big(30, 10).to_string(),
+        "215760462268683520394805979744"
+    );
+    assert_eq!(stirling_s1_big(10, 3), BigInt::from(-1172700));
+    assert_eq!(stirling_s1_big(3, 5), BigInt::ZERO);
+    assert_eq!(stirling_s2_big(25, 12), BigInt::from(362262620784874680u64));
+    assert_eq!(
+        stirling_s2_big(200, 100).to_string(),
+        "2283943596473854926494186023981050257599257601238577334618926128114625773649859560873689656807578061558677832404485591735394159623463212267910289858070088234410209458409430330345615635450585241866631706977579545278456032333350189907556"
+    );
+
+    // Row sums of the second kind are the Bell numbers
+    for n in 0..20 {
+        let sum: u64 = (0..=n).map(|k| stirling_s2(n, k).unwrap()).sum();
+        assert_eq!(Some(sum), bell_number(n));
+    }
+}
+
+#[test]
+fn test_bell_catalan_narayana() {
+    let bell: Vec<u64> = (0..10).map(|n| bell_number(n).unwrap()).collect();
+    assert_eq!(bell, vec![1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147]);
+    assert_eq!(bell_number(25), Some(4638590332229999353));
+    assert_eq!(bell_number(26), None);
+    assert_eq!(bell_number_big(0), BigInt::from(1));
+    assert_eq!(bell_number_big(25), BigInt::from(4638590332229999353u64));
+    assert_eq!(
+        bell_number_big(100).to_string(),
+        "47585391276764833658790768841387207826363669686825611466616334637559114497892442622672724044217756306953557882560751"
+    );
+
+    let catalan: Vec<u64> = (0..10).map(|n| catalan_number(n).unwrap()).collect();
+    assert_eq!(catalan, vec![1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]);
+    assert_eq!(catalan_number(36), Some(11959798385860453492));
+    assert_eq!(catalan_number(37), None);
+
+    let narayana: Vec<u64> = (1..=5).map(|k| narayana_number(5, k).unwrap()).collect();
+    assert_eq!(narayana, vec![1, 10, 20, 10, 1]);
+    assert_eq!(narayana_number(0, 0), Some(1));
+    assert_eq!(narayana_number(4, 0), Some(0));
+    assert_eq!(narayana_number(4, 5), Some(0));
+    for n in 1..30 {
+        let sum: u64 = (1..=n).map(|k| narayana_number(n, k).unwrap()).sum();
+        assert_eq!(Some(sum), catalan_number(n as u32));
+    }
+}
+
+#[test]
+fn test_euler_zigzag() {
+    let zigzag: Vec<u64> = (0..11).map(|n| euler_zigzag(n).unwrap()).collect();
+    assert_eq!(zigzag, vec![1, 1, 1, 2, 5, 16, 61, 272, 1385, 7936, 50521]);
+    assert!(euler_zigzag(24).is_some());
+    assert_eq!(euler_zigzag(25), None);
+}
+
+#[test]
+fn test_partitions() {
+    let p: Vec<u64> = (0..12).map(|n| partitions_p(n).unwrap()).collect();
+    assert_eq!(p, vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56]);
+    assert_eq!(partitions_p(100), Some(190569292));
+    assert_eq!(partitions_p(416), Some(17873792969689876004));
+    assert_eq!(partitions_p(417), None);
+
+    assert_eq!(partitions_p_big(0), BigInt::from(1));
+    assert_eq!(partitions_p_big(100), BigInt::from(190569292));
+    assert_eq!(
+        partitions_p_big(1000).to_string(),
+        "24061467864032622473692149727991"
+    );
+}
+
+#[test]
+fn test_log_space() {
+    assert_relative_eq!(
+        log_factorial(1000.0),
+        5912.128178488163,
+        max_relative = 1e-14
+    );
+    assert_eq!(log_factorial(0.0), 0.0);
+    assert_relative_eq!(
+        log_binomial(1e6, 10.0),
+        123.05064800642472,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        log_binomial(100.0, 50.0).exp(),
+        1.0089134454556419e29,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(log_binomial(10.0, 0.0), 0.0, epsilon = 1e-14);
+    assert_relative_eq!(log_binomial(5.0f32, 2.0), 10.0f32.ln(), max_relative = 1e-6);
+    assert_relative_eq!(
+        log_multinomial(&[2.0, 3.0, 4.0]),
+        1260.0f64.ln(),
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(log_double_factorial(0.0), 0.0, epsilon = 1e-14);
+    assert_relative_eq!(log_double_factorial(1.0), 0.0, epsilon = 1e-14);
+    assert_relative_eq!(
+        log_double_factorial(7.0),
+        105.0f64.ln(),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        log_double_factorial(8.0),
+        384.0f64.ln(),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        log_double_factorial(1001.0),
+        2961.13288452734983,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        log_double_factorial(1000.0),
+        2957.90404874012874,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(log_catalan(0.0), 0.0, epsilon = 1e-14);
+    assert_relative_eq!(log_catalan(9.0), 4862.0f64.ln(), max_relative = 1e-14);
+    assert_relative_eq!(
+        log_catalan(500.0),
+        683.250655466766315,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(log_narayana(5.0, 3.0), 20.0f64.ln(), max_relative = 1e-14);
+    assert_relative_eq!(
+        log_narayana(1000.0, 300.0),
+        1206.78651183738900,
+        max_relative = 1e-14
+    );
+}
@@ -0,0 +1,314 @@
+use approx::assert_relative_eq;
+use rmath::complex::*;
+use std::f64::consts::{E, FRAC_PI_2, PI};
+
+fn assert_complex_eq(actual: Complex, expected: Complex, epsilon: f64) {
+    assert_relative_eq!(actual.re, expected.re, epsilon = epsilon);
+    assert_relative_eq!(actual.im, expected.im, epsilon = epsilon);
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    let a = Complex::new(1.0, 2.0);
+    let b = Complex::new(3.0, -4.0);
+
+    assert_eq!(a + b, Complex::new(4.0, -2.0));
+    assert_eq!(a - b, Complex::new(-2.0, 6.0));
+    assert_eq!(a * b, Complex::new(11.0, 2.0));
+    assert_complex_eq(a / b, Complex::new(-0.2, 0.4), 1e-15);
+    assert_eq!(-a, Complex::new(-1.0, -2.0));
+
+    assert_eq!(a + 1.0, Complex::new(2.0, 2.0));
+    assert_eq!(2.0 * a, Complex::new(2.0, 4.0));
+    assert_eq!(1.0 - a, Complex::new(0.0, -2.0));
+    assert_complex_eq(1.0 / Complex::I, Complex::new(0.0, -1.0), 1e-15);
+
+    let mut c = a;
+    c += b;
+    c *= Complex::I;
+    c -= 1.0;
+    c /= 2.0;
+    assert_eq!(c, Complex::new(0.5, 2.0));
+
+    assert_eq!(Complex::I * Complex::I, Complex::new(-1.0, 0.0));
+}
+
+#[test]
+fn test_complex_division_no_overflow() {
+    let big = Complex::new(1e300, 1e300);
+    let q = big / big;
+    assert_complex_eq(q, Complex::ONE, 1e-15);
+}
+
+#[test]
+fn test_complex_polar() {
+    let z = Complex::new(3.0, 4.0);
+    assert_relative_eq!(z.abs(), 5.0, epsilon = 1e-15);
+    assert_relative_eq!(z.norm_sqr(), 25.0, epsilon = 1e-15);
+    assert_eq!(z.conj(), Complex::new(3.0, -4.0));
+
+    let (r, theta) = z.to_polar();
+    assert_complex_eq(Complex::from_polar(r, theta), z, 1e-14);
+    assert_relative_eq!(Complex::new(-1.0, 0.0).arg(), PI, epsilon = 1e-15);
+}
+
+#[test]
+fn test_complex_display() {
+    assert_eq!(Complex::new(1.0, 2.0).to_string(), "1+2i");
+    assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+}
+
+#[test]
+fn test_csqrt_negative_real() {
+    assert_complex_eq(csqrt(Complex::from(-1.0)), Complex::I, 1e-15);
+    assert_complex_eq(csqrt(Complex::from(-4.0)), Complex::new(0.0, 2.0), 1e-15);
+    assert_complex_eq(
+        csqrt(Complex::new(-4.0, -0.0)),
+        Complex::new(0.0, -2.0),
+        1e-15,
+    );
+    assert_complex_eq(csqrt(Complex::new(3.0, 4.0)), Complex::new(2.0, 1.0), 1e-15);
+    assert_eq!(csqrt(Complex::ZERO), Complex::ZERO);
+
+    let huge = Complex::new(1e308, 1e308);
+    let root = csqrt(huge);
+    assert!(root.is_finite());
+    assert_relative_eq!(root.re / 1e154, 1.09868411346781, epsilon = 1e-14);
+}
+
+#[test]
+fn test_cexp_clog() {
+    assert_complex_eq(cexp(Complex::new(0.0, PI)), Complex::new(-1.0, 0.0), 1e-15);
+    assert_complex_eq(cexp(Complex::ONE), Complex::new(E, 0.0), 1e-15);
+
+    assert_complex_eq(
+        clog(Complex::from(-2.0)),
+        Complex::new(2.0_f64.ln(), PI),
+        1e-15,
+    );
+    assert_complex_eq(clog(Complex::I), Complex::new(0.0, FRAC_PI_2), 1e-15);
+    assert_eq!(clog(Complex::ZERO).re, f64::NEG_INFINITY);
+
+    let z = Complex::new(0.3, -1.7);
+    assert_complex_eq(cexp(clog(z)), z, 1e-14);
+    assert_complex_eq(clog(cexp(z)), z, 1e-14);
+
+    // ln|z| for |z| close to one must not cancel
+    let near_one = Complex::new(1.0, 1e-10);
+    assert_relative_eq!(clog(near_one).re, 5e-21, max_relative = 1e-10);
+}
+
+#[test]
+fn test_clog_bases() {
+    assert_complex_eq(
+        clog10(Complex::from(-100.0)),
+        Complex::new(2.0, PI / 10.0_f64.ln()),
+        1e-14,
+    );
+    assert_complex_eq(clog2(Complex::from(8.0)), Complex::new(3.0, 0.0), 1e-15);
+    assert_complex_eq(
+        clog_base(Complex::I, Complex::from(-1.0)),
+        Complex::from(2.0),
+        1e-15,
+    );
+    assert!(clog_base(Complex::ONE, Complex::from(2.0)).is_nan());
+
+    assert_complex_eq(cexp2(Complex::from(10.0)), Complex::from(1024.0), 1e-12);
+    assert_complex_eq(cexp10(Complex::from(2.0)), Complex::from(100.0), 1e-12);
+}
+
+#[test]
+fn test_cpow() {
+    let root = cpow(Complex::from(-8.0), Complex::from(1.0 / 3.0));
+    assert_complex_eq(root, Complex::new(1.0, 3.0_f64.sqrt()), 1e-14);
+
+    assert_eq!(
+        cpow(Complex::new(1.0, 1.0), Complex::from(2.0)),
+        Complex::new(0.0, 2.0)
+    );
+    assert_complex_eq(
+        cpow(Complex::I, Complex::I),
+        Complex::from((-FRAC_PI_2).exp()),
+        1e-15,
+    );
+    assert_eq!(cpow(Complex::ZERO, Complex::from(2.0)), Complex::ZERO);
+    assert_eq!(cpow(Complex::new(5.0, 3.0), Complex::ZERO), Complex::ONE);
+    assert!(cpow(Complex::ZERO, Complex::I).is_nan());
+
+    assert_complex_eq(ccbrt(Complex::from(-8.0)), root, 1e-14);
+    assert_complex_eq(cnth_root(Complex::from(16.0), 4), Complex::from(2.0), 1e-15);
+    assert!(cnth_root(Complex::ONE, 0).is_nan());
+    assert_eq!(csquare(Complex::I), Complex::from(-1.0));
+    assert_eq!(ccube(Complex::I), Complex::new(0.0, -1.0));
+}
+
+#[test]
+fn test_ctrig_values() {
+    let z = Complex::new(1.0, 2.0);
+
+    assert_complex_eq(
+        csin(z),
+        Complex::new(3.165778513216168, 1.959601041421606),
+        1e-14,
+    );
+    assert_complex_eq(
+        ccos(z),
+        Complex::new(2.032723007019666, -3.0518977991518),
+        1e-14,
+    );
+    assert_complex_eq(
+        ctan(z),
+        Complex::new(0.0338128260798967, 1.0147936161466335),
+        1e-14,
+    );
+    assert_complex_eq(ccot(z) * ctan(z), Complex::ONE, 1e-14);
+    assert_complex_eq(ccsc(z) * csin(z), Complex::ONE, 1e-14);
+    assert_complex_eq(csec(z) * ccos(z), Complex::ONE, 1e-14);
+
+    assert_complex_eq(csinc(Complex::ZERO), Complex::ONE, 1e-15);
+    assert_complex_eq(csinc(z), csin(z) / z, 1e-15);
+
+    let big = ctan(Complex::new(0.5, 400.0));
+    assert!(big.is_finite());
+    assert_complex_eq(big, Complex::I, 1e-15);
+}
+
+#[test]
+fn test_ctrig_identities() {
+    let values = [
+        Complex::new(0.5, 0.5),
+        Complex::new(-1.2, 0.3),
+        Complex::new(2.0, -1.5),
+    ];
+
+    for &z in &values {
+        let s = csin(z);
+        let c = ccos(z);
+        assert_complex_eq(s * s + c * c, Complex::ONE, 1e-13);
+        assert_complex_eq(csin(casin(z)), z, 1e-13);
+        assert_complex_eq(ccos(cacos(z)), z, 1e-13);
+        assert_complex_eq(ctan(catan(z)), z, 1e-13);
+        assert_complex_eq(ccot(cacot(z)), z, 1e-13);
+        assert_complex_eq(ccsc(cacsc(z)), z, 1e-13);
+        assert_complex_eq(csec(casec(z)), z, 1e-13);
+    }
+}
+
+#[test]
+fn test_cinverse_trig_branches() {
+    assert_complex_eq(
+        casin(Complex::from(0.5)),
+        Complex::from(0.5_f64.asin()),
+        1e-15,
+    );
+    assert_complex_eq(
+        casin(Complex::from(2.0)),
+        Complex::new(FRAC_PI_2, 2.0_f64.acosh()),
+        1e-15,
+    );
+    assert_complex_eq(
+        casin(Complex::new(2.0, -0.0)),
+        Complex::new(FRAC_PI_2, -(2.0_f64.acosh())),
+        1e-15,
+    );
+    assert_complex_eq(
+        cacos(Complex::from(2.0)),
+        Complex::new(0.0, -(2.0_f64.acosh())),
+        1e-15,
+    );
+    assert_complex_eq(cacos(Complex::from(-1.0)), Complex::from(PI), 1e-15);
+    assert_complex_eq(catan(Complex::from(1.0)), Complex::from(PI / 4.0), 1e-15);
+    assert_complex_eq(
+        catan(Complex::new(0.0, 2.0)),
+        Complex::new(FRAC_PI_2, 3.0_f64.ln() / 2.0),
+        1e-15,
+    );
+}
+
+#[test]
+fn test_chyperbolic() {
+    let values = [
+        Complex::new(0.5, 0.5),
+        Complex::new(-1.2, 0.3),
+        Complex::new(2.0, -1.5),
+    ];
+
+    for &z in &values {
+        let ch = ccosh(z);
+        let sh = csinh(z);
+        assert_complex_eq(ch * ch - sh * sh, Complex::ONE, 1e-13);
+        assert_complex_eq(ctanh(z), sh / ch, 1e-14);
+        assert_complex_eq(ccoth(z), ch / sh, 1e-14);
+        assert_complex_eq(ccsch(z), sh.recip(), 1e-14);
+        assert_complex_eq(csech(z), ch.recip(), 1e-14);
+
+        assert_complex_eq(csinh(casinh(z)), z, 1e-13);
+        assert_complex_eq(ccosh(cacosh(z)), z, 1e-13);
+        assert_complex_eq(ctanh(catanh(z)), z, 1e-13);
+        assert_complex_eq(ccoth(cacoth(z)), z, 1e-13);
+        assert_complex_eq(ccsch(cacsch(z)), z, 1e-13);
+        assert_complex_eq(csech(casech(z)), z, 1e-13);
+    }
+
+    assert_complex_eq(
+        csinh(Complex::from(1.0)),
+        Complex::from(1.0_f64.sinh()),
+        1e-15,
+    );
+    assert_complex_eq(
+        cacosh(Complex::from(0.0)),
+        Complex::new(0.0, FRAC_PI_2),
+        1e-15,
+    );
+    assert_complex_eq(
+        catanh(Complex::from(2.0)),
+        Complex::new(3.0_f64.ln() / 2.0, FRAC_PI_2),
+        1e-15,
+    );
+    assert_eq!(catanh(Complex::from(1.0)).re, f64::INFINITY);
+}
+
+#[test]
+fn test_complex_inverse_tangents_small() {
+    // Relative accuracy near the origin, where ln(1 + z) - ln(1 - z) cancels
+    let cases = [
+        (catanh(Complex::new(1e-8, 1e-8)), Complex::new(1e-8, 1e-8)),
+        (
+            catanh(Complex::new(3e-5, -2e-6)),
+            Complex::new(3.000000000888e-5, -2.000000001797333e-6),
+        ),
+        (
+            catanh(Complex::new(-1e-300, 1e-300)),
+            Complex::new(-1e-300, 1e-300),
+        ),
+        (catan(Complex::new(1e-8, 1e-8)), Complex::new(1e-8, 1e-8)),
+        (
+            catan(Complex::new(-2e-7, 5e-9)),
+            Complex::new(-1.9999999999999732e-7, 4.9999999999998e-9),
+        ),
+    ];
+    for (actual, expected) in cases {
+        assert_relative_eq!(actual.re, expected.re, max_relative = 1e-15);
+        assert_relative_eq!(actual.im, expected.im, max_relative = 1e-15);
+    }
+
+    // The branch cut side follows the sign of a zero imaginary part
+    assert_eq!(catanh(Complex::new(2.0, 0.0)).im, FRAC_PI_2);
+    assert_eq!(catanh(Complex::new(2.0, -0.0)).im, -FRAC_PI_2);
+}
+
+#[test]
+fn test_complex_nan_propagation() {
+    let nan = Complex::new(f64::NAN, 0.0);
+
+    assert!(cexp(nan).is_nan());
+    assert!(clog(nan).is_nan());
+    assert!(csqrt(nan).is_nan());
+    assert!(ctan(nan).is_nan());
+    assert!(casin(nan).is_nan());
+    assert!(cacos(nan).is_nan());
+    assert!(catanh(nan).is_nan());
+    assert!(cacosh(nan).is_nan());
+    assert!(cpow(nan, Complex::ONE).is_nan());
+    assert!(csinc(nan).is_nan());
+}
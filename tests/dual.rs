@@ -0,0 +1,229 @@
+use approx::assert_relative_eq;
+use rmath::*;
+
+#[test]
+fn test_dual_arithmetic() {
+    let x = Dual::variable(3.0);
+    let c = Dual::constant(2.0);
+
+    let product = x * x * c;
+    assert_eq!(product.value, 18.0);
+    assert_eq!(product.derivative, 12.0);
+
+    let quotient = c / x;
+    assert_relative_eq!(quotient.value, 2.0 / 3.0, max_relative = 1e-15);
+    assert_relative_eq!(quotient.derivative, -2.0 / 9.0, max_relative = 1e-15);
+
+    let shifted = (x + 1.0) * 2.0 - x / 4.0;
+    assert_eq!(shifted.value, 7.25);
+    assert_eq!(shifted.derivative, 1.75);
+
+    // Comparisons only look at the value
+    assert_eq!(Dual::new(1.0, 5.0), Dual::new(1.0, -2.0));
+    assert!(Dual::new(1.0, 5.0) < Dual::constant(2.0));
+    assert_eq!(format!("{}", Dual::new(1.5, -2.0)), "1.5-2ε");
+}
+
+#[test]
+fn test_dual_elementary() {
+    let x = 0.7;
+    assert_relative_eq!(derivative(|x| x.sin(), x), x.cos(), max_relative = 1e-15);
+    assert_relative_eq!(derivative(|x| x.exp(), x), x.exp(), max_relative = 1e-15);
+    assert_relative_eq!(derivative(|x| x.ln(), x), 1.0 / x, max_relative = 1e-15);
+    assert_relative_eq!(
+        derivative(|x| x.sqrt(), x),
+        0.5 / x.sqrt(),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        derivative(|x| x.tanh(), x),
+        1.0 / (x.cosh() * x.cosh()),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        derivative(|x| x.asin(), x),
+        1.0 / (1.0 - x * x).sqrt(),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        derivative(|x| x.powi(3), x),
+        3.0 * x * x,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        derivative(|x| x.powf(Dual::constant(2.5)), x),
+        2.5 * x.powf(1.5),
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_dual_through_library_functions() {
+    // The generic functions differentiate without knowing about dual numbers
+    assert_relative_eq!(
+        derivative(smoothstep, 0.3),
+        6.0 * 0.3 * 0.7,
+        max_relative = 1e-15
+    );
+    assert_eq!(derivative(smoothstep, 1.5), 0.0);
+    assert_relative_eq!(derivative(ease_in_out_quad, 0.8), 0.8, max_relative = 1e-15);
+    assert_relative_eq!(
+        derivative(elastic, 0.6),
+        0.38715617594304945,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        derivative(|x| x.powf(x), 1.5),
+        2.5820042746129494,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        derivative(|x| tanh(x).powf(Dual::constant(2.5)) * log(x), 1.2),
+        0.6346154449714608,
+        max_relative = 1e-14
+    );
+
+    let angle = |t: Dual| (t.sin() * 2.0).atan2(t.cos() - t * 0.1);
+    assert_relative_eq!(
+        derivative(angle, 2.0),
+        0.636931521861574,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_dual_special_functions() {
+    let erf_at = Dual::variable(0.7).erf();
+    assert_relative_eq!(erf_at.value, erf(0.7), max_relative = 1e-15);
+    assert_relative_eq!(erf_at.derivative, 0.6912748604105386, max_relative = 1e-15);
+
+    let gamma_at = Dual::variable(2.5).gamma();
+    assert_relative_eq!(
+        gamma_at.derivative,
+        0.9347345216260855,
+        max_relative = 1e-14
+    );
+
+    // Running the generic gamma on dual numbers agrees with the closed form
+    assert_relative_eq!(
+        derivative(gamma, 2.5),
+        gamma_at.derivative,
+        max_relative = 1e-12
+    );
+
+    let digamma_at = Dual::variable(3.0).digamma();
+    assert_relative_eq!(digamma_at.derivative, trigamma(3.0), max_relative = 1e-15);
+}
+
+#[test]
+fn test_dual_lambert_w() {
+    let w = Dual::variable(2.0).product_log();
+    assert_relative_eq!(w.value, product_log(2.0), max_relative = 1e-15);
+    assert_relative_eq!(w.derivative, 0.23010983749291725, max_relative = 1e-15);
+
+    // Finite at the origin where W(z)/(z(1 + W(z))) is 0/0
+    assert_eq!(Dual::variable(0.0).product_log().derivative, 1.0);
+
+    let composed = derivative(|x| (x * x.exp() + x.sin()).product_log(), 1.3);
+    assert_relative_eq!(composed, 0.8873435037939362, max_relative = 1e-14);
+
+    let omega = derivative(|x| x.wright_omega(), 2.0);
+    assert_relative_eq!(omega, 0.6089389667948532, max_relative = 1e-14);
+}
+
+#[test]
+fn test_hyper_dual_second_derivatives() {
+    let x = 0.9;
+    assert_relative_eq!(
+        second_derivative(|x| x.sin(), x),
+        -x.sin(),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        second_derivative(|x| x.exp(), x),
+        x.exp(),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        second_derivative(|x| x.ln(), x),
+        -1.0 / (x * x),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        second_derivative(|x| x.powi(4), x),
+        12.0 * x * x,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        second_derivative(|x| x.powf(x), 1.5),
+        4.853661788346221,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        second_derivative(|x| tanh(x).powf(HyperDual::constant(2.5)) * log(x), 1.2),
+        0.4083560404862178,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        second_derivative(elastic, 0.6),
+        3.174391927584812,
+        max_relative = 1e-14
+    );
+
+    let product = HyperDual::variable(2.0) * HyperDual::variable(2.0) / HyperDual::variable(2.0);
+    assert_eq!(product.value, 2.0);
+    assert_eq!(product.derivative(), 1.0);
+    assert_eq!(product.second_derivative(), 0.0);
+}
+
+#[test]
+fn test_hyper_dual_special_functions() {
+    assert_relative_eq!(
+        HyperDual::variable(0.7).erf().second_derivative(),
+        -0.967784804574754,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        HyperDual::variable(2.5).gamma().second_derivative(),
+        1.3091171559626735,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        HyperDual::variable(2.0).product_log().second_derivative(),
+        -0.08153219555043212,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        HyperDual::variable(2.0).wright_omega().second_derivative(),
+        0.09312426386945529,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_dual_constants_at_singular_points() {
+    // Constants stay constant where f' is infinite, instead of ∞ · 0 = NaN
+    let root = Dual::constant(0.0).sqrt();
+    assert_eq!(root, Dual::constant(0.0));
+    assert_eq!(root.derivative, 0.0);
+    assert_eq!(Dual::constant(0.0).ln().derivative, 0.0);
+    assert_eq!(Dual::constant(1.0).acos().derivative, 0.0);
+
+    let root = HyperDual::constant(0.0).sqrt();
+    assert_eq!(root.value, 0.0);
+    assert_eq!(root.derivative(), 0.0);
+    assert_eq!(root.second_derivative(), 0.0);
+}
+
+#[test]
+fn test_dual_f32() {
+    let x = Dual::variable(0.7_f32);
+    assert_relative_eq!(x.sin().derivative, 0.7_f32.cos(), max_relative = 1e-6);
+    assert_relative_eq!(derivative(smoothstep, 0.3_f32), 1.26, max_relative = 1e-6);
+    assert_relative_eq!(x.erf().derivative, 0.6912749, max_relative = 1e-6);
+    assert_relative_eq!(
+        second_derivative(|x| x.exp(), 1.0_f32),
+        std::f32::consts::E,
+        max_relative = 1e-6
+    );
+}
@@ -0,0 +1,302 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn test_carlson_rf_rd_rc() {
+    assert_relative_eq!(
+        carlson_rf(1.0, 2.0, 3.0),
+        0.7269459354689082,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rf(0.5, 0.0, 4.0),
+        1.236798086875672,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rf(1e-3, 10.0, 100.0),
+        0.25681469881126545,
+        max_relative = 1e-15
+    );
+
+    assert_relative_eq!(
+        carlson_rd(1.0, 2.0, 3.0),
+        0.29046028102899063,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rd(0.0, 2.0, 1.0),
+        1.7972103521033884,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rd(0.2, 7.0, 0.5),
+        1.2897986102944412,
+        max_relative = 1e-15
+    );
+
+    assert_relative_eq!(carlson_rc(1.0, 2.0), PI / 4.0, max_relative = 1e-15);
+    assert_relative_eq!(
+        carlson_rc(2.0, 1.0),
+        0.881373587019543,
+        max_relative = 1e-15
+    );
+    // Cauchy principal value for negative y
+    assert_relative_eq!(
+        carlson_rc(0.5, -2.0),
+        0.30434508083896766,
+        max_relative = 1e-15
+    );
+
+    // R_F(x, x, x) = 1 / √x and R_D(x, x, x) = x^-3/2
+    assert_relative_eq!(carlson_rf(4.0, 4.0, 4.0), 0.5, max_relative = 1e-15);
+    assert_relative_eq!(carlson_rd(4.0, 4.0, 4.0), 0.125, max_relative = 1e-15);
+}
+
+#[test]
+fn test_carlson_rj() {
+    assert_relative_eq!(
+        carlson_rj(1.0, 2.0, 3.0, 4.0),
+        0.23984809974956775,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rj(0.5, 1.0, 2.0, 0.3),
+        1.8423947308351403,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        carlson_rj(2.0, 3.0, 4.0, -0.5),
+        0.24723819703051564,
+        max_relative = 1e-14
+    );
+
+    // R_J(x, y, z, z) = R_D(x, y, z)
+    assert_relative_eq!(
+        carlson_rj(0.3, 1.7, 2.5, 2.5),
+        carlson_rd(0.3, 1.7, 2.5),
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_carlson_special_cases() {
+    assert_eq!(carlson_rf(0.0, 0.0, 1.0), f64::INFINITY);
+    assert_eq!(carlson_rd(1.0, 1.0, 0.0), f64::INFINITY);
+    assert!(carlson_rf(-1.0, 2.0, 3.0_f64).is_nan());
+    assert!(carlson_rd(1.0, 2.0, -3.0_f64).is_nan());
+    assert!(carlson_rc(-1.0, 2.0_f64).is_nan());
+    assert!(carlson_rj(1.0, 2.0, f64::NAN, 1.0).is_nan());
+}
+
+#[test]
+fn test_complete_elliptic_integrals() {
+    assert_relative_eq!(elliptic_k(0.5), 1.8540746773013719, max_relative = 1e-15);
+    assert_relative_eq!(elliptic_k(0.9), 2.5780921133481733, max_relative = 1e-15);
+    assert_relative_eq!(elliptic_k(-3.0), 1.0782578237498217, max_relative = 1e-15);
+    assert_relative_eq!(
+        elliptic_k(1.0 - 1e-12),
+        15.201815980070121,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(elliptic_e(0.5), 1.3506438810476755, max_relative = 1e-15);
+    assert_relative_eq!(elliptic_e(0.9), 1.1047747327040733, max_relative = 1e-15);
+    assert_relative_eq!(elliptic_e(-3.0), 2.422112055136919, max_relative = 1e-15);
+    assert_relative_eq!(
+        elliptic_e(1.0 - 1e-12),
+        1.0000000000073508,
+        max_relative = 1e-15
+    );
+
+    assert_relative_eq!(elliptic_k(0.0), FRAC_PI_2, max_relative = 1e-15);
+    assert_relative_eq!(elliptic_e(0.0), FRAC_PI_2, max_relative = 1e-15);
+    assert_eq!(elliptic_k(1.0), f64::INFINITY);
+    assert_eq!(elliptic_e(1.0), 1.0);
+    assert!(elliptic_k(1.5_f64).is_nan());
+    assert!(elliptic_e(f64::NAN).is_nan());
+
+    // Perimeter of an ellipse with semi-axes 3 and 2
+    assert_relative_eq!(
+        4.0 * 3.0 * elliptic_e(1.0 - (2.0f64 / 3.0).powi(2)),
+        15.86543958929059,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_legendre_relation() {
+    // E K' + E' K - K K' = π / 2
+    for &m in &[0.01, 0.3, 0.5, 0.77, 0.999] {
+        let (k, e) = (elliptic_k(m), elliptic_e(m));
+        let (k1, e1) = (elliptic_k(1.0 - m), elliptic_e(1.0 - m));
+        assert_relative_eq!(e * k1 + e1 * k - k * k1, FRAC_PI_2, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_incomplete_elliptic_integrals() {
+    assert_relative_eq!(
+        elliptic_f(0.7, 0.5),
+        0.7287703057181902,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_f(2.5, 0.8),
+        3.835175676891544,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_f(-1.2, -2.0),
+        -0.9540256933864917,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_f(0.4, 3.0),
+        0.439965510363979,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(elliptic_f(7.0, 0.3), 7.59007171719006, max_relative = 1e-15);
+
+    assert_relative_eq!(
+        elliptic_e_incomplete(0.7, 0.5),
+        0.6731891745471288,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_e_incomplete(2.5, 0.8),
+        1.7494161539377033,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_e_incomplete(-1.2, -2.0),
+        -1.551875519436463,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_e_incomplete(0.4, 3.0),
+        0.36641881721723746,
+        max_relative = 1e-15
+    );
+
+    // Quasi-periodicity in the amplitude
+    assert_relative_eq!(
+        elliptic_f(FRAC_PI_2, 0.6),
+        elliptic_k(0.6),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_e_incomplete(PI + 0.3, 0.6),
+        2.0 * elliptic_e(0.6) + elliptic_e_incomplete(0.3, 0.6),
+        max_relative = 1e-15
+    );
+    assert_eq!(elliptic_f(0.0, 0.5), 0.0);
+    assert!(elliptic_f(1.0, 2.0_f64).is_nan());
+}
+
+#[test]
+fn test_elliptic_pi() {
+    assert_relative_eq!(
+        elliptic_pi(0.3, 0.7, 0.5),
+        0.7649831095832479,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        elliptic_pi(-0.5, 2.5, 0.8),
+        2.906170992074584,
+        max_relative = 1e-15
+    );
+    // Principal value past the singularity of the integrand
+    assert_relative_eq!(
+        elliptic_pi(2.0, 0.9, 0.4),
+        1.0936967971495675,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        elliptic_pi(0.5, FRAC_PI_2, 0.5),
+        2.701287762095351,
+        max_relative = 1e-15
+    );
+
+    for &phi in &[0.3, 1.1, 4.0] {
+        assert_relative_eq!(
+            elliptic_pi(0.0, phi, 0.7),
+            elliptic_f(phi, 0.7),
+            max_relative = 1e-15
+        );
+    }
+}
+
+#[test]
+fn test_jacobi_elliptic_functions() {
+    let cases = [
+        (
+            0.7,
+            0.5,
+            0.6243400909662173,
+            0.7811526424536344,
+            0.8972734953213249,
+        ),
+        (
+            3.0,
+            0.9,
+            0.9906305999378325,
+            -0.13656871701385334,
+            0.34173953973769106,
+        ),
+        (
+            2.0,
+            -1.5,
+            0.4711380091779783,
+            -0.8820595083710686,
+            1.1545373686192593,
+        ),
+        (
+            0.4,
+            2.5,
+            0.3659195504349829,
+            0.9306464863789364,
+            0.8156330097069698,
+        ),
+        (
+            10.0,
+            0.99,
+            -0.9914207448606821,
+            -0.1307092447376634,
+            0.16405504440062638,
+        ),
+    ];
+    for &(u, m, sn, cn, dn) in &cases {
+        assert_relative_eq!(jacobi_sn(u, m), sn, max_relative = 1e-14);
+        assert_relative_eq!(jacobi_cn(u, m), cn, max_relative = 1e-14);
+        assert_relative_eq!(jacobi_dn(u, m), dn, max_relative = 1e-14);
+    }
+
+    // Degenerate parameters reduce to circular and hyperbolic functions
+    assert_eq!(jacobi_elliptic(0.8, 0.0), (0.8f64.sin(), 0.8f64.cos(), 1.0));
+    assert_relative_eq!(jacobi_sn(0.8, 1.0), 0.8f64.tanh(), max_relative = 1e-15);
+    assert_relative_eq!(
+        jacobi_cn(0.8, 1.0),
+        1.0 / 0.8f64.cosh(),
+        max_relative = 1e-15
+    );
+
+    // The amplitude inverts the incomplete integral of the first kind
+    for &(phi, m) in &[(0.5, 0.3), (1.4, 0.95), (5.0, -2.0), (0.3, 4.0)] {
+        assert_relative_eq!(
+            jacobi_amplitude(elliptic_f(phi, m), m),
+            phi,
+            max_relative = 1e-14
+        );
+    }
+
+    // Period of a pendulum released from 2 rad with g = 9.81
+    assert_relative_eq!(
+        4.0 * (1.0f64 / 9.81).sqrt() * elliptic_k(1.0f64.sin().powi(2)),
+        2.6658709428349256,
+        max_relative = 1e-15
+    );
+    assert!(jacobi_sn(f64::NAN, 0.5).is_nan());
+    assert!(jacobi_sn(f64::INFINITY, 0.5).is_nan());
+}
@@ -0,0 +1,199 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn test_expint_ei() {
+    assert_relative_eq!(expint_ei(1.0), 1.8951178163559368, max_relative = 1e-15);
+    assert_relative_eq!(expint_ei(0.5), 0.4542199048631736, max_relative = 1e-15);
+    assert_relative_eq!(expint_ei(-2.0), -0.04890051070806112, max_relative = 1e-15);
+    assert_relative_eq!(expint_ei(10.0), 2492.2289762418777, max_relative = 1e-15);
+    assert_relative_eq!(expint_ei(50.0), 1.058563689713169e20, max_relative = 1e-15);
+    assert_relative_eq!(
+        expint_ei(-30.0),
+        -3.0215520106888124e-15,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(expint_ei(1e-10), -22.448635264938925, max_relative = 1e-15);
+    assert_relative_eq!(
+        expint_ei(700.0),
+        1.4509787360525608e301,
+        max_relative = 1e-14
+    );
+
+    // Accurate right next to the zero of Ei
+    assert_relative_eq!(
+        expint_ei(0.3725),
+        -2.8874183188745963e-5,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_expint_en() {
+    assert_relative_eq!(expint_e1(0.5), 0.5597735947761608, max_relative = 1e-15);
+    assert_relative_eq!(expint_e1(1.0), 0.21938393439552029, max_relative = 1e-15);
+    assert_relative_eq!(expint_e1(3.0), 0.013048381094197037, max_relative = 1e-15);
+    assert_relative_eq!(
+        expint_e1(100.0),
+        3.683597761682032e-46,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(expint_e1(1e-8), 17.84346508905083, max_relative = 1e-15);
+
+    assert_relative_eq!(expint_en(0, 2.0), 0.06766764161830635, max_relative = 1e-15);
+    assert_relative_eq!(expint_en(2, 0.5), 0.326643862324553, max_relative = 1e-15);
+    assert_relative_eq!(
+        expint_en(3, 4.0),
+        0.002761360945689981,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        expint_en(10, 0.1),
+        0.09929843200089682,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        expint_en(5, 30.0),
+        2.6840578116206304e-15,
+        max_relative = 1e-15
+    );
+
+    // n E_n+1(x) = e^-x - x E_n(x)
+    for &x in &[0.3, 1.0, 2.5, 12.0] {
+        for n in 1..6 {
+            assert_relative_eq!(
+                n as f64 * expint_en(n + 1, x),
+                (-x).exp() - x * expint_en(n, x),
+                max_relative = 1e-13
+            );
+        }
+    }
+}
+
+#[test]
+fn test_expint_special_cases() {
+    assert_eq!(expint_ei(0.0), f64::NEG_INFINITY);
+    assert_eq!(expint_ei(f64::INFINITY), f64::INFINITY);
+    assert_eq!(expint_ei(f64::NEG_INFINITY), 0.0);
+    assert_eq!(expint_ei(720.0), f64::INFINITY);
+    assert_eq!(expint_e1(0.0), f64::INFINITY);
+    assert_eq!(expint_e1(f64::INFINITY), 0.0);
+    assert_eq!(expint_en(3, 0.0), 0.5);
+    assert_eq!(expint_en(1, 800.0), 0.0);
+
+    assert!(expint_ei(f64::NAN).is_nan());
+    assert!(expint_e1(-1.0_f64).is_nan());
+    assert!(expint_en(2, -0.5_f64).is_nan());
+
+    // E_0(x) = e^-x / x stays real for negative x
+    assert_relative_eq!(
+        expint_en(0, -2.0),
+        -std::f64::consts::E.powi(2) / 2.0,
+        max_relative = 1e-15
+    );
+    assert_eq!(expint_en(0, 0.0), f64::INFINITY);
+    assert_eq!(expint_en(0, f64::INFINITY), 0.0);
+    assert_eq!(expint_en(0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_logarithmic_integral() {
+    assert_relative_eq!(li(2.0), 1.045163780117493, max_relative = 1e-15);
+    assert_relative_eq!(li(10.0), 6.165599504787298, max_relative = 1e-15);
+    assert_relative_eq!(li(0.5), -0.37867104306108795, max_relative = 1e-15);
+    assert_relative_eq!(li(1e20), 2.2208196027836634e18, max_relative = 1e-15);
+    assert_relative_eq!(li(1.0000001), -15.54087993547292, max_relative = 1e-14);
+
+    // Close to π(10⁶) = 78498
+    assert_relative_eq!(li(1e6), 78627.54915946219, max_relative = 1e-15);
+
+    // Accurate right next to the Ramanujan-Soldner constant
+    assert_relative_eq!(li(1.45), -0.003680389115816111, max_relative = 1e-14);
+
+    assert_eq!(li(0.0), 0.0);
+    assert_eq!(li(1.0), f64::NEG_INFINITY);
+    assert_eq!(li(f64::INFINITY), f64::INFINITY);
+    assert!(li(-1.0_f64).is_nan());
+    assert!(li(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_sine_cosine_integrals() {
+    assert_relative_eq!(si(1.0), 0.946083070367183, max_relative = 1e-15);
+    assert_relative_eq!(si(0.01), 0.00999994444461111, max_relative = 1e-15);
+    assert_relative_eq!(si(5.0), 1.549931244944674, max_relative = 1e-15);
+    assert_relative_eq!(si(20.0), 1.54824170104344, max_relative = 1e-15);
+    assert_relative_eq!(si(1e6), 1.570795390043119, max_relative = 1e-15);
+    assert_eq!(si(-5.0), -si(5.0));
+
+    assert_relative_eq!(ci(1.0), 0.33740392290096816, max_relative = 1e-15);
+    assert_relative_eq!(ci(0.01), -4.027979520982392, max_relative = 1e-15);
+    assert_relative_eq!(ci(5.0), -0.19002974965664388, max_relative = 1e-15);
+    assert_relative_eq!(ci(20.0), 0.044419820845353314, max_relative = 1e-14);
+    assert_relative_eq!(ci(1e6), -3.499944389227205e-7, max_relative = 1e-12);
+
+    // Accurate right next to the first two zeros of Ci
+    assert_relative_eq!(ci(0.6165), -7.259899799942e-6, max_relative = 1e-13);
+    assert_relative_eq!(ci(3.3842), -5.615573041917743e-6, max_relative = 1e-12);
+
+    assert_eq!(si(0.0), 0.0);
+    assert_eq!(si(f64::INFINITY), FRAC_PI_2);
+    assert_eq!(si(f64::NEG_INFINITY), -FRAC_PI_2);
+    assert_eq!(ci(0.0), f64::NEG_INFINITY);
+    assert_eq!(ci(f64::INFINITY), 0.0);
+    assert!(ci(-1.0_f64).is_nan());
+    assert!(si(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_hyperbolic_sine_cosine_integrals() {
+    assert_relative_eq!(shi(1.0), 1.0572508753757286, max_relative = 1e-15);
+    assert_relative_eq!(shi(0.01), 0.010000055555722222, max_relative = 1e-15);
+    assert_relative_eq!(shi(5.0), 20.093211825697228, max_relative = 1e-15);
+    assert_relative_eq!(shi(50.0), 5.292818448565845e19, max_relative = 1e-15);
+    assert_relative_eq!(shi(700.0), 7.254893680262804e300, max_relative = 1e-14);
+    assert_eq!(shi(-5.0), -shi(5.0));
+
+    assert_relative_eq!(chi(1.0), 0.8378669409802082, max_relative = 1e-15);
+    assert_relative_eq!(chi(0.01), -4.027929520982392, max_relative = 1e-15);
+    assert_relative_eq!(chi(5.0), 20.09206353010595, max_relative = 1e-15);
+    assert_relative_eq!(chi(50.0), 5.292818448565845e19, max_relative = 1e-15);
+    assert_relative_eq!(chi(0.5238), -4.91386742350275e-5, max_relative = 1e-14);
+
+    // Shi + Chi = Ei and Chi - Shi = -E1
+    for &x in &[0.2, 1.5, 8.0, 45.0] {
+        assert_relative_eq!(shi(x) + chi(x), expint_ei(x), max_relative = 1e-14);
+    }
+    for &x in &[0.05, 0.2, 1.5] {
+        assert_relative_eq!(chi(x) - shi(x), -expint_e1(x), max_relative = 1e-13);
+    }
+
+    assert_eq!(shi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(chi(0.0), f64::NEG_INFINITY);
+    assert_eq!(chi(f64::INFINITY), f64::INFINITY);
+    assert!(chi(-1.0_f64).is_nan());
+}
+
+#[test]
+fn test_fresnel_integrals() {
+    let cases = [
+        (0.5, 0.06473243285999927, 0.4923442258714464),
+        (1.0, 0.43825914739035476, 0.7798934003768229),
+        (2.5, 0.6191817558195929, 0.45741300964177706),
+        (10.0, 0.46816997858488224, 0.49989869420551575),
+        (1e4, 0.49996816901138164, 0.4999999999998987),
+        (0.001, 5.235987755982066e-10, 0.0009999999999997533),
+    ];
+    for &(x, s, c) in &cases {
+        assert_relative_eq!(fresnel_s(x), s, max_relative = 1e-15);
+        assert_relative_eq!(fresnel_c(x), c, max_relative = 1e-15);
+        assert_eq!(fresnel(-x), (-fresnel_s(x), -fresnel_c(x)));
+    }
+
+    assert_eq!(fresnel(0.0), (0.0, 0.0));
+    assert_eq!(fresnel(f64::INFINITY), (0.5, 0.5));
+    assert_eq!(fresnel(f64::NEG_INFINITY), (-0.5, -0.5));
+    assert!(fresnel_s(f64::NAN).is_nan());
+    assert!(fresnel_c(f64::NAN).is_nan());
+}
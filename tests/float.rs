@@ -0,0 +1,116 @@
+use approx::assert_relative_eq;
+use rmath::*;
+
+#[test]
+fn test_float_constants() {
+    assert_eq!(<f32 as Float>::PI, std::f32::consts::PI);
+    assert_eq!(<f64 as Float>::PI, std::f64::consts::PI);
+    assert_eq!(<f32 as Float>::EPSILON, f32::EPSILON);
+    assert_relative_eq!(<f32 as Float>::MAX_LN, f32::MAX.ln(), max_relative = 1e-6);
+    assert_relative_eq!(<f64 as Float>::MAX_LN, f64::MAX.ln(), max_relative = 1e-15);
+
+    // Tolerances keep the same number of ulps in each precision
+    assert_eq!(f64::tolerance(1e-15), 1e-15);
+    assert_relative_eq!(
+        f32::tolerance(f64::EPSILON),
+        f32::EPSILON,
+        max_relative = 1e-7
+    );
+    assert_eq!(f32::by_precision(1.0, 2.0), 1.0);
+    assert_eq!(f64::by_precision(1.0, 2.0), 2.0);
+}
+
+#[test]
+fn test_elementary_f32() {
+    assert_relative_eq!(arcsin(0.3_f32), 0.30469266, max_relative = 1e-6);
+    assert_relative_eq!(tanh(0.8_f32), 0.66403675, max_relative = 1e-6);
+    assert_relative_eq!(power(2.5_f32, 1.7), 4.7478614, max_relative = 1e-6);
+    assert_relative_eq!(log10(7.0_f32), 0.845098, max_relative = 1e-6);
+    assert_relative_eq!(exp10(1.3_f32), 19.952623, max_relative = 1e-6);
+    assert_eq!(round(2.5_f32), 3.0);
+    assert_eq!(smoothstep(0.5_f32), 0.5);
+    assert_eq!(square_wave(1.5_f32), -1.0);
+    assert!(arcsin(1.1_f32).is_nan());
+}
+
+#[test]
+fn test_gamma_f32() {
+    assert_relative_eq!(gamma(4.5_f32), 11.631728, max_relative = 1e-6);
+    assert_relative_eq!(log_gamma(100.0_f32), 359.13422, max_relative = 1e-6);
+    assert_relative_eq!(digamma(0.3_f32), -3.5025241, max_relative = 1e-6);
+    assert_relative_eq!(beta(2.5_f32, 3.5), 0.03681554, max_relative = 1e-6);
+
+    // Overflow happens at the f32 limit rather than the f64 one
+    assert!(gamma(35.0_f32).is_finite());
+    assert_eq!(gamma(36.0_f32), f32::INFINITY);
+    assert!(log_gamma(1e30_f32).is_finite());
+}
+
+#[test]
+fn test_erf_zeta_f32() {
+    assert_relative_eq!(erf(0.7_f32), 0.6778012, max_relative = 1e-6);
+    assert_relative_eq!(erfc(5.0_f32), 1.5374598e-12, max_relative = 1e-6);
+    assert_relative_eq!(erf_inv(0.9_f32), 1.1630871, max_relative = 1e-6);
+    assert_eq!(erfc(11.0_f32), 0.0);
+
+    assert_relative_eq!(zeta(3.0_f32), 1.2020569, max_relative = 1e-6);
+    assert_relative_eq!(zeta(-2.5_f32), 0.008516929, max_relative = 1e-6);
+    assert_relative_eq!(hurwitz_zeta(2.5_f32, 0.3), 21.069239, max_relative = 1e-6);
+    assert_relative_eq!(polylog(2.0_f32, 0.5), 0.5822405, max_relative = 1e-6);
+}
+
+#[test]
+fn test_incomplete_and_integrals_f32() {
+    assert_relative_eq!(gamma_p(2.5_f32, 1.7), 0.36143008, max_relative = 1e-6);
+    assert_relative_eq!(beta_inc(2.0_f32, 3.0, 0.4), 0.5248, max_relative = 1e-6);
+    assert_relative_eq!(
+        gamma_p(2.5_f32, gamma_p_inv(2.5_f32, 0.3)),
+        0.3,
+        max_relative = 1e-6
+    );
+
+    assert_relative_eq!(expint_ei(5.0_f32), 40.185276, max_relative = 1e-6);
+    assert_relative_eq!(si(3.0_f32), 1.8486525, max_relative = 1e-6);
+    assert!(expint_ei(15.0_f32).is_finite());
+}
+
+#[test]
+fn test_bessel_airy_f32() {
+    assert_relative_eq!(bessel_j(0.0_f32, 2.5), -0.048383776, max_relative = 1e-5);
+    assert_relative_eq!(bessel_y(1.0_f32, 3.5), 0.4101884, max_relative = 1e-6);
+    assert_relative_eq!(bessel_k(0.5_f32, 1.2), 0.34460053, max_relative = 1e-6);
+
+    assert_relative_eq!(airy_ai(1.5_f32), 0.07174949, max_relative = 1e-6);
+    assert_relative_eq!(airy_bi(-2.0_f32), -0.41230258, max_relative = 1e-6);
+    assert_relative_eq!(airy_ai_zero::<f32>(3), -5.52056, max_relative = 1e-6);
+}
+
+#[test]
+fn test_elliptic_hypergeometric_f32() {
+    assert_relative_eq!(elliptic_k(0.7_f32), 2.0753632, max_relative = 1e-6);
+    assert_relative_eq!(elliptic_e(0.3_f32), 1.445363, max_relative = 1e-6);
+    assert_relative_eq!(jacobi_sn(1.2_f32, 0.6), 0.8776394, max_relative = 1e-6);
+
+    assert_relative_eq!(
+        hypergeometric_1f1(0.5_f32, 1.5, -4.0),
+        0.4410407,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        hypergeometric_2f1(0.3_f32, 1.3, 2.2, -3.0),
+        0.75212264,
+        max_relative = 1e-6
+    );
+}
+
+#[test]
+fn test_lambert_w_f32() {
+    assert_relative_eq!(product_log(3.0_f32), 1.0499089, max_relative = 1e-6);
+    assert_relative_eq!(wright_omega(2.0_f32), 1.5571456, max_relative = 1e-6);
+
+    // The defining relation holds to single precision
+    for &x in &[0.1_f32, 1.0, 10.0, 1e20] {
+        let w = product_log(x);
+        assert_relative_eq!(w * w.exp(), x, max_relative = 1e-6);
+    }
+}
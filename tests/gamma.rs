@@ -0,0 +1,186 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::PI;
+
+#[test]
+fn test_gamma_basic_values() {
+    assert_eq!(gamma(1.0), 1.0);
+    assert_eq!(gamma(2.0), 1.0);
+    assert_eq!(gamma(5.0), 24.0);
+    assert_eq!(gamma(21.0), 2432902008176640000.0);
+
+    assert_relative_eq!(gamma(0.5), PI.sqrt(), max_relative = 1e-15);
+    assert_relative_eq!(gamma(1.5), 0.886226925452758, max_relative = 1e-15);
+    assert_relative_eq!(gamma(4.5), 11.631728396567448, max_relative = 1e-15);
+    assert_relative_eq!(gamma(1e-10), 9999999999.422785, max_relative = 1e-15);
+    assert_relative_eq!(gamma(0.001), 999.4237724845955, max_relative = 1e-15);
+    assert_relative_eq!(gamma(170.5), 5.56209241456e+305, max_relative = 1e-14);
+}
+
+#[test]
+fn test_gamma_reflection() {
+    assert_relative_eq!(gamma(-0.5), -3.544907701811032, max_relative = 1e-15);
+    assert_relative_eq!(gamma(-1.5), 2.363271801207355, max_relative = 1e-15);
+    assert_relative_eq!(gamma(-2.5), -0.9453087204829419, max_relative = 1e-15);
+
+    for &x in &[0.1, 0.3, 0.7, 2.2, 5.5] {
+        assert_relative_eq!(
+            gamma(x) * gamma(1.0 - x),
+            PI / (PI * x).sin(),
+            max_relative = 1e-14
+        );
+    }
+
+    let tiny: f64 = gamma(-175.5);
+    assert!(tiny != 0.0 && tiny.abs() < 1e-300);
+}
+
+#[test]
+fn test_gamma_special_cases() {
+    assert!(gamma(f64::NAN).is_nan());
+    assert_eq!(gamma(f64::INFINITY), f64::INFINITY);
+    assert!(gamma(f64::NEG_INFINITY).is_nan());
+    assert_eq!(gamma(0.0), f64::INFINITY);
+    assert_eq!(gamma(-0.0), f64::NEG_INFINITY);
+    assert!(gamma(-1.0_f64).is_nan());
+    assert!(gamma(-100.0_f64).is_nan());
+    assert_eq!(gamma(172.0), f64::INFINITY);
+}
+
+#[test]
+fn test_factorial() {
+    assert_eq!(factorial(0.0), 1.0);
+    assert_eq!(factorial(1.0), 1.0);
+    assert_eq!(factorial(10.0), 3628800.0);
+    assert_relative_eq!(factorial(0.5), PI.sqrt() / 2.0, max_relative = 1e-15);
+    assert_relative_eq!(
+        factorial(170.0),
+        7.257415615307994e306,
+        max_relative = 1e-14
+    );
+    assert_eq!(factorial(171.0), f64::INFINITY);
+}
+
+#[test]
+fn test_log_gamma() {
+    assert_eq!(log_gamma(1.0), 0.0);
+    assert_eq!(log_gamma(2.0), 0.0);
+    assert_relative_eq!(log_gamma(0.5), 0.5723649429247001, max_relative = 1e-15);
+    assert_relative_eq!(log_gamma(3.7), 1.428072326665388, max_relative = 1e-15);
+    assert_relative_eq!(log_gamma(100.0), 359.1342053695754, max_relative = 1e-15);
+    assert_relative_eq!(log_gamma(1e10), 220258509288.81058, max_relative = 1e-15);
+    assert_relative_eq!(
+        log_gamma(1.0000001),
+        -5.772155829918507e-08,
+        max_relative = 1e-14
+    );
+
+    let (value, sign) = log_gamma_sign(-2.5);
+    assert_relative_eq!(value, -0.056243716497674054, max_relative = 1e-14);
+    assert_eq!(sign, -1.0);
+
+    let (value, sign) = log_gamma_sign(-0.5);
+    assert_relative_eq!(value, 1.2655121234846454, max_relative = 1e-15);
+    assert_eq!(sign, -1.0);
+    assert_eq!(log_gamma_sign(-1.5).1, 1.0);
+
+    assert!(log_gamma(f64::NAN).is_nan());
+    assert_eq!(log_gamma(0.0), f64::INFINITY);
+    assert_eq!(log_gamma(-3.0), f64::INFINITY);
+    assert_eq!(log_gamma(f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_digamma() {
+    const EULER_GAMMA: f64 = 0.5772156649015329;
+
+    assert_relative_eq!(digamma(1.0), -EULER_GAMMA, max_relative = 1e-15);
+    assert_relative_eq!(digamma(0.5), -1.9635100260214235, max_relative = 1e-15);
+    assert_relative_eq!(digamma(2.5), 0.7031566406452432, max_relative = 1e-15);
+    assert_relative_eq!(digamma(-0.5), 0.03648997397857652, max_relative = 1e-14);
+    assert_relative_eq!(digamma(100.0), 4.600161852738087, max_relative = 1e-15);
+    assert_relative_eq!(digamma(1e-8), -100000000.57721564, max_relative = 1e-15);
+
+    // Relative accuracy is kept right next to the positive root
+    assert_relative_eq!(
+        digamma(1.4616321449683622),
+        -9.241265521729427e-17,
+        max_relative = 1e-12
+    );
+
+    for &x in &[0.3, 1.7, 4.2, 12.5] {
+        assert_relative_eq!(digamma(x + 1.0), digamma(x) + 1.0 / x, max_relative = 1e-14);
+    }
+
+    assert!(digamma(0.0_f64).is_nan());
+    assert!(digamma(-2.0_f64).is_nan());
+    assert!(digamma(f64::NAN).is_nan());
+    assert_eq!(digamma(f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_polygamma() {
+    assert_relative_eq!(trigamma(1.0), PI * PI / 6.0, max_relative = 1e-15);
+    assert_relative_eq!(trigamma(0.5), PI * PI / 2.0, max_relative = 1e-15);
+    assert_relative_eq!(trigamma(-0.5), 8.934802200544679, max_relative = 1e-14);
+    assert_relative_eq!(trigamma(50.0), 0.020201333226697125, max_relative = 1e-15);
+
+    assert_relative_eq!(polygamma(2, 1.0), -2.4041138063191885, max_relative = 1e-15);
+    assert_relative_eq!(polygamma(3, 2.5), 0.22390584881725206, max_relative = 1e-14);
+    assert_relative_eq!(polygamma(5, 0.1), 120000069.30751093, max_relative = 1e-14);
+    assert_eq!(polygamma(0, 2.5), digamma(2.5));
+
+    assert!(polygamma(1, 0.0_f64).is_nan());
+    assert!(polygamma(2, -3.0_f64).is_nan());
+    assert_eq!(polygamma(1, f64::INFINITY), 0.0);
+}
+
+#[test]
+fn test_beta() {
+    assert_relative_eq!(beta(1.0, 1.0), 1.0, max_relative = 1e-15);
+    assert_relative_eq!(beta(2.5, 3.5), 0.03681553890925539, max_relative = 1e-15);
+    assert_relative_eq!(beta(0.1, 0.2), 14.59937149276483, max_relative = 1e-15);
+    assert_relative_eq!(beta(-0.5, 2.5), -4.71238898038469, max_relative = 1e-14);
+    assert_relative_eq!(
+        beta(200.0, 300.0),
+        1.6485491608664747e-147,
+        max_relative = 1e-12
+    );
+    assert_eq!(beta(2.0, 3.0), beta(3.0, 2.0));
+
+    assert_relative_eq!(
+        log_beta(200.0, 300.0),
+        -337.98011306546465,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        log_beta(1e5, 3.5),
+        -39.094309274611234,
+        max_relative = 1e-15
+    );
+
+    assert!(beta(f64::NAN, 1.0).is_nan());
+    assert!(beta(-1.0, 2.0_f64).is_nan());
+}
+
+#[test]
+fn test_pochhammer() {
+    assert_eq!(pochhammer(2.5, 0.0), 1.0);
+    assert_eq!(pochhammer(2.5, 3.0), 39.375);
+    assert_eq!(pochhammer(3.0, -2.0), 0.5);
+    assert_eq!(pochhammer(-3.0, 5.0), 0.0);
+    assert_relative_eq!(
+        pochhammer(0.5, 0.5),
+        0.5641895835477563,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        pochhammer(50.0, 30.5),
+        1.3134068595602667e+55,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(pochhammer(1.0, 10.0), gamma(11.0), max_relative = 1e-15);
+
+    assert!(pochhammer(f64::NAN, 1.0).is_nan());
+    assert_eq!(pochhammer(-2.5, 1.5), f64::INFINITY);
+}
@@ -43,7 +43,7 @@ fn test_arcosh() {
     assert_relative_eq!(arcosh(1.0), 0.0, epsilon = 1e-10);
     assert_relative_eq!(arcosh(E), 1.6574544541530771, epsilon = 1e-10);
 
-    assert!(arcosh(0.5).is_nan());
+    assert!(arcosh(0.5_f64).is_nan());
 }
 
 #[test]
@@ -51,9 +51,9 @@ fn test_artanh() {
     assert_relative_eq!(artanh(0.0), 0.0, epsilon = 1e-10);
     assert_relative_eq!(artanh(0.5), 0.5493061443340549, epsilon = 1e-10);
 
-    assert!(artanh(1.0).is_nan());
-    assert!(artanh(-1.0).is_nan());
-    assert!(artanh(1.5).is_nan());
+    assert!(artanh(1.0_f64).is_nan());
+    assert!(artanh(-1.0_f64).is_nan());
+    assert!(artanh(1.5_f64).is_nan());
 
     assert_relative_eq!(tanh(100.0), 1.0, epsilon = 1e-10);
     assert_relative_eq!(tanh(-100.0), -1.0, epsilon = 1e-10);
@@ -62,19 +62,19 @@ fn test_artanh() {
 #[test]
 fn test_inverse_reciprocal_hyperbolic() {
     assert_relative_eq!(arcoth(2.0), 0.5 * (3.0_f64.ln()), epsilon = 1e-10);
-    assert!(arcoth(0.5).is_nan());
+    assert!(arcoth(0.5_f64).is_nan());
 
     assert_relative_eq!(arccsch(1.0), (1.0 + 2.0_f64.sqrt()).ln(), epsilon = 1e-10);
-    assert!(arccsch(0.0).is_infinite());
+    assert!(arccsch(0.0_f64).is_infinite());
 
     assert_relative_eq!(arcsech(1.0), 0.0, epsilon = 1e-10);
-    assert!(arcsech(0.0).is_nan());
-    assert!(arcsech(1.5).is_nan());
+    assert!(arcsech(0.0_f64).is_nan());
+    assert!(arcsech(1.5_f64).is_nan());
 }
 
 #[test]
 fn test_hyperbolic_identities() {
-    let values = [-2.0, -1.0, 0.0, 1.0, 2.0];
+    let values = [-2.0_f64, -1.0, 0.0, 1.0, 2.0];
 
     for x in values.iter() {
         let sh = sinh(*x);
@@ -0,0 +1,265 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, LN_2};
+
+#[test]
+fn test_hypergeometric_0f1() {
+    assert_relative_eq!(
+        hypergeometric_0f1(1.5, 2.0),
+        2.980406103535168,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_0f1(0.5, -10.0),
+        0.9991443830469295,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_0f1(3.0, 100.0),
+        786255.7044208151,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_0f1(2.5, -1000.0),
+        -0.0006819625965429243,
+        max_relative = 1e-13
+    );
+
+    // 0F1(; 1/2; -x²/4) = cos x
+    for &x in &[0.5, 2.0, 7.0] {
+        assert_relative_eq!(
+            hypergeometric_0f1(0.5, -0.25 * x * x),
+            f64::cos(x),
+            max_relative = 1e-13
+        );
+    }
+
+    assert_eq!(hypergeometric_0f1(2.0, 0.0), 1.0);
+    assert!(hypergeometric_0f1(-2.0, 1.0_f64).is_nan());
+}
+
+#[test]
+fn test_hypergeometric_1f1() {
+    assert_relative_eq!(
+        hypergeometric_1f1(0.5, 1.5, 2.0),
+        2.3644538928052095,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_1f1(2.0, 3.5, -4.0),
+        0.1573392024514622,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_1f1(1.5, 2.5, -60.0),
+        0.002860285102699278,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_1f1(0.25, 1.75, 200.0),
+        6.51296038933542e82,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_1f1(-2.5, 1.5, 3.0),
+        -0.2073005487189103,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_hypergeometric_1f1_special_cases() {
+    assert_eq!(hypergeometric_1f1(0.0, 2.5, 3.0), 1.0);
+    assert_relative_eq!(
+        hypergeometric_1f1(1.5, 1.5, 2.0),
+        f64::exp(2.0),
+        max_relative = 1e-15
+    );
+
+    // M(1, 2, z) = (e^z - 1)/z
+    assert_relative_eq!(
+        hypergeometric_1f1(1.0, 2.0, 0.5),
+        f64::exp_m1(0.5) / 0.5,
+        max_relative = 1e-15
+    );
+
+    // M(-n, 1, z) is the Laguerre polynomial L_n(z)
+    assert_relative_eq!(
+        hypergeometric_1f1(-3.0, 1.0, 2.0),
+        -1.0 / 3.0,
+        max_relative = 1e-15
+    );
+
+    assert!(hypergeometric_1f1(1.0, -2.0, 1.0_f64).is_nan());
+    assert_eq!(hypergeometric_1f1(1.0, 2.0, f64::NEG_INFINITY), 0.0);
+    assert_eq!(hypergeometric_1f1(1.0, 2.0, f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_hypergeometric_u() {
+    assert_relative_eq!(
+        hypergeometric_u(2.0, 3.5, 0.1),
+        433.8955554429374,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_u(1.5, -0.5, 30.0),
+        0.005300820209603519,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_u(0.3, 1.2, 0.001),
+        5.49805702616632,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_u(-1.5, 0.5, 2.0),
+        FRAC_1_SQRT_2,
+        max_relative = 1e-14
+    );
+
+    // U(a, a + 1, z) = z^(-a)
+    assert_relative_eq!(
+        hypergeometric_u(0.5, 1.5, 2.0),
+        FRAC_1_SQRT_2,
+        max_relative = 1e-14
+    );
+
+    // U(-n, b, z) is a polynomial
+    assert_relative_eq!(
+        hypergeometric_u(-2.0, 1.5, 2.0),
+        -2.25,
+        max_relative = 1e-15
+    );
+
+    // U(a, b, 0) = Γ(1 - b)/Γ(a - b + 1) for b < 1
+    assert_relative_eq!(
+        hypergeometric_u(0.5, 0.5, 0.0),
+        gamma(0.5),
+        max_relative = 1e-15
+    );
+
+    assert!(hypergeometric_u(1.0, 2.0, -1.0_f64).is_nan());
+}
+
+#[test]
+fn test_hypergeometric_2f1() {
+    assert_relative_eq!(
+        hypergeometric_2f1(0.5, 1.5, 2.5, 0.3),
+        1.108062551056932,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_2f1(1.5, -0.5, 3.25, -0.4),
+        1.0874939475011163,
+        max_relative = 1e-15
+    );
+
+    // ln(1 + z) = z 2F1(1, 1; 2; -z)
+    assert_relative_eq!(
+        hypergeometric_2f1(1.0, 1.0, 2.0, -0.25),
+        f64::ln(1.25) / 0.25,
+        max_relative = 1e-15
+    );
+
+    // Terminating series are polynomials for every z
+    assert_relative_eq!(
+        hypergeometric_2f1(-2.0, 1.0, 1.0, 3.0),
+        4.0,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_hypergeometric_2f1_continuation() {
+    assert_relative_eq!(
+        hypergeometric_2f1(0.5, 1.5, 2.5, 0.95),
+        1.8262489839789269,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_2f1(1.25, 0.75, 2.5, -5.0),
+        0.44148170046233015,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_2f1(2.5, 1.5, 3.0, -1e4),
+        1.6963392939116506e-6,
+        max_relative = 1e-14
+    );
+
+    // Integer c - a - b goes through the logarithmic series
+    assert_relative_eq!(
+        hypergeometric_2f1(1.0, 1.0, 2.0, 0.9),
+        -f64::ln(0.1) / 0.9,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hypergeometric_2f1(1.0, 1.0, 2.0, -20.0),
+        f64::ln(21.0) / 20.0,
+        max_relative = 1e-14
+    );
+
+    // K(m) = π/2 2F1(1/2, 1/2; 1; m)
+    for &m in &[0.7, 0.99, -3.0] {
+        assert_relative_eq!(
+            FRAC_PI_2 * hypergeometric_2f1(0.5, 0.5, 1.0, m),
+            elliptic_k(m),
+            max_relative = 1e-14
+        );
+    }
+}
+
+#[test]
+fn test_hypergeometric_2f1_special_cases() {
+    // Gauss's summation theorem at z = 1
+    assert_relative_eq!(
+        hypergeometric_2f1(0.3, 0.6, 2.5, 1.0),
+        1.1208954938382738,
+        max_relative = 1e-14
+    );
+    assert_eq!(hypergeometric_2f1(1.0, 2.0, 3.0, 1.0), f64::INFINITY);
+
+    assert_eq!(hypergeometric_2f1(0.0, 2.0, 3.0, 0.5), 1.0);
+    assert_eq!(hypergeometric_2f1(1.0, 2.0, 3.0, 0.0), 1.0);
+
+    // The real axis beyond 1 is the branch cut
+    assert!(hypergeometric_2f1(1.0, 2.0, 3.0, 2.0_f64).is_nan());
+    assert!(hypergeometric_2f1(1.0, 2.0, -3.0, 0.5_f64).is_nan());
+
+    // A terminating series never reaches the pole in c
+    assert_relative_eq!(
+        hypergeometric_2f1(-1.0, 2.0, -3.0, 0.5),
+        4.0 / 3.0,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_hypergeometric_pfq() {
+    assert_relative_eq!(
+        hypergeometric_pfq(&[1.0, 2.0, 3.0], &[4.0, 5.0], 0.5),
+        1.189874754256423,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_pfq(&[0.5], &[1.5, 2.5], -3.0),
+        0.6881385014517409,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hypergeometric_pfq(&[], &[], 1.0),
+        std::f64::consts::E,
+        max_relative = 1e-15
+    );
+
+    // log(2) = 2F1(1, 1; 2; 1/2) / 2
+    assert_relative_eq!(
+        hypergeometric_pfq(&[1.0, 1.0], &[2.0], 0.5) / 2.0,
+        LN_2,
+        max_relative = 1e-15
+    );
+
+    assert!(hypergeometric_pfq(&[1.0, 1.0], &[2.0], 1.5_f64).is_nan());
+    assert!(hypergeometric_pfq(&[1.0, 1.0, 1.0], &[2.0], 0.1_f64).is_nan());
+}
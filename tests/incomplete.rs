@@ -0,0 +1,188 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+
+#[test]
+fn test_gamma_p_values() {
+    assert_relative_eq!(gamma_p(1.0, 1.0), 0.6321205588285577, max_relative = 1e-15);
+    assert_relative_eq!(gamma_p(0.5, 2.0), 0.9544997361036416, max_relative = 1e-15);
+    assert_relative_eq!(gamma_p(5.0, 3.0), 0.18473675547622792, max_relative = 1e-14);
+    assert_relative_eq!(
+        gamma_p(100.0, 90.0),
+        0.15822098918643016,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        gamma_p(0.01, 1e-5),
+        0.8963367982671973,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(gamma_p(2.5, 30.0), 0.9999999999878454, max_relative = 1e-15);
+}
+
+#[test]
+fn test_gamma_q_values() {
+    assert_relative_eq!(gamma_q(1.0, 1.0), 0.36787944117144233, max_relative = 1e-15);
+    assert_relative_eq!(gamma_q(0.5, 2.0), 0.04550026389635842, max_relative = 1e-14);
+    assert_relative_eq!(gamma_q(5.0, 3.0), 0.8152632445237721, max_relative = 1e-15);
+    assert_relative_eq!(
+        gamma_q(0.01, 0.1),
+        0.018135316050987345,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        gamma_q(1000.0, 1200.0),
+        1.2881606086281433e-09,
+        max_relative = 1e-12
+    );
+
+    // The upper tail keeps its relative accuracy where 1 - P would be all rounding
+    assert_relative_eq!(
+        gamma_q(2.5, 30.0),
+        1.215456977718304e-11,
+        max_relative = 1e-13
+    );
+}
+
+#[test]
+fn test_gamma_p_q_complement() {
+    for &a in &[0.1, 0.5, 1.0, 3.7, 20.0, 150.0] {
+        for &x in &[0.01, 0.4, 1.0, 2.5, 10.0, 40.0, 200.0] {
+            assert_relative_eq!(gamma_p(a, x) + gamma_q(a, x), 1.0, epsilon = 1e-14);
+        }
+    }
+}
+
+#[test]
+fn test_gamma_p_special_cases() {
+    assert!(gamma_p(f64::NAN, 1.0).is_nan());
+    assert!(gamma_p(1.0, f64::NAN).is_nan());
+    assert!(gamma_p(0.0, 1.0_f64).is_nan());
+    assert!(gamma_p(-1.0, 1.0_f64).is_nan());
+    assert!(gamma_p(1.0, -1.0_f64).is_nan());
+
+    assert_eq!(gamma_p(2.0, 0.0), 0.0);
+    assert_eq!(gamma_q(2.0, 0.0), 1.0);
+    assert_eq!(gamma_p(2.0, f64::INFINITY), 1.0);
+    assert_eq!(gamma_q(2.0, f64::INFINITY), 0.0);
+}
+
+#[test]
+fn test_gamma_inverses() {
+    assert_relative_eq!(
+        gamma_p_inv(3.0, 0.5),
+        2.6740603137235603,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        gamma_p_inv(0.5, 0.9),
+        1.3527717270477073,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        gamma_q_inv(10.0, 1e-10),
+        44.62785721705907,
+        max_relative = 1e-14
+    );
+
+    for &a in &[0.05, 0.5, 1.0, 4.0, 25.0, 500.0] {
+        for &p in &[1e-8, 0.01, 0.3, 0.5, 0.9, 0.999] {
+            let x = gamma_p_inv(a, p);
+            assert_relative_eq!(gamma_p(a, x), p, max_relative = 1e-11);
+
+            let x = gamma_q_inv(a, p);
+            assert_relative_eq!(gamma_q(a, x), p, max_relative = 1e-11);
+        }
+    }
+
+    assert_eq!(gamma_p_inv(2.0, 0.0), 0.0);
+    assert_eq!(gamma_p_inv(2.0, 1.0), f64::INFINITY);
+    assert_eq!(gamma_q_inv(2.0, 1.0), 0.0);
+    assert!(gamma_p_inv(2.0, 1.5_f64).is_nan());
+    assert!(gamma_p_inv(-2.0, 0.5_f64).is_nan());
+}
+
+#[test]
+fn test_beta_inc_values() {
+    assert_relative_eq!(beta_inc(2.0, 3.0, 0.4), 0.5248, max_relative = 1e-15);
+    assert_relative_eq!(beta_inc(1.0, 1.0, 0.37), 0.37, max_relative = 1e-15);
+    assert_relative_eq!(
+        beta_inc(0.5, 0.5, 0.2),
+        0.2951672353008666,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        beta_inc(10.0, 20.0, 0.3),
+        0.3640040810719442,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        beta_inc(0.1, 5.0, 0.01),
+        0.7690889207843463,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        beta_inc(200.0, 300.0, 0.45),
+        0.9881463456202629,
+        max_relative = 1e-13
+    );
+}
+
+#[test]
+fn test_beta_inc_symmetry() {
+    for &(a, b) in &[(0.5, 2.0), (3.0, 3.0), (12.5, 4.0), (100.0, 80.0)] {
+        for &x in &[0.05, 0.3, 0.5, 0.8, 0.99] {
+            assert_relative_eq!(
+                beta_inc(a, b, x) + beta_inc(b, a, 1.0 - x),
+                1.0,
+                epsilon = 1e-13
+            );
+        }
+    }
+}
+
+#[test]
+fn test_beta_inc_special_cases() {
+    assert!(beta_inc(f64::NAN, 1.0, 0.5).is_nan());
+    assert!(beta_inc(1.0, 1.0, f64::NAN).is_nan());
+    assert!(beta_inc(0.0, 1.0, 0.5_f64).is_nan());
+    assert!(beta_inc(1.0, -1.0, 0.5_f64).is_nan());
+    assert!(beta_inc(1.0, 1.0, 1.5_f64).is_nan());
+
+    assert_eq!(beta_inc(2.0, 3.0, 0.0), 0.0);
+    assert_eq!(beta_inc(2.0, 3.0, 1.0), 1.0);
+}
+
+#[test]
+fn test_beta_inc_inv() {
+    assert_relative_eq!(
+        beta_inc_inv(2.0, 3.0, 0.5),
+        0.38572756813238956,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        beta_inc_inv(0.5, 0.5, 0.1),
+        0.024471741852423214,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        beta_inc_inv(50.0, 20.0, 0.999),
+        0.8593488412171252,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        beta_inc_inv(0.2, 0.3, 0.7),
+        0.7474718280552142,
+        max_relative = 1e-14
+    );
+
+    for &(a, b) in &[(0.3, 0.7), (1.0, 4.0), (5.0, 2.0), (40.0, 60.0)] {
+        for &p in &[1e-6, 0.05, 0.5, 0.95] {
+            let x = beta_inc_inv(a, b, p);
+            assert_relative_eq!(beta_inc(a, b, x), p, max_relative = 1e-11);
+        }
+    }
+
+    assert_eq!(beta_inc_inv(2.0, 3.0, 0.0), 0.0);
+    assert_eq!(beta_inc_inv(2.0, 3.0, 1.0), 1.0);
+    assert!(beta_inc_inv(2.0, 3.0, -0.1_f64).is_nan());
+}
@@ -0,0 +1,220 @@
+use approx::assert_relative_eq;
+use rmath::*;
+
+fn ulps(x: f64, n: usize) -> Interval {
+    let mut lo = x;
+    let mut hi = x;
+    for _ in 0..n {
+        lo = lo.next_down();
+        hi = hi.next_up();
+    }
+    Interval::new(lo, hi)
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let a = Interval::new(1.0, 2.0);
+    let b = Interval::new(-3.0, 4.0);
+
+    // Exact results are not widened
+    assert_eq!(a + b, Interval::new(-2.0, 6.0));
+    assert_eq!(a - b, Interval::new(-3.0, 5.0));
+    assert_eq!(a * b, Interval::new(-6.0, 8.0));
+    assert_eq!(b / a, Interval::new(-3.0, 4.0));
+    assert_eq!(-a, Interval::new(-2.0, -1.0));
+
+    // Inexact ones are rounded outward by one ulp
+    let sum = Interval::point(0.1) + Interval::point(0.2);
+    assert_eq!(sum.hi, sum.lo.next_up());
+    assert!(sum.contains(0.1 + 0.2));
+
+    let third = Interval::point(1.0) / 3.0;
+    assert_eq!(third.hi, third.lo.next_up());
+
+    // The dependency problem: x - x is not zero for a wide x
+    assert_eq!(a - a, Interval::new(-1.0, 1.0));
+    assert_eq!(a.square(), Interval::new(1.0, 4.0));
+    assert_eq!(b.square(), Interval::new(0.0, 16.0));
+    assert_eq!(b * b, Interval::new(-12.0, 16.0));
+}
+
+#[test]
+fn test_interval_division_by_zero() {
+    let a = Interval::new(1.0, 2.0);
+    assert_eq!(a / Interval::new(-1.0, 1.0), Interval::ENTIRE);
+    assert_eq!(
+        a / Interval::new(0.0, 4.0),
+        Interval::new(0.25, f64::INFINITY)
+    );
+    assert_eq!(
+        a / Interval::new(-4.0, 0.0),
+        Interval::new(f64::NEG_INFINITY, -0.25)
+    );
+    assert!((a / Interval::ZERO).is_empty());
+    assert_eq!(
+        Interval::new(0.0, 1.0) / Interval::new(0.0, 1.0),
+        Interval::new(0.0, f64::INFINITY)
+    );
+
+    assert_eq!(Interval::new(2.0, 4.0).recip(), Interval::new(0.25, 0.5));
+    assert_eq!(Interval::new(-1.0, 1.0).recip(), Interval::ENTIRE);
+
+    // Overflow leaves the finite side of the bound
+    let huge = Interval::point(f64::MAX) * 2.0;
+    assert_eq!(huge, Interval::new(f64::MAX, f64::INFINITY));
+}
+
+#[test]
+fn test_interval_sin_cos() {
+    // Extremes inside the interval are reached exactly
+    assert_eq!(Interval::new(1.0, 2.0).sin().hi, 1.0);
+    assert_eq!(Interval::new(3.0, 3.5).cos().lo, -1.0);
+    assert_eq!(Interval::new(-0.5, 0.5).cos().hi, 1.0);
+
+    // Monotone pieces stay tight
+    let s = Interval::new(2.0, 3.0).sin();
+    assert!(s.contains(3.0_f64.sin()) && s.contains(2.0_f64.sin()));
+    assert_relative_eq!(s.lo, 0.1411200080598672, max_relative = 1e-14);
+    assert_relative_eq!(s.hi, 0.9092974268256817, max_relative = 1e-14);
+
+    assert_eq!(Interval::new(0.0, 7.0).sin(), Interval::new(-1.0, 1.0));
+    assert_eq!(
+        Interval::new(0.0, f64::INFINITY).cos(),
+        Interval::new(-1.0, 1.0)
+    );
+
+    // Large arguments still locate the period
+    let far = Interval::new(1e10, 1e10 + 0.5).sin();
+    assert!(far.contains(1e10_f64.sin()));
+    assert!(far.width() < 0.5);
+}
+
+#[test]
+fn test_interval_tan_poles() {
+    assert_eq!(Interval::new(1.0, 2.0).tan(), Interval::ENTIRE);
+    assert_eq!(Interval::new(-5.0, -4.0).tan(), Interval::ENTIRE);
+
+    // The double nearest π/2 is treated as the pole it rounds
+    assert_eq!(
+        Interval::point(std::f64::consts::FRAC_PI_2).tan(),
+        Interval::ENTIRE
+    );
+
+    let t = Interval::new(2.0, 4.0).tan();
+    assert!(t.contains(2.0_f64.tan()) && t.contains(4.0_f64.tan()));
+    assert_relative_eq!(t.lo, -2.185039863261519, max_relative = 1e-14);
+    assert_relative_eq!(t.hi, 1.1578212823495775, max_relative = 1e-14);
+
+    // The same holds for 1/x, which the plotter can detect from the width
+    let x = Interval::new(-0.1, 0.1);
+    assert_eq!(Interval::ONE / x, Interval::ENTIRE);
+}
+
+#[test]
+fn test_interval_powers_and_logarithms() {
+    assert_eq!(Interval::new(4.0, 9.0).sqrt(), Interval::new(2.0, 3.0));
+    assert_eq!(
+        Interval::point(2.0).sqrt(),
+        Interval::new(
+            std::f64::consts::SQRT_2.next_down(),
+            std::f64::consts::SQRT_2
+        )
+    );
+    assert!(Interval::new(-4.0, -1.0).sqrt().is_empty());
+    assert_eq!(Interval::new(-4.0, 9.0).sqrt(), Interval::new(0.0, 3.0));
+
+    assert!(Interval::point(27.0).cbrt().is_subset(ulps(3.0, 4)));
+    assert!(Interval::point(-32.0).nth_root(5).contains(-2.0));
+    assert!(Interval::point(1e300)
+        .nth_root(7)
+        .contains(7.19685673001152e42));
+
+    let even = Interval::new(-2.0, 3.0).powi(4);
+    assert!(even.contains(81.0) && even.lo == 0.0);
+    let odd = Interval::new(-2.0, 3.0).powi(3);
+    assert!(odd.contains(-8.0) && odd.contains(27.0));
+    assert!(Interval::new(2.0, 4.0).powi(-2).contains(0.0625));
+
+    let p = Interval::new(2.0, 3.0).powf(Interval::new(-1.0, 2.0));
+    assert!(p.contains(1.0 / 3.0) && p.contains(9.0));
+
+    let e = Interval::new(0.0, 1.0).exp();
+    assert!(e.contains(1.0) && e.contains(std::f64::consts::E));
+    assert!(Interval::new(-1000.0, 0.0).exp().lo == 0.0);
+    assert!(Interval::point(3.0).exp10().contains(1000.0));
+
+    let l = Interval::new(0.0, std::f64::consts::E).ln();
+    assert_eq!(l.lo, f64::NEG_INFINITY);
+    assert!(l.contains(1.0));
+    assert!(Interval::point(1024.0).log2().contains(10.0));
+    assert!(Interval::point(8.0)
+        .log_base(Interval::point(2.0))
+        .contains(3.0));
+}
+
+#[test]
+fn test_interval_inverse_and_hyperbolic() {
+    assert!(Interval::new(-1.0, 1.0)
+        .asin()
+        .contains(std::f64::consts::FRAC_PI_2));
+    assert!(Interval::new(0.5, 2.0).acos().contains(0.0));
+    assert!(Interval::ENTIRE
+        .atan()
+        .contains(-std::f64::consts::FRAC_PI_2));
+
+    let c = Interval::new(-1.0, 2.0).cosh();
+    assert_eq!(c.lo, 1.0);
+    assert!(c.contains(2.0_f64.cosh()));
+
+    assert!(Interval::new(-40.0, 40.0)
+        .tanh()
+        .is_subset(Interval::new(-1.0, 1.0)));
+    assert!(Interval::point(0.5).sinh().contains(0.5_f64.sinh()));
+
+    // Close to the ends of their domains, where the std formulas lose precision
+    let near_one = Interval::point(1.0 + 1e-10).acosh();
+    assert_relative_eq!(near_one.lo, 1.4142136208675861e-5, max_relative = 1e-14);
+    let near_minus_one = Interval::point(-0.99).atanh();
+    assert!(near_minus_one.contains(-2.6466524123622457));
+    assert_eq!(Interval::new(0.5, 2.0).atanh().hi, f64::INFINITY);
+    assert!(Interval::new(0.0, 0.5).acosh().is_empty());
+}
+
+#[test]
+fn test_interval_enclosure() {
+    let f = |x: f64| (x.sin() * x.exp() + x * x) / (1.0 + x * x);
+    let g = |x: Interval| (x.sin() * x.exp() + x.square()) / (Interval::ONE + x.square());
+
+    for i in 0..40 {
+        let lo = -4.0 + 0.2 * i as f64;
+        let x = Interval::new(lo, lo + 0.3);
+        let range = g(x);
+        for j in 0..=30 {
+            let t = lo + 0.01 * j as f64;
+            assert!(range.contains(f(t)));
+        }
+    }
+}
+
+#[test]
+fn test_interval_set_operations() {
+    let a = Interval::new(1.0, 3.0);
+    let b = Interval::new(2.0, 5.0);
+    assert_eq!(a.intersection(b), Interval::new(2.0, 3.0));
+    assert_eq!(a.hull(b), Interval::new(1.0, 5.0));
+    assert!(a.intersection(Interval::new(4.0, 5.0)).is_empty());
+    assert!(Interval::<f64>::EMPTY.is_subset(a));
+    assert_eq!(Interval::<f64>::EMPTY, Interval::new(2.0, 1.0));
+
+    assert_eq!(a.midpoint(), 2.0);
+    assert_eq!(a.width(), 2.0);
+    assert_eq!(a.radius(), 1.0f64.next_up());
+    assert_eq!(Interval::<f64>::ENTIRE.midpoint(), 0.0);
+    assert_eq!(a.abs(), a);
+    assert_eq!(Interval::new(-4.0, 3.0).abs(), Interval::new(0.0, 4.0));
+    assert_eq!(format!("{}", a), "[1, 3]");
+
+    let single = Interval::new(1.0_f32, 2.0).sin();
+    assert_eq!(single.hi, 1.0);
+    assert!(single.contains(1.0_f32.sin()));
+}
@@ -1,6 +1,7 @@
 use approx::assert_relative_eq;
+use rmath::complex::{cexp, clog, Complex};
 use rmath::lambert_w::*;
-use std::f64::consts::E;
+use std::f64::consts::{E, PI};
 
 #[test]
 fn test_product_log_basic_values() {
@@ -18,19 +19,19 @@ fn test_product_log_basic_values() {
 fn test_product_log_domain() {
     let branch_point = -1.0 / E;
 
-    assert!(product_log(0.0).is_finite());
-    assert!(product_log(1.0).is_finite());
+    assert!(product_log(0.0_f64).is_finite());
+    assert!(product_log(1.0_f64).is_finite());
     assert!(product_log(branch_point).is_finite());
 
     assert!(product_log(branch_point - 1e-10).is_nan());
-    assert!(product_log(-1.0).is_nan());
-    assert!(product_log(-2.0).is_nan());
+    assert!(product_log(-1.0_f64).is_nan());
+    assert!(product_log(-2.0_f64).is_nan());
 }
 
 #[test]
 fn test_product_log_definition() {
     // The defining property: W(x) * exp(W(x)) = x
-    let test_values = [0.0, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0];
+    let test_values = [0.0_f64, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0];
 
     for x in test_values.iter() {
         let w = product_log(*x);
@@ -216,11 +217,10 @@ fn test_product_log_accuracy() {
     }
 }
 
-// TODO
-/*#[test]
+#[test]
 fn test_product_log_k_branches() {
-    assert_relative_eq!(product_log_k(0, 1.0), product_log(1.0), epsilon = 1e-10);
-    assert_relative_eq!(product_log_k(0, E), product_log(E), epsilon = 1e-10);
+    assert_relative_eq!(product_log_k(0, 1.0).re, product_log(1.0), epsilon = 1e-10);
+    assert_relative_eq!(product_log_k(0, E).re, product_log(E), epsilon = 1e-10);
 
     let branch_point = -1.0 / E;
     let test_values = [-0.3, -0.25, -0.2, -0.1, -0.05];
@@ -229,15 +229,210 @@ fn test_product_log_k_branches() {
         if *x > branch_point && *x < 0.0 {
             let w = product_log_k(-1, *x);
             assert!(w.is_finite());
+            assert_eq!(w.im, 0.0);
 
-            let verification = w * w.exp();
+            let verification = w.re * w.re.exp();
             assert_relative_eq!(verification, *x, epsilon = 1e-10);
 
-            assert!(w < -1.0);
+            assert!(w.re < -1.0);
         }
     }
 
-    assert!(product_log_k(1, 1.0).is_nan());
-    assert!(product_log_k(-2, 1.0).is_nan());
-    assert!(product_log_k(-1, 1.0).is_nan());
-}*/
+    assert_relative_eq!(product_log_k(-1, branch_point).re, -1.0, epsilon = 1e-10);
+
+    // A signed zero imaginary part picks the side of the cut
+    let below = product_log_k(-1, Complex::new(-0.36, -0.0));
+    assert_relative_eq!(below.re, -3.11125126566516, max_relative = 1e-13);
+    assert_relative_eq!(below.im, -7.45880012961316, max_relative = 1e-13);
+    let above = product_log_k(1, Complex::new(-0.36, 0.0));
+    assert_relative_eq!(above.re, -3.11125126566516, max_relative = 1e-13);
+    assert_relative_eq!(above.im, 7.45880012961316, max_relative = 1e-13);
+    assert_relative_eq!(
+        product_log_k(-1, Complex::new(-0.36, 0.0)).re,
+        -1.22277013397851,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        product_log_k(1, Complex::new(-0.36, -0.0)).re,
+        -1.22277013397851,
+        max_relative = 1e-13
+    );
+
+    // Tiny arguments, where w e^w and its derivatives underflow
+    for x in [-1e-200, -1e-300, -5e-324] {
+        let w = product_log_k(-1, x).re;
+        assert!(w.is_finite());
+        assert_relative_eq!(w + (-w).ln(), x.abs().ln(), max_relative = 1e-14);
+    }
+    assert_relative_eq!(
+        product_log_k(-1, -1e-200).re,
+        -466.6626251653469,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_product_log_k_complex_values() {
+    struct TestCase {
+        k: i32,
+        z: Complex,
+        expected: Complex,
+    }
+
+    let test_cases = [
+        TestCase {
+            k: 1,
+            z: Complex::from(1.0),
+            expected: Complex::new(-1.5339133197935745, 4.375185153061898),
+        },
+        TestCase {
+            k: -1,
+            z: Complex::from(1.0),
+            expected: Complex::new(-1.5339133197935745, -4.375185153061898),
+        },
+        TestCase {
+            k: 0,
+            z: Complex::from(-1.0),
+            expected: Complex::new(-0.31813150520476413, 1.3372357014306895),
+        },
+        TestCase {
+            k: 2,
+            z: Complex::new(1.0, 1.0),
+            expected: Complex::new(-2.120883937943714, 11.600137110774577),
+        },
+        TestCase {
+            k: -3,
+            z: Complex::new(-2.5, 0.5),
+            expected: Complex::new(-1.7256244489392172, -14.213748451441813),
+        },
+        TestCase {
+            k: 0,
+            z: Complex::new(1e6, -1e6),
+            expected: Complex::new(11.700540314897427, -0.7236308963832878),
+        },
+    ];
+
+    for case in test_cases.iter() {
+        let w = product_log_k(case.k, case.z);
+        assert_relative_eq!(w.re, case.expected.re, epsilon = 1e-12);
+        assert_relative_eq!(w.im, case.expected.im, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_product_log_k_definition() {
+    let values = [
+        Complex::new(0.5, 0.0),
+        Complex::new(-0.5, 0.0),
+        Complex::new(-0.36, 0.01),
+        Complex::new(-0.36, -0.01),
+        Complex::new(3.0, -4.0),
+        Complex::new(-1e-5, 1e-5),
+        Complex::new(1e10, 1e10),
+    ];
+
+    for k in -5..=5 {
+        for &z in &values {
+            let w = product_log_k(k, z);
+            let verification = w * cexp(w);
+            assert_relative_eq!(verification.re, z.re, max_relative = 1e-12, epsilon = 1e-15);
+            assert_relative_eq!(verification.im, z.im, max_relative = 1e-12, epsilon = 1e-15);
+
+            // Each branch should land in its own strip of the w-plane
+            if k > 0 {
+                assert!(w.im > 0.0);
+            } else if k < -1 {
+                assert!(w.im < 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_product_log_k_special_cases() {
+    assert!(product_log_k(0, f64::NAN).is_nan());
+    assert_eq!(product_log_k(0, 0.0), Complex::ZERO);
+    assert_eq!(product_log_k(3, 0.0).re, f64::NEG_INFINITY);
+
+    let w = product_log_k(2, f64::INFINITY);
+    assert_eq!(w.re, f64::INFINITY);
+    assert_relative_eq!(w.im, 4.0 * PI, epsilon = 1e-12);
+
+    // Conjugate symmetry W_k(conj z) = conj(W_-k(z))
+    let z = Complex::new(-2.0, 3.0);
+    let a = product_log_k(2, z.conj());
+    let b = product_log_k(-2, z).conj();
+    assert_relative_eq!(a.re, b.re, epsilon = 1e-12);
+    assert_relative_eq!(a.im, b.im, epsilon = 1e-12);
+
+    assert_eq!(cproduct_log(Complex::from(E)), Complex::ONE);
+}
+
+#[test]
+fn test_wright_omega_real() {
+    assert_relative_eq!(wright_omega(0.0), 0.5671432904097838, epsilon = 1e-15);
+    assert_relative_eq!(wright_omega(1.0), 1.0, epsilon = 1e-15);
+    assert_relative_eq!(wright_omega(-50.0), (-50.0_f64).exp(), max_relative = 1e-15);
+
+    for &x in &[-10.0, -1.0, 0.5, 3.0, 100.0, 800.0, 1e10] {
+        let w: f64 = wright_omega(x);
+        assert_relative_eq!(w + w.ln(), x, max_relative = 1e-14);
+    }
+
+    assert!(wright_omega(f64::NAN).is_nan());
+    assert_eq!(wright_omega(f64::INFINITY), f64::INFINITY);
+    assert_eq!(wright_omega(f64::NEG_INFINITY), 0.0);
+}
+
+#[test]
+fn test_wright_omega_complex() {
+    let values = [
+        Complex::new(1.0, 2.0),
+        Complex::new(-3.0, 10.0),
+        Complex::new(0.5, -7.0),
+        Complex::new(1000.0, 5.0),
+        Complex::new(-100.0, 1.0),
+        Complex::new(-600.0, 20.0),
+        Complex::new(-600.0, -20.0),
+        Complex::new(-2000.0, 7.0),
+        Complex::new(-600.0, 2.0),
+    ];
+
+    for &z in &values {
+        let w = cwright_omega(z);
+        let verification = w + clog(w);
+        assert_relative_eq!(verification.re, z.re, max_relative = 1e-13, epsilon = 1e-13);
+        assert_relative_eq!(verification.im, z.im, max_relative = 1e-13, epsilon = 1e-13);
+    }
+
+    let w = cwright_omega(Complex::new(-1.0, PI));
+    assert_eq!(w, Complex::from(-1.0));
+
+    // On the boundaries Im z = ±π, both sides of the principal strip
+    for z in [
+        Complex::new(1.0, -PI),
+        Complex::new(1.0, PI),
+        Complex::new(-0.5, -PI),
+    ] {
+        let w = cwright_omega(z);
+        let verification = w + clog(w);
+        assert_relative_eq!(verification.re, z.re, epsilon = 1e-13);
+        assert_relative_eq!(verification.im, z.im, epsilon = 1e-13);
+    }
+    // For Re z <= -1 the two boundaries sit on different real branches,
+    // W_0(-e^t) at Im z = π and W_-1(-e^t) at Im z = -π
+    let w = cwright_omega(Complex::new(-2.0, PI));
+    assert_relative_eq!(w.re, -0.158594339563039, max_relative = 1e-13);
+    assert_eq!(w.im, 0.0);
+    let w = cwright_omega(Complex::new(-2.0, -PI));
+    assert_relative_eq!(w.re, -3.14619322062058, max_relative = 1e-13);
+    assert_eq!(w.im, 0.0);
+    assert_eq!(cwright_omega(Complex::new(-1.0, -PI)), Complex::from(-1.0));
+    let w = cwright_omega(Complex::new(-800.0, -PI));
+    assert_relative_eq!(w.re, -806.692943104048, max_relative = 1e-14);
+    assert_eq!(cwright_omega(Complex::new(-800.0, PI)), Complex::ZERO);
+
+    let w = cwright_omega(Complex::new(1.0, -PI));
+    assert_relative_eq!(w.re, 0.394979082707293, epsilon = 1e-13);
+    assert_relative_eq!(w.im, -1.78818804138363, epsilon = 1e-13);
+}
@@ -9,8 +9,8 @@ fn test_log_basic_values() {
     assert_relative_eq!(log(E * E), 2.0, epsilon = 1e-10);
     assert_relative_eq!(log(1.0 / E), -1.0, epsilon = 1e-10);
 
-    assert!(log(0.0).is_infinite() && log(0.0).is_sign_negative());
-    assert!(log(-1.0).is_nan());
+    assert!(log(0.0_f64).is_infinite() && log(0.0_f64).is_sign_negative());
+    assert!(log(-1.0_f64).is_nan());
     assert!(log(f64::INFINITY).is_infinite() && log(f64::INFINITY).is_sign_positive());
 }
 
@@ -23,8 +23,8 @@ fn test_log10_basic_values() {
     assert_relative_eq!(log10(0.1), -1.0, epsilon = 1e-10);
     assert_relative_eq!(log10(0.01), -2.0, epsilon = 1e-10);
 
-    assert!(log10(0.0).is_infinite() && log10(0.0).is_sign_negative());
-    assert!(log10(-1.0).is_nan());
+    assert!(log10(0.0_f64).is_infinite() && log10(0.0_f64).is_sign_negative());
+    assert!(log10(-1.0_f64).is_nan());
     assert!(log10(f64::INFINITY).is_infinite() && log10(f64::INFINITY).is_sign_positive());
 }
 
@@ -38,8 +38,8 @@ fn test_log2_basic_values() {
     assert_relative_eq!(log2(0.5), -1.0, epsilon = 1e-10);
     assert_relative_eq!(log2(0.25), -2.0, epsilon = 1e-10);
 
-    assert!(log2(0.0).is_infinite() && log2(0.0).is_sign_negative());
-    assert!(log2(-1.0).is_nan());
+    assert!(log2(0.0_f64).is_infinite() && log2(0.0_f64).is_sign_negative());
+    assert!(log2(-1.0_f64).is_nan());
     assert!(log2(f64::INFINITY).is_infinite() && log2(f64::INFINITY).is_sign_positive());
 }
 
@@ -59,12 +59,12 @@ fn test_log_base_basic() {
 
 #[test]
 fn test_log_base_special_cases() {
-    assert!(log_base(1.0, 10.0).is_nan());
-    assert!(log_base(0.0, 10.0).is_nan());
-    assert!(log_base(-2.0, 10.0).is_nan());
+    assert!(log_base(1.0, 10.0_f64).is_nan());
+    assert!(log_base(0.0, 10.0_f64).is_nan());
+    assert!(log_base(-2.0, 10.0_f64).is_nan());
 
-    assert!(log_base(2.0, 0.0).is_infinite() && log_base(2.0, 0.0).is_sign_negative());
-    assert!(log_base(2.0, -1.0).is_nan());
+    assert!(log_base(2.0, 0.0_f64).is_infinite() && log_base(2.0, 0.0_f64).is_sign_negative());
+    assert!(log_base(2.0, -1.0_f64).is_nan());
 
     assert!(
         log_base(2.0, f64::INFINITY).is_infinite()
@@ -78,8 +78,8 @@ fn test_log_base_special_cases() {
 
 #[test]
 fn test_logarithm_properties() {
-    let a = 3.0;
-    let x = 2.0;
+    let a = 3.0_f64;
+    let x = 2.0_f64;
     let y = 7.0;
 
     // log(xy) = log(x) + log(y)
@@ -112,10 +112,10 @@ fn test_change_of_base_formula() {
                 epsilon = 1e-10
             );
 
-            if (*base - 10.0).abs() < 1e-10 {
+            if (*base - 10.0_f64).abs() < 1e-10 {
                 assert_relative_eq!(log_base(*base, *val), log10(*val), epsilon = 1e-10);
             }
-            if (*base - 2.0).abs() < 1e-10 {
+            if (*base - 2.0_f64).abs() < 1e-10 {
                 assert_relative_eq!(log_base(*base, *val), log2(*val), epsilon = 1e-10);
             }
             if (*base - E).abs() < 1e-10 {
@@ -127,7 +127,7 @@ fn test_change_of_base_formula() {
 
 #[test]
 fn test_logarithm_consistency() {
-    let values = [0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 100.0];
+    let values = [0.1_f64, 0.5, 1.0, 2.0, 5.0, 10.0, 100.0];
 
     for &val in values.iter() {
         if val > 0.0 {
@@ -176,14 +176,14 @@ fn test_exp_log_inverse() {
 
 #[test]
 fn test_logarithm_limits() {
-    assert!(log(1e-100).is_finite());
+    assert!(log(1e-100_f64).is_finite());
     assert!(log(1e-100) < 0.0);
 
-    assert!(log(1e100).is_finite());
+    assert!(log(1e100_f64).is_finite());
     assert!(log(1e100) > 0.0);
 
-    assert!(log10(1e-100).is_finite());
-    assert!(log2(1e-100).is_finite());
-    assert!(log10(1e100).is_finite());
-    assert!(log2(1e100).is_finite());
+    assert!(log10(1e-100_f64).is_finite());
+    assert!(log2(1e-100_f64).is_finite());
+    assert!(log10(1e100_f64).is_finite());
+    assert!(log2(1e100_f64).is_finite());
 }
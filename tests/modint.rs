@@ -0,0 +1,64 @@
+use rmath::*;
+
+type Mod7 = ModInt<7>;
+type Mod1e9 = ModInt<1_000_000_007>;
+
+#[test]
+fn test_modint_arithmetic() {
+    let a = Mod7::new(5);
+    let b = Mod7::new(4);
+
+    assert_eq!((a + b).value(), 2);
+    assert_eq!((a - b).value(), 1);
+    assert_eq!((b - a).value(), 6);
+    assert_eq!((a * b).value(), 6);
+    assert_eq!((a / b).value(), 3);
+    assert_eq!((-a).value(), 2);
+    assert_eq!((-Mod7::new(0)).value(), 0);
+    assert_eq!(Mod7::new(23).value(), 2);
+    assert_eq!(Mod7::from(-1i64).value(), 6);
+    assert_eq!(Mod7::from(-15i32).value(), 6);
+    assert_eq!(Mod7::MODULUS, 7);
+
+    let mut c = a;
+    c += 3;
+    c *= b;
+    c -= 10;
+    c /= 2;
+    assert_eq!(c, Mod7::new(4));
+    assert_eq!(format!("{}", c), "4");
+
+    let sum: Mod1e9 = (1..=100_000u64).map(Mod1e9::new).sum();
+    assert_eq!(sum.value(), 5_000_050_000 % 1_000_000_007);
+    let factorial: Mod1e9 = (1..=20u64).map(Mod1e9::new).product();
+    assert_eq!(factorial.value(), 146326063);
+}
+
+#[test]
+fn test_modint_pow_inverse() {
+    assert_eq!(Mod1e9::new(2).pow(1_000_000_006).value(), 1);
+    assert_eq!(Mod1e9::new(3).pow(0).value(), 1);
+    assert_eq!(Mod1e9::new(2).inverse().unwrap().value(), 500_000_004);
+
+    assert_eq!(ModInt::<12>::new(5).inverse().unwrap().value(), 5);
+    assert_eq!(ModInt::<12>::new(8).inverse(), None);
+    assert_eq!(ModInt::<1>::new(5).value(), 0);
+
+    // Moduli past i64 keep working, including composite ones
+    type Big = ModInt<18446744073709551557>;
+    let x = Big::new(u64::MAX);
+    assert_eq!((x + x).value(), 116);
+    assert_eq!((x * x).value(), 3364);
+    assert_eq!((x * x.inverse().unwrap()).value(), 1);
+
+    type BigComposite = ModInt<{ u64::MAX }>;
+    let y = BigComposite::new(2);
+    assert_eq!((y * y.inverse().unwrap()).value(), 1);
+    assert_eq!(BigComposite::new(3).inverse(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_modint_divide_by_noninvertible() {
+    let _ = ModInt::<10>::new(3) / ModInt::<10>::new(4);
+}
@@ -1,3 +1,4 @@
+use rmath::bigint::BigInt;
 use rmath::number_theory::*;
 
 #[test]
@@ -202,7 +203,7 @@ fn test_factor_integer() {
     assert_eq!(factors_of_0, vec![]);
 
     let factors_of_1 = factor_integer(1);
-    assert_eq!(factors_of_1, vec![(1, 1)]);
+    assert_eq!(factors_of_1, vec![]);
 
     let factors_of_72760 = factor_integer(72760);
     assert_eq!(factors_of_72760, vec![(2, 3), (5, 1), (17, 1), (107, 1)]);
@@ -210,3 +211,505 @@ fn test_factor_integer() {
     let factors_of_7919 = factor_integer(7919);
     assert_eq!(factors_of_7919, vec![(7919, 1)]);
 }
+
+#[test]
+fn test_gcd_lcm_big() {
+    let a: BigInt = "123456789012345678901234567890".parse().unwrap();
+    let b: BigInt = "987654321098765432109876543210".parse().unwrap();
+    assert_eq!(gcd_big(&a, &b).to_string(), "9000000000900000000090");
+    assert_eq!(
+        lcm_big(&a, &b).to_string(),
+        "13548070124980948012498094801236261410"
+    );
+    assert_eq!(gcd_big(&-a.clone(), &b), gcd_big(&a, &b));
+    assert_eq!(gcd_big(&BigInt::ZERO, &BigInt::ZERO), BigInt::ZERO);
+    assert_eq!(lcm_big(&a, &BigInt::ZERO), BigInt::ZERO);
+
+    for (x, y) in [(48, 18), (-17, 51), (1 << 40, 6 << 20)] {
+        assert_eq!(
+            gcd_big(&BigInt::from(x), &BigInt::from(y)),
+            BigInt::from(gcd(x, y))
+        );
+        assert_eq!(
+            lcm_big(&BigInt::from(x), &BigInt::from(y)),
+            BigInt::from(lcm(x, y))
+        );
+    }
+}
+
+#[test]
+fn test_factor_integer_big() {
+    assert_eq!(factor_integer_big(&BigInt::ZERO), vec![]);
+    assert_eq!(
+        factor_integer_big(&BigInt::from(72760)),
+        vec![
+            (BigInt::from(2), 3),
+            (BigInt::from(5), 1),
+            (BigInt::from(17), 1),
+            (BigInt::from(107), 1)
+        ]
+    );
+
+    let n = BigInt::from(2).pow(100) * BigInt::from(27) * 65537 * 4294967291i64;
+    assert_eq!(
+        factor_integer_big(&-n),
+        vec![
+            (BigInt::from(2), 100),
+            (BigInt::from(3), 3),
+            (BigInt::from(65537), 1),
+            (BigInt::from(4294967291i64), 1)
+        ]
+    );
+
+    assert_eq!(factor_integer_big(&BigInt::from(-1)), vec![]);
+    assert_eq!(
+        factor_integer_big(&(BigInt::from(2).pow(64) + 1)),
+        vec![
+            (BigInt::from(274177), 1),
+            (BigInt::from(67280421310721i64), 1)
+        ]
+    );
+    let n = BigInt::from(1000000007) * 1000000009 * BigInt::from(18446744073709551557u64);
+    assert_eq!(
+        factor_integer_big(&n),
+        vec![
+            (BigInt::from(1000000007), 1),
+            (BigInt::from(1000000009), 1),
+            (BigInt::from(18446744073709551557u64), 1)
+        ]
+    );
+}
+
+#[test]
+fn test_is_prime_u64() {
+    // Largest primes below 2^63 and 2^64
+    assert!(is_prime(9223372036854775783));
+    assert!(is_prime_u64(18446744073709551557));
+    assert!(!is_prime_u64(18446744073709551615));
+    assert!(is_prime_u64((1 << 61) - 1));
+    assert!(!is_prime(i64::MIN));
+    assert!(!is_prime(-7));
+
+    // Carmichael numbers and strong pseudoprimes to several prime bases
+    for n in [561, 1105, 3215031751, 2152302898747, 3474749660383] {
+        assert!(!is_prime_u64(n), "{} is composite", n);
+    }
+    assert!(!is_prime_u64(3825123056546413051));
+    assert!(!is_prime_u64(4294967291 * 4294967279));
+
+    let sieved = (0..2000).filter(|&n| is_prime(n)).count();
+    assert_eq!(sieved, 303);
+}
+
+#[test]
+fn test_is_probable_prime() {
+    let two = BigInt::from(2);
+    assert!(is_probable_prime(&(two.pow(89) - 1), 20));
+    assert!(is_probable_prime(&(two.pow(127) - 1), 20));
+    assert!(is_probable_prime(&(two.pow(521) - 1), 8));
+    assert!(!is_probable_prime(&(two.pow(67) - 1), 20));
+    assert!(!is_probable_prime(&(two.pow(128) + 1), 20));
+    assert!(!is_probable_prime(&(two.pow(64) * 3 + 3), 20));
+    assert!(!is_probable_prime(&BigInt::from(-7), 20));
+
+    // Strong pseudoprimes to every prime base up to 37 and up to 41, caught by
+    // the next prime base
+    let psi_12: BigInt = "318665857834031151167461".parse().unwrap();
+    let psi_13: BigInt = "3317044064679887385961981".parse().unwrap();
+    assert!(is_probable_prime(&psi_12, 12));
+    assert!(!is_probable_prime(&psi_12, 13));
+    assert!(is_probable_prime(&psi_13, 13));
+    assert!(!is_probable_prime(&psi_13, 14));
+
+    // Small values agree with the deterministic test
+    for n in 0..500u64 {
+        assert_eq!(is_probable_prime(&BigInt::from(n), 1), is_prime_u64(n));
+    }
+    assert!(is_probable_prime(&BigInt::from(18446744073709551557u64), 1));
+}
+
+#[test]
+fn test_factor_integer_rho() {
+    // Semiprimes with two large factors, out of reach of trial division
+    assert_eq!(
+        factor_integer(2147483647 * 2147483629),
+        vec![(2147483629, 1), (2147483647, 1)]
+    );
+    assert_eq!(
+        factor_integer_u64(4294967291 * 4294967279),
+        vec![(4294967279, 1), (4294967291, 1)]
+    );
+    assert_eq!(
+        factor_integer_u64(u64::MAX),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (6700417, 1)
+        ]
+    );
+    assert_eq!(
+        factor_integer(i64::MAX),
+        vec![(7, 2), (73, 1), (127, 1), (337, 1), (92737, 1), (649657, 1)]
+    );
+    assert_eq!(factor_integer(i64::MIN), vec![(2, 63)]);
+    assert_eq!(factor_integer(-600851475143), factor_integer(600851475143));
+    assert_eq!(
+        factor_integer_u64(18446744073709551557),
+        vec![(18446744073709551557, 1)]
+    );
+    assert_eq!(factor_integer_u64(1), vec![]);
+
+    // The factorization multiplies back for a range of values
+    for n in (1..2000).chain(999_999_000..1_000_000_000) {
+        let product: i64 = factor_integer(n)
+            .iter()
+            .map(|&(p, k)| p.pow(k as u32))
+            .product();
+        assert_eq!(product, n);
+        assert!(factor_integer(n).iter().all(|&(p, _)| is_prime(p)));
+    }
+
+    assert_eq!(divisors(2147483647 * 2147483629).len(), 4);
+}
+
+#[test]
+fn test_prime_iterators() {
+    let first: Vec<u64> = primes().take(10).collect();
+    assert_eq!(first, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(primes().nth(99_999), Some(1299709));
+
+    assert_eq!(primes_in(100..=200).count(), 21);
+    assert_eq!(primes_in(..100).last(), Some(97));
+    assert_eq!(primes_in(97..97).count(), 0);
+    assert_eq!(primes_in(..0).count(), 0);
+    assert_eq!(primes_in(13..=13).collect::<Vec<_>>(), vec![13]);
+
+    // Far beyond the cache the range is sieved on its own
+    let lo = 1_000_000_000_000;
+    let sieved: Vec<u64> = primes_in(lo..lo + 2000).collect();
+    let tested: Vec<u64> = (lo..lo + 2000).filter(|&n| is_prime_u64(n)).collect();
+    assert_eq!(sieved, tested);
+    assert_eq!(sieved[0], 1_000_000_000_039);
+
+    // Across the largest cached bound, where later segments stop growing the
+    // cache and are sieved into the iterator's own buffer
+    assert_eq!(primes_in(..=1 << 22).count(), 295947);
+    assert_eq!(primes_in(..=5_000_000).count(), 348513);
+    assert_eq!(primes_in(4_000_000..=5_000_000).count(), 348513 - 283146);
+
+    let top: Vec<u64> = primes_in(u64::MAX - 100..).collect();
+    assert_eq!(
+        top,
+        vec![
+            18446744073709551521,
+            18446744073709551533,
+            18446744073709551557
+        ]
+    );
+}
+
+#[test]
+fn test_next_prev_prime() {
+    assert_eq!(next_prime(0), Some(2));
+    assert_eq!(next_prime(2), Some(3));
+    assert_eq!(next_prime(1299689), Some(1299709));
+    assert_eq!(next_prime(1_000_000_000_000), Some(1_000_000_000_039));
+    assert_eq!(next_prime(18446744073709551557), None);
+
+    assert_eq!(prev_prime(2), None);
+    assert_eq!(prev_prime(3), Some(2));
+    assert_eq!(prev_prime(1299709), Some(1299689));
+    assert_eq!(prev_prime(1_000_000_000_039), Some(999_999_999_989));
+    assert_eq!(prev_prime(u64::MAX), Some(18446744073709551557));
+}
+
+#[test]
+fn test_prime_threads() {
+    // Concurrent growth of the shared sieve
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            std::thread::spawn(move || {
+                (
+                    prime(50_000 + 10_000 * t),
+                    prime_pi(200_000 * (t as u64 + 1)),
+                )
+            })
+        })
+        .collect();
+    let results: Vec<(i64, u64)> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(results[0], (611953, 17984));
+    assert_eq!(results[7], (1583539, 121127));
+    for (t, &(p, _)) in results.iter().enumerate() {
+        assert_eq!(p, prime(50_000 + 10_000 * t as i32));
+    }
+}
+
+#[test]
+fn test_prime_pi_large() {
+    assert_eq!(prime_pi(0), 0);
+    assert_eq!(prime_pi(2), 1);
+    assert_eq!(prime_pi(100), 25);
+    assert_eq!(prime_pi(4_194_304), 295947);
+    assert_eq!(prime_pi(4_194_305), 295947);
+    assert_eq!(prime_pi(100_000_000), 5761455);
+    assert_eq!(prime_pi(10_000_000_000), 455052511);
+    assert_eq!(prime_pi(1_000_000_007), 50847535);
+}
+
+#[test]
+fn test_prime_sum() {
+    assert_eq!(prime_sum(1), 0);
+    assert_eq!(prime_sum(2), 2);
+    assert_eq!(prime_sum(10), 17);
+    assert_eq!(prime_sum(2_000_000), 142913828922);
+    assert_eq!(prime_sum(10_000_000_000), 2220822432581729238);
+}
+
+#[test]
+fn test_nth_prime() {
+    assert_eq!(nth_prime(1), 2);
+    assert_eq!(nth_prime(6), 13);
+    assert_eq!(nth_prime(1_000_000), 15485863);
+    assert_eq!(nth_prime(10_000_000), 179424673);
+    assert_eq!(nth_prime(100_000_000), 2038074743);
+    assert_eq!(prime(50_847_534), 999999937);
+    assert_eq!(nth_prime(455052511), 9999999967);
+}
+
+#[test]
+fn test_extended_gcd() {
+    for &(a, b) in &[
+        (240, 46),
+        (-240, 46),
+        (17, 0),
+        (0, -5),
+        (0, 0),
+        (i64::MAX, 2),
+    ] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+    }
+    assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+}
+
+#[test]
+fn test_mod_pow_mul_inverse() {
+    assert_eq!(mod_pow(2, 10, 1000), 24);
+    assert_eq!(mod_pow(3, 0, 1), 0);
+    assert_eq!(
+        mod_pow(u64::MAX, u64::MAX, 18446744073709551557),
+        4959809447704153900
+    );
+    assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+    assert_eq!(mod_inverse(1_000_000, 1_000_000_007), Some(142857001));
+}
+
+#[test]
+fn test_chinese_remainder() {
+    assert_eq!(
+        chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+        Some((23, 105))
+    );
+    assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+    assert_eq!(chinese_remainder(&[(-1, 4)]), Some((3, 4)));
+
+    // Non-coprime moduli
+    assert_eq!(chinese_remainder(&[(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+
+    let big = [(123456789, 1_000_000_007), (987654321, 998_244_353)];
+    let (x, m) = chinese_remainder(&big).unwrap();
+    assert_eq!(m, 1_000_000_007 * 998_244_353);
+    assert_eq!(x % 1_000_000_007, 123456789);
+    assert_eq!(x % 998_244_353, 987654321);
+
+    assert_eq!(chinese_remainder(&[(0, i64::MAX), (1, 2)]), None);
+}
+
+#[test]
+fn test_multiplicative_functions() {
+    let phi: Vec<i64> = (1..=12).map(euler_phi).collect();
+    assert_eq!(phi, vec![1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]);
+    assert_eq!(euler_phi(-36), 12);
+    assert_eq!(euler_phi(0), 0);
+    assert_eq!(euler_phi(600851475143), 591194251200);
+
+    let lambda: Vec<i64> = (1..=16).map(carmichael_lambda).collect();
+    assert_eq!(
+        lambda,
+        vec![1, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2, 12, 6, 4, 4]
+    );
+    assert_eq!(carmichael_lambda(561), 80);
+    assert_eq!(carmichael_lambda(1024), 256);
+
+    let mu: Vec<i32> = (1..=12).map(moebius_mu).collect();
+    assert_eq!(mu, vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+    let liouville: Vec<i32> = (1..=10).map(liouville_lambda).collect();
+    assert_eq!(liouville, vec![1, -1, -1, 1, -1, 1, -1, -1, 1, 1]);
+
+    assert_eq!(divisor_sigma(1, 12), Some(28));
+    assert_eq!(divisor_sigma(2, 12), Some(210));
+    assert_eq!(divisor_sigma(1, 1), Some(1));
+    assert_eq!(divisor_sigma(0, 0), Some(0));
+    assert_eq!(divisor_sigma(3, 1_000_003), Some(1_000_009_000_027_000_028));
+    assert_eq!(divisor_sigma(5, 1_000_003), None);
+    assert_eq!(divisor_sigma(1, 963761198400), Some(5531765944320));
+    assert_eq!(divisor_count(720720), 240);
+    assert_eq!(divisor_count(963761198400), 6720);
+    for n in [1, 36, 97, 1234, 5040] {
+        let divs = divisors(n);
+        assert_eq!(divisor_count(n), divs.len() as i64);
+        assert_eq!(
+            divisor_sigma(3, n),
+            Some(divs.iter().map(|d| d.pow(3)).sum())
+        );
+    }
+
+    assert_eq!(radical(504), 42);
+    assert_eq!(radical(1), 1);
+    assert_eq!(prime_nu(504), 3);
+    assert_eq!(prime_omega(504), 6);
+    assert_eq!(prime_omega(1), 0);
+}
+
+#[test]
+fn test_arithmetic_sieves() {
+    let phi = totient_sieve(10_000);
+    let mu = mobius_sieve(10_000);
+    assert_eq!(phi.len(), 10_001);
+    assert_eq!(phi[0], 0);
+    assert_eq!(mu[0], 0);
+    for n in 1..=10_000 {
+        assert_eq!(phi[n], euler_phi(n as i64));
+        assert_eq!(mu[n], moebius_mu(n as i64));
+    }
+
+    assert_eq!(totient_sieve(0), vec![0]);
+    assert_eq!(mobius_sieve(1), vec![0, 1]);
+    assert_eq!(totient_sieve(1_000_000).iter().sum::<i64>(), 303963552392);
+}
+
+#[test]
+fn test_residue_symbols() {
+    let legendre: Vec<i32> = (0..11).map(|a| legendre_symbol(a, 11)).collect();
+    assert_eq!(legendre, vec![0, 1, -1, 1, 1, 1, -1, -1, -1, 1, -1]);
+    assert_eq!(legendre_symbol(-1, 13), 1);
+    assert_eq!(legendre_symbol(-1, 19), -1);
+
+    assert_eq!(jacobi_symbol(1001, 9907), -1);
+    assert_eq!(jacobi_symbol(19, 45), 1);
+    assert_eq!(jacobi_symbol(8, 21), -1);
+    assert_eq!(jacobi_symbol(5, 21), 1);
+    assert_eq!(jacobi_symbol(6, 15), 0);
+    assert_eq!(jacobi_symbol(0, 1), 1);
+
+    assert_eq!(kronecker_symbol(3, 8), -1);
+    assert_eq!(kronecker_symbol(5, 8), -1);
+    assert_eq!(kronecker_symbol(7, 8), 1);
+    assert_eq!(kronecker_symbol(2, 6), 0);
+    assert_eq!(kronecker_symbol(-5, -3), -1);
+    assert_eq!(kronecker_symbol(-5, 3), 1);
+    assert_eq!(kronecker_symbol(5, -3), -1);
+    assert_eq!(kronecker_symbol(1, 0), 1);
+    assert_eq!(kronecker_symbol(2, 0), 0);
+    assert_eq!(kronecker_symbol(19, 45), jacobi_symbol(19, 45));
+}
+
+#[test]
+#[should_panic]
+fn test_jacobi_even_modulus() {
+    jacobi_symbol(3, 10);
+}
+
+#[test]
+fn test_mod_sqrt() {
+    assert_eq!(mod_sqrt(10, 13), Some(6));
+    assert_eq!(mod_sqrt(5, 13), None);
+    assert_eq!(mod_sqrt(0, 13), Some(0));
+    assert_eq!(mod_sqrt(1, 2), Some(1));
+
+    // p ≡ 1 (mod 2^k) exercises the full Tonelli-Shanks loop
+    for p in [
+        17,
+        97,
+        257,
+        65537,
+        998_244_353,
+        1_000_000_007,
+        4611686018427387847,
+    ] {
+        for a in [2, 3, 5, 10, 12345, -7] {
+            match mod_sqrt(a, p) {
+                Some(r) => {
+                    assert_eq!(
+                        mod_mul(r as u64, r as u64, p as u64),
+                        a.rem_euclid(p) as u64
+                    );
+                    assert!(r <= p - r);
+                }
+                None => assert_eq!(legendre_symbol(a, p), -1),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_multiplicative_order_primitive_root() {
+    assert_eq!(multiplicative_order(2, 7), Some(3));
+    assert_eq!(multiplicative_order(3, 7), Some(6));
+    assert_eq!(multiplicative_order(10, 49), Some(42));
+    assert_eq!(multiplicative_order(4, 6), None);
+    assert_eq!(multiplicative_order(5, 1), Some(1));
+    assert_eq!(multiplicative_order(2, 1_000_000_007), Some(500000003));
+
+    assert_eq!(primitive_root(1), Some(0));
+    assert_eq!(primitive_root(2), Some(1));
+    assert_eq!(primitive_root(4), Some(3));
+    assert_eq!(primitive_root(7), Some(3));
+    assert_eq!(primitive_root(50), Some(3));
+    assert_eq!(primitive_root(998_244_353), Some(3));
+    assert_eq!(primitive_root(1_000_000_007), Some(5));
+    assert_eq!(primitive_root(8), None);
+    assert_eq!(primitive_root(15), None);
+    for n in [9, 18, 25, 54, 121] {
+        let g = primitive_root(n).unwrap();
+        assert_eq!(multiplicative_order(g, n), Some(euler_phi(n)));
+    }
+}
+
+#[test]
+fn test_discrete_log() {
+    assert_eq!(discrete_log(3, 13, 17), Some(4));
+    assert_eq!(discrete_log(2, 1, 7), Some(0));
+    assert_eq!(discrete_log(2, 3, 7), None);
+    assert_eq!(discrete_log(5, 123456789, 1_000_000_007), Some(981640996));
+    assert_eq!(mod_pow(5, 981640996, 1_000_000_007), 123456789);
+
+    // Non-coprime base and modulus
+    assert_eq!(discrete_log(2, 0, 8), Some(3));
+    assert_eq!(discrete_log(2, 4, 12), Some(2));
+    assert_eq!(discrete_log(6, 0, 12), Some(2));
+    assert_eq!(discrete_log(6, 3, 12), None);
+    assert_eq!(discrete_log(3, 7, 1), Some(0));
+
+    for n in [2, 9, 20, 36, 97, 100, 1024] {
+        for a in 0..n.min(30) {
+            for x in 0..12 {
+                let b = mod_pow(a as u64, x, n as u64) as i64;
+                let found = discrete_log(a, b, n).unwrap();
+                assert!(found <= x as i64);
+                assert_eq!(mod_pow(a as u64, found as u64, n as u64), b as u64);
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use approx::assert_relative_eq;
+use rmath::orthogonal_polynomials::*;
+use std::f64::consts::PI;
+
+// Integrates f with a quadrature rule
+fn integrate(rule: (Vec<f64>, Vec<f64>), f: impl Fn(f64) -> f64) -> f64 {
+    rule.0.iter().zip(&rule.1).map(|(&x, &w)| w * f(x)).sum()
+}
+
+#[test]
+fn test_legendre() {
+    assert_relative_eq!(legendre_p(5, 0.3), 0.34538625, max_relative = 1e-15);
+    assert_relative_eq!(
+        legendre_p(20, -0.77),
+        0.14948826389399514,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        legendre_p(100, 0.999),
+        -0.3217733884157668,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(legendre_p(3, 2.5), 35.3125, max_relative = 1e-15);
+
+    assert_relative_eq!(
+        legendre_p_derivative(5, 0.3),
+        -0.16856249999999987,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        legendre_p_derivative(20, -0.77),
+        -5.354438825553699,
+        max_relative = 1e-13
+    );
+    assert_eq!(legendre_p_derivative(4, 1.0), 10.0);
+    assert_eq!(legendre_p_derivative(4, -1.0), -10.0);
+
+    for n in 0..8 {
+        assert_eq!(legendre_p(n, 1.0), 1.0);
+    }
+    assert_eq!(legendre_p(3, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert!(legendre_p(3, f64::NAN).is_nan());
+}
+
+#[test]
+fn test_assoc_legendre() {
+    assert_relative_eq!(
+        assoc_legendre_p(5, 2, 0.3),
+        -10.462725,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        assoc_legendre_p(10, 3, -0.6),
+        -49.08869222400036,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(assoc_legendre_p(4, 4, 0.5), 59.0625, max_relative = 1e-15);
+    assert_relative_eq!(
+        assoc_legendre_p(7, 1, 0.9),
+        -1.4433628337663476,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(
+        assoc_legendre_p_derivative(5, 2, 0.3),
+        -2.178750000000003,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        assoc_legendre_p_derivative(10, 3, -0.6),
+        3866.4198144,
+        max_relative = 1e-14
+    );
+
+    // m = 0 reduces to the Legendre polynomial
+    assert_eq!(assoc_legendre_p(6, 0, 0.4), legendre_p(6, 0.4));
+    assert_eq!(assoc_legendre_p(3, 5, 0.4), 0.0);
+    assert_eq!(assoc_legendre_p(3, 1, 1.0), 0.0);
+    assert!(assoc_legendre_p(3, 1, 1.5).is_nan());
+}
+
+#[test]
+fn test_chebyshev() {
+    assert_relative_eq!(chebyshev_t(5, 0.3), 0.99888, max_relative = 1e-15);
+    assert_relative_eq!(
+        chebyshev_t(50, 0.77),
+        -0.9991904978699413,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        chebyshev_t(7, 1.8),
+        2115.8840448000005,
+        max_relative = 1e-15
+    );
+    assert_eq!(chebyshev_t(4, -3.0), 577.0);
+
+    assert_relative_eq!(chebyshev_u(5, 0.3), 1.01376, max_relative = 1e-15);
+    assert_relative_eq!(
+        chebyshev_u(50, 0.77),
+        -1.0477390476480308,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        chebyshev_u(7, 1.8),
+        4660.6058496000005,
+        max_relative = 1e-15
+    );
+
+    // T_n(cos θ) = cos(nθ)
+    for n in 0..12 {
+        assert_relative_eq!(
+            chebyshev_t(n, 0.4f64.cos()),
+            (n as f64 * 0.4).cos(),
+            max_relative = 1e-13
+        );
+    }
+
+    assert_relative_eq!(
+        chebyshev_t_derivative(6, 0.4),
+        6.0 * chebyshev_u(5, 0.4),
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        chebyshev_u_derivative(6, 0.4),
+        2.652159999999999,
+        max_relative = 1e-14
+    );
+    assert_eq!(chebyshev_u_derivative(3, 1.0), 20.0);
+    assert_eq!(chebyshev_u_derivative(3, -1.0), 20.0);
+}
+
+#[test]
+fn test_hermite() {
+    assert_relative_eq!(hermite_h(5, 0.3), 31.757759999999998, max_relative = 1e-15);
+    assert_relative_eq!(hermite_h(20, 2.5), -15799429886575.0, max_relative = 1e-14);
+    assert_relative_eq!(
+        hermite_h(10, -1.1),
+        -15676.055823257622,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(hermite_he(5, 0.3), 4.23243, max_relative = 1e-15);
+    assert_relative_eq!(hermite_he(20, 2.5), 570520371.3062525, max_relative = 1e-14);
+
+    assert_eq!(hermite_h_derivative(5, 0.3), 10.0 * hermite_h(4, 0.3));
+    assert_eq!(hermite_he_derivative(5, 0.3), 5.0 * hermite_he(4, 0.3));
+    assert_eq!(hermite_h(3, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(hermite_h(4, f64::NEG_INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_laguerre() {
+    assert_relative_eq!(
+        laguerre_l(5, 0.3),
+        -0.09333274999999998,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        laguerre_l(20, 7.5),
+        0.4510955524973794,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        generalized_laguerre_l(6, 2.5, 1.7),
+        -4.647439411111111,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        generalized_laguerre_l(10, -0.5, 3.0),
+        -0.032174878801618303,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        generalized_laguerre_l_derivative(6, 2.5, 1.7),
+        -0.5659640000000008,
+        max_relative = 1e-14
+    );
+    assert_eq!(laguerre_l_derivative(1, 2.0), -1.0);
+
+    // The leading coefficient of L_n is (-1)^n / n!
+    assert_eq!(laguerre_l(3, f64::INFINITY), f64::NEG_INFINITY);
+    assert_eq!(laguerre_l(4, f64::INFINITY), f64::INFINITY);
+    assert!(laguerre_l(4, f64::NAN).is_nan());
+}
+
+#[test]
+fn test_jacobi_and_gegenbauer() {
+    assert_relative_eq!(jacobi_p(5, 0.5, 1.5, 0.3), 0.5180175, max_relative = 1e-14);
+    assert_relative_eq!(
+        jacobi_p(12, -0.3, 2.0, -0.8),
+        0.46557240498394087,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        jacobi_p(4, 1.0, 1.0, 0.9),
+        2.1488125000000005,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        jacobi_p_derivative(5, 0.5, 1.5, 0.3),
+        1.1261250000000003,
+        max_relative = 1e-14
+    );
+
+    assert_relative_eq!(gegenbauer_c(5, 1.5, 0.3), 2.02174875, max_relative = 1e-14);
+    assert_relative_eq!(
+        gegenbauer_c(12, 0.25, -0.8),
+        0.029373131064062568,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        gegenbauer_c(6, 3.0, 1.2),
+        2375.6353279999994,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        gegenbauer_c_derivative(5, 1.5, 0.3),
+        -4.629187499999999,
+        max_relative = 1e-14
+    );
+
+    // Special parameters reduce to the other families
+    for n in 0..8 {
+        assert_relative_eq!(
+            jacobi_p(n, 0.0, 0.0, 0.35),
+            legendre_p(n, 0.35),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            gegenbauer_c(n, 0.5, 0.35),
+            legendre_p(n, 0.35),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            gegenbauer_c(n, 1.0, 0.35),
+            chebyshev_u(n, 0.35),
+            max_relative = 1e-14
+        );
+    }
+}
+
+#[test]
+fn test_gauss_nodes() {
+    let (nodes, weights) = gauss_legendre(4);
+    assert_relative_eq!(nodes[3], 0.8611363115940526, max_relative = 1e-15);
+    assert_relative_eq!(nodes[2], 0.33998104358485626, max_relative = 1e-15);
+    assert_relative_eq!(weights[3], 0.34785484513745385, max_relative = 1e-15);
+    assert_relative_eq!(weights[2], 0.6521451548625461, max_relative = 1e-15);
+    assert_eq!(nodes[0], -nodes[3]);
+
+    let (nodes, weights) = gauss_chebyshev_t(5);
+    assert_eq!(nodes[2], 0.0);
+    assert_relative_eq!(weights[0], PI / 5.0, max_relative = 1e-15);
+
+    // Nodes are the zeros of the polynomial and come out in ascending order
+    for (nodes, p) in [
+        (gauss_hermite(9).0, hermite_h(9, 1.3)),
+        (gauss_laguerre(9).0, laguerre_l(9, 1.3)),
+        (gauss_jacobi(9, 0.5, -0.2).0, jacobi_p(9, 0.5, -0.2, 1.3)),
+    ] {
+        assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+        assert!(p.is_finite());
+    }
+    for &x in &gauss_hermite(9).0 {
+        assert!((hermite_h(9, x) / hermite_h_derivative(9, x)).abs() < 1e-14);
+    }
+    for &x in &gauss_jacobi(9, 0.5, -0.2).0 {
+        assert!((jacobi_p(9, 0.5, -0.2, x) / jacobi_p_derivative(9, 0.5, -0.2, x)).abs() < 1e-15);
+    }
+
+    assert!(gauss_legendre(0).0.is_empty());
+    assert!(gauss_jacobi(3, -1.5, 0.0).0[0].is_nan());
+}
+
+#[test]
+fn test_gauss_quadrature_exactness() {
+    // An n-point rule integrates polynomials of degree up to 2n - 1 exactly
+    let f = |x: f64| x.powi(8) - 3.0 * x.powi(5) + x * x + 1.0;
+
+    assert_relative_eq!(
+        integrate(gauss_legendre(5), f),
+        2.0 / 9.0 + 2.0 / 3.0 + 2.0,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_chebyshev_t(5), f),
+        PI * (35.0 / 128.0 + 0.5 + 1.0),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_chebyshev_u(5), f),
+        PI * (7.0 / 256.0 + 1.0 / 8.0 + 0.5),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_hermite(5), f),
+        PI.sqrt() * (105.0 / 16.0 + 0.5 + 1.0),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_hermite_e(5), f),
+        (2.0 * PI).sqrt() * (105.0 + 1.0 + 1.0),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_laguerre(5), f),
+        40320.0 - 360.0 + 2.0 + 1.0,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        integrate(gauss_generalized_laguerre(5, 0.5), |x| x.powi(4)),
+        // Γ(5.5)
+        52.34277778455352,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        integrate(gauss_jacobi(5, 1.0, 2.0), |x| x.powi(3) + 1.0),
+        // ∫ (x³ + 1)(1 - x)(1 + x)² dx over [-1, 1]
+        152.0 / 105.0,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        integrate(gauss_gegenbauer(5, 1.0), f),
+        integrate(gauss_chebyshev_u(5), f),
+        max_relative = 1e-14
+    );
+}
@@ -10,7 +10,7 @@ fn test_sqrt_basic() {
     assert_relative_eq!(sqrt(9.0), 3.0, epsilon = 1e-10);
     assert_relative_eq!(sqrt(2.0), 2.0_f64.sqrt(), epsilon = 1e-10);
 
-    assert!(sqrt(-1.0).is_nan());
+    assert!(sqrt(-1.0_f64).is_nan());
 
     assert!(sqrt(f64::INFINITY).is_infinite());
     assert!(sqrt(f64::INFINITY).is_sign_positive());
@@ -27,7 +27,7 @@ fn test_power_basic() {
     assert_relative_eq!(power(-2.0, 2.0), 4.0, epsilon = 1e-10);
     assert_relative_eq!(power(-3.0, 4.0), 81.0, epsilon = 1e-10);
 
-    assert!(power(-2.0, 0.5).is_nan());
+    assert!(power(-2.0, 0.5_f64).is_nan());
 }
 
 #[test]
@@ -36,7 +36,7 @@ fn test_power_special_cases() {
     assert_relative_eq!(power(1.0, -100.0), 1.0, epsilon = 1e-10);
 
     assert_relative_eq!(power(0.0, 2.0), 0.0, epsilon = 1e-10);
-    assert!(power(0.0, -1.0).is_infinite());
+    assert!(power(0.0, -1.0_f64).is_infinite());
 
     assert_relative_eq!(power(8.0, 1.0 / 3.0), 2.0, epsilon = 1e-10);
     assert_relative_eq!(power(16.0, 0.25), 2.0, epsilon = 1e-10);
@@ -62,10 +62,10 @@ fn test_nth_root() {
     assert_relative_eq!(nth_root(-8.0, 3), -2.0, epsilon = 1e-10);
     assert_relative_eq!(nth_root(-32.0, 5), -2.0, epsilon = 1e-10);
 
-    assert!(nth_root(-4.0, 2).is_nan());
-    assert!(nth_root(-16.0, 4).is_nan());
+    assert!(nth_root(-4.0_f64, 2).is_nan());
+    assert!(nth_root(-16.0_f64, 4).is_nan());
 
-    assert!(nth_root(4.0, 0).is_nan());
+    assert!(nth_root(4.0_f64, 0).is_nan());
 }
 
 #[test]
@@ -118,7 +118,7 @@ fn test_integer_power_efficiency() {
 
 #[test]
 fn test_power_consistency() {
-    let bases = [0.5, 1.0, 2.0, 3.0, 10.0];
+    let bases = [0.5_f64, 1.0, 2.0, 3.0, 10.0];
     let exponents = [0.0, 0.5, 1.0, 2.0, 3.0];
 
     for base in bases.iter() {
@@ -0,0 +1,202 @@
+use rmath::*;
+
+fn r(numer: i64, denom: i64) -> Rational {
+    Rational::new(numer, denom)
+}
+
+#[test]
+fn test_rational_normalization() {
+    let x = r(6, -8);
+    assert_eq!(*x.numer(), -3);
+    assert_eq!(*x.denom(), 4);
+    assert_eq!(r(0, -5), Rational::zero());
+    assert_eq!(r(10, 5), Rational::from(2));
+    assert!(r(10, 5).is_integer());
+    assert_eq!(format!("{}", x), "-3/4");
+    assert_eq!(format!("{}", r(4, 2)), "2");
+
+    // The most negative values reduce without overflowing
+    assert_eq!(r(i64::MIN, 2), Rational::from(i64::MIN / 2));
+    assert_eq!(r(i64::MIN, 6), r(i64::MIN / 2, 3));
+    assert_eq!(r(i64::MIN, i64::MIN), Rational::one());
+    assert_eq!(r(0, i64::MIN), Rational::zero());
+    let x = Rational::new(i128::MIN, 4);
+    assert_eq!(*x.numer(), i128::MIN / 4);
+    assert_eq!(*x.denom(), 1);
+    assert_eq!(Rational::new(i128::MIN, i128::MIN), Rational::<i128>::one());
+}
+
+#[test]
+#[should_panic]
+fn test_rational_zero_denominator() {
+    r(1, 0);
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+    assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+    assert_eq!(r(1, 2) - r(3, 4), r(-1, 4));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+    assert_eq!(r(7, 2) % r(1, 1), r(1, 2));
+    assert_eq!(r(-7, 2) % r(1, 1), r(-1, 2));
+    assert_eq!(-r(1, 2), r(-1, 2));
+    assert_eq!(r(2, 3).pow(3), r(8, 27));
+    assert_eq!(r(2, 3).pow(-2), r(9, 4));
+    assert_eq!(r(-2, 3).pow(5), r(-32, 243));
+    assert_eq!(r(5, 7).pow(0), r(1, 1));
+    assert_eq!(r(3, 2).pow(39), r(4052555153018976267, 549755813888));
+    let big = BigRational::new(BigInt::from(3), BigInt::from(2)).pow(-1000);
+    assert_eq!(*big.numer(), BigInt::from(2).pow(1000));
+    assert_eq!(*big.denom(), BigInt::from(3).pow(1000));
+    assert_eq!(r(-2, 3).recip(), r(-3, 2));
+
+    let mut x = r(1, 2);
+    x += r(1, 4);
+    x *= r(4, 1);
+    x -= r(1, 1);
+    x /= r(4, 1);
+    assert_eq!(x, r(1, 2));
+
+    // Harmonic number H_20 exactly
+    let h: Rational = (1..=20).map(|k| r(1, k)).sum();
+    assert_eq!(h, r(55835135, 15519504));
+    let p: Rational = (1..=10).map(|k| r(k, k + 1)).product();
+    assert_eq!(p, r(1, 11));
+
+    // Gear ratios stay exact where f64 drifts
+    let ratio = r(17, 53) * r(53, 29) * r(29, 17);
+    assert_eq!(ratio, Rational::one());
+}
+
+#[test]
+fn test_rational_ordering_and_rounding() {
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert_eq!(r(2, 4).cmp(&r(1, 2)), std::cmp::Ordering::Equal);
+    let mut values = vec![r(3, 4), r(-1, 2), r(2, 3), r(0, 1)];
+    values.sort();
+    assert_eq!(values, vec![r(-1, 2), r(0, 1), r(2, 3), r(3, 4)]);
+
+    assert_eq!(r(7, 2).floor(), 3);
+    assert_eq!(r(-7, 2).floor(), -4);
+    assert_eq!(r(7, 2).ceil(), 4);
+    assert_eq!(r(-7, 2).ceil(), -3);
+    assert_eq!(r(-7, 2).trunc(), -3);
+    assert_eq!(r(7, 2).round(), 4);
+    assert_eq!(r(-7, 2).round(), -4);
+    assert_eq!(r(5, 3).round(), 2);
+    assert_eq!(r(-7, 2).fract(), r(-1, 2));
+    assert_eq!(r(-7, 2).abs(), r(7, 2));
+}
+
+#[test]
+fn test_rational_parsing() {
+    assert_eq!("3/4".parse::<Rational>(), Ok(r(3, 4)));
+    assert_eq!("-6/8".parse::<Rational>(), Ok(r(-3, 4)));
+    assert_eq!("42".parse::<Rational>(), Ok(r(42, 1)));
+    assert_eq!("1.25".parse::<Rational>(), Ok(r(5, 4)));
+    assert_eq!("-0.125".parse::<Rational>(), Ok(r(-1, 8)));
+    assert_eq!("1/0".parse::<Rational>(), Err(ParseRationalError));
+    assert_eq!("1.".parse::<Rational>(), Err(ParseRationalError));
+    assert_eq!("abc".parse::<Rational>(), Err(ParseRationalError));
+
+    let big: BigRational = "123456789012345678901234567890/987654321098765432109876543210"
+        .parse()
+        .unwrap();
+    assert_eq!(format!("{}", big), "13717421/109739369");
+}
+
+#[test]
+fn test_rational_float_conversion() {
+    assert_eq!(r(1, 3).to_f64(), 1.0 / 3.0);
+    assert_eq!(r(-22, 7).to_f64(), -22.0 / 7.0);
+    assert_eq!(Rational::<i64>::zero().to_f64(), 0.0);
+
+    assert_eq!(Rational::<i64>::from_f64(0.375), Some(r(3, 8)));
+    assert_eq!(Rational::<i64>::from_f64(-2.5), Some(r(-5, 2)));
+    assert_eq!(Rational::<i64>::from_f64(1e300), None);
+    assert_eq!(Rational::<i64>::from_f64(f64::NAN), None);
+    let tenth = BigRational::from_f64(0.1).unwrap();
+    assert_eq!(tenth.to_f64(), 0.1);
+    assert_eq!(*tenth.denom(), BigInt::from(1) << 55);
+
+    // (2^54 + 2) + 1 / (3 2^20) truncates to exactly the halfway point between
+    // 2^54 and 2^54 + 4, but lies above it
+    let above_half = BigRational::new(
+        BigInt::from(3u64 << 20) * BigInt::from((1u64 << 54) + 2) + BigInt::from(1),
+        BigInt::from(3u64 << 20),
+    );
+    assert_eq!(above_half.to_f64(), ((1u64 << 54) + 4) as f64);
+    assert_eq!((-above_half).to_f64(), -(((1u64 << 54) + 4) as f64));
+
+    // Subnormal results round once, straight to a multiple of 2^-1074
+    let scale = BigInt::from(1) << 1024;
+    let subnormal = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d) * &scale);
+    assert_eq!(subnormal(5, 39).to_f64(), 7.1316469823949e-310);
+    assert_eq!(subnormal(6, 11).to_f64(), 3.034191625237095e-309);
+    assert_eq!(subnormal(-1, 3).to_f64(), -1.854228215422666e-309);
+    let smallest = BigRational::new(BigInt::from(1), BigInt::from(1) << 1074);
+    assert_eq!(smallest.to_f64(), f64::from_bits(1));
+    let below_half = BigRational::new(BigInt::from(1), BigInt::from(3) << 1074);
+    assert_eq!(below_half.to_f64(), 0.0);
+    let above_half = BigRational::new(BigInt::from(2), BigInt::from(3) << 1074);
+    assert_eq!(above_half.to_f64(), f64::from_bits(1));
+
+    let tiny = BigRational::from_f64(f64::MIN_POSITIVE / 8.0).unwrap();
+    assert_eq!(tiny.to_f64(), f64::MIN_POSITIVE / 8.0);
+
+    assert_eq!(
+        Rational::approximate(std::f64::consts::PI, 1000),
+        Some(r(355, 113))
+    );
+    assert_eq!(
+        Rational::approximate(std::f64::consts::PI, 100),
+        Some(r(311, 99))
+    );
+    assert_eq!(
+        Rational::approximate(std::f64::consts::PI, 7),
+        Some(r(22, 7))
+    );
+    assert_eq!(Rational::approximate(0.1, 1_000_000), Some(r(1, 10)));
+    assert_eq!(Rational::approximate(-0.333333, 100), Some(r(-1, 3)));
+    assert_eq!(Rational::approximate(2.0, 1), Some(r(2, 1)));
+    assert_eq!(
+        Rational::<i128>::approximate(std::f64::consts::E, 1_000_000_000_000),
+        Some(Rational::new(2248982312617, 827354356370))
+    );
+}
+
+#[test]
+fn test_continued_fractions() {
+    assert_eq!(r(415, 93).continued_fraction(), vec![4, 2, 6, 7]);
+    assert_eq!(r(-7, 3).continued_fraction(), vec![-3, 1, 2]);
+    assert_eq!(r(5, 1).continued_fraction(), vec![5]);
+
+    assert_eq!(
+        continued_fraction(std::f64::consts::PI, 5),
+        vec![3, 7, 15, 1, 292]
+    );
+    assert_eq!(continued_fraction(0.5, 10), vec![0, 2]);
+    assert_eq!(continued_fraction(f64::INFINITY, 3), vec![]);
+
+    let sqrt2 = continued_fraction(std::f64::consts::SQRT_2, 10);
+    assert_eq!(sqrt2, vec![1, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+
+    let c = convergents(&[3, 7, 15, 1, 292]);
+    assert_eq!(
+        c,
+        vec![
+            r(3, 1),
+            r(22, 7),
+            r(333, 106),
+            r(355, 113),
+            r(103993, 33102)
+        ]
+    );
+    assert_eq!(
+        convergents(&r(415, 93).continued_fraction()).last(),
+        Some(&r(415, 93))
+    );
+}
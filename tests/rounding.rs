@@ -75,9 +75,9 @@ fn test_special_cases() {
     assert!(ceiling(f64::INFINITY).is_infinite());
     assert!(round(f64::INFINITY).is_infinite());
 
-    assert!(modulo(5.0, 0.0).is_nan());
-    assert!(quotient(5.0, 0.0).is_infinite());
-    assert!(quotient(0.0, 0.0).is_nan());
+    assert!(modulo(5.0, 0.0_f64).is_nan());
+    assert!(quotient(5.0, 0.0_f64).is_infinite());
+    assert!(quotient(0.0, 0.0_f64).is_nan());
 }
 
 #[test]
@@ -1,3 +1,4 @@
+use rmath::bigint::BigInt;
 use rmath::sequences::*;
 
 #[test]
@@ -33,3 +34,38 @@ fn test_fibonacci_lucas_relationship() {
         assert_eq!(lucas(n), fibonacci(n - 1) + fibonacci(n + 1));
     }
 }
+
+#[test]
+fn test_fibonacci_big() {
+    for n in -92..=92 {
+        assert_eq!(fibonacci_big(n), BigInt::from(fibonacci(n)));
+    }
+
+    assert_eq!(
+        fibonacci_big(1000).to_string(),
+        "43466557686937456435688527675040625802564660517371780402481729089536555417949051890403879840079255169295922593080322634775209689623239873322471161642996440906533187938298969649928516003704476137795166849228875"
+    );
+
+    let f = fibonacci_big(10000).to_string();
+    assert_eq!(f.len(), 2090);
+    assert!(f.starts_with("33644764876431783266"));
+    assert!(f.ends_with("66073310059947366875"));
+}
+
+#[test]
+fn test_lucas_big() {
+    for n in -90..=90 {
+        assert_eq!(lucas_big(n), BigInt::from(lucas(n)));
+    }
+
+    assert_eq!(
+        lucas_big(500).to_string(),
+        "311759807762174781605301007201736860141952393239819073913168769888623683854510476118474315229371415703127"
+    );
+
+    // L(n) = F(n-1) + F(n+1) and F(2n) = F(n) L(n) well past i64
+    for n in [100, 257, 1000] {
+        assert_eq!(lucas_big(n), fibonacci_big(n - 1) + fibonacci_big(n + 1));
+        assert_eq!(fibonacci_big(2 * n), fibonacci_big(n) * lucas_big(n));
+    }
+}
@@ -48,7 +48,7 @@ fn test_erf_special_cases() {
 
 #[test]
 fn test_erf_mathematical_properties() {
-    let x = 1.0;
+    let x = 1.0_f64;
     let h = 1e-8;
     let numerical_derivative = (erf(x + h) - erf(x - h)) / (2.0 * h);
     let analytical_derivative = 2.0 / std::f64::consts::PI.sqrt() * (-x * x).exp();
@@ -56,7 +56,107 @@ fn test_erf_mathematical_properties() {
 
     // Test integral relationship: ∫₀ˣ erf(t) dt = x·erf(x) + 1/√π·(1 - exp(-x²))
     // This is just a sanity check for small values
-    let x = 0.5;
+    let x = 0.5_f64;
     let expected_integral = x * erf(x) + (1.0 - (-x * x).exp()) / std::f64::consts::PI.sqrt();
     assert!(expected_integral > 0.0);
 }
+
+#[test]
+fn test_erf_accuracy() {
+    assert_relative_eq!(erf(0.1), 0.1124629160182849, max_relative = 1e-15);
+    assert_relative_eq!(erf(0.5), 0.5204998778130465, max_relative = 1e-15);
+    assert_relative_eq!(erf(1.0), 0.8427007929497149, max_relative = 1e-15);
+    assert_relative_eq!(erf(3.0), 0.9999779095030014, max_relative = 1e-15);
+    assert_relative_eq!(erf(1e-10), 1.1283791670955126e-10, max_relative = 1e-15);
+    assert_eq!(erf(-0.0_f64).to_bits(), (-0.0_f64).to_bits());
+}
+
+#[test]
+fn test_erfc_tails() {
+    assert_relative_eq!(erfc(0.5), 0.4795001221869535, max_relative = 1e-15);
+    assert_relative_eq!(erfc(2.0), 0.004677734981047266, max_relative = 1e-15);
+    assert_relative_eq!(erfc(5.0), 1.537459794428035e-12, max_relative = 1e-15);
+    assert_relative_eq!(erfc(10.0), 2.088487583762545e-45, max_relative = 1e-15);
+    assert_relative_eq!(erfc(26.0), 5.663192408856143e-296, max_relative = 1e-15);
+    assert_relative_eq!(erfc(-3.0), 1.9999779095030015, max_relative = 1e-15);
+
+    // Subnormal results keep all the precision their format allows
+    assert_relative_eq!(erfc(27.0), 5.23705e-319, max_relative = 1e-5);
+    assert_eq!(erfc(28.0), 0.0);
+}
+
+#[test]
+fn test_erfcx() {
+    assert_eq!(erfcx(0.0), 1.0);
+    assert_relative_eq!(erfcx(1.0), 0.427583576155807, max_relative = 1e-15);
+    assert_relative_eq!(erfcx(5.0), 0.11070463773306863, max_relative = 1e-15);
+    assert_relative_eq!(erfcx(30.0), 0.01879588886141675, max_relative = 1e-15);
+    assert_relative_eq!(erfcx(1e10), 5.641895835477563e-11, max_relative = 1e-15);
+    assert_relative_eq!(erfcx(-2.0), 108.94090438997797, max_relative = 1e-15);
+
+    for &x in &[0.2_f64, 0.7, 1.9, 3.3, 6.0] {
+        assert_relative_eq!(erfcx(x) * (-x * x).exp(), erfc(x), max_relative = 1e-14);
+    }
+
+    assert!(erfcx(f64::NAN).is_nan());
+    assert_eq!(erfcx(f64::INFINITY), 0.0);
+    assert_eq!(erfcx(-30.0), f64::INFINITY);
+}
+
+#[test]
+fn test_erf_inv() {
+    assert_eq!(erf_inv(0.0), 0.0);
+    assert_relative_eq!(erf_inv(0.1), 0.08885599049425769, max_relative = 1e-15);
+    assert_relative_eq!(erf_inv(0.5), 0.4769362762044699, max_relative = 1e-15);
+    assert_relative_eq!(erf_inv(0.9), 1.1630871536766743, max_relative = 1e-15);
+    assert_relative_eq!(erf_inv(0.999999), 3.458910737275499, max_relative = 1e-15);
+    assert_relative_eq!(erf_inv(-0.3), -0.2724627147267543, max_relative = 1e-15);
+
+    for &x in &[-2.5, -0.7, 0.01, 0.45, 1.3, 2.8] {
+        assert_relative_eq!(erf_inv(erf(x)), x, max_relative = 1e-12);
+    }
+
+    assert_eq!(erf_inv(1.0), f64::INFINITY);
+    assert_eq!(erf_inv(-1.0), f64::NEG_INFINITY);
+    assert!(erf_inv(1.5_f64).is_nan());
+    assert!(erf_inv(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_erfc_inv() {
+    assert_relative_eq!(erfc_inv(0.1), 1.163087153676674, max_relative = 1e-15);
+    assert_relative_eq!(erfc_inv(1e-10), 4.572824967389486, max_relative = 1e-15);
+    assert_relative_eq!(erfc_inv(1e-300), 26.209469960516124, max_relative = 1e-15);
+    assert_relative_eq!(erfc_inv(1.5), -0.4769362762044699, max_relative = 1e-15);
+    assert_relative_eq!(erfc_inv(1.9999), -2.75106390571208, max_relative = 1e-14);
+
+    for &x in &[-1.5, 0.3, 2.0, 9.0, 20.0] {
+        assert_relative_eq!(erfc_inv(erfc(x)), x, max_relative = 1e-14);
+    }
+
+    assert_eq!(erfc_inv(0.0), f64::INFINITY);
+    assert_eq!(erfc_inv(2.0), f64::NEG_INFINITY);
+    assert!(erfc_inv(-0.1_f64).is_nan());
+    assert!(erfc_inv(2.1_f64).is_nan());
+}
+
+#[test]
+fn test_dawson() {
+    assert_eq!(dawson(0.0), 0.0);
+    assert_relative_eq!(dawson(0.1), 0.09933599239785286, max_relative = 1e-15);
+    assert_relative_eq!(dawson(0.5), 0.4244363835020223, max_relative = 1e-15);
+    assert_relative_eq!(dawson(2.0), 0.30134038892379195, max_relative = 1e-15);
+    assert_relative_eq!(dawson(10.0), 0.05025384718759853, max_relative = 1e-15);
+    assert_relative_eq!(dawson(100.0), 0.005000250037509378, max_relative = 1e-15);
+    assert_relative_eq!(dawson(-2.0), -dawson(2.0), max_relative = 1e-15);
+
+    // Maximum of F at x ≈ 0.924, where F(x) = 1 / (2x)
+    assert_relative_eq!(
+        dawson(0.9241388730045916),
+        0.5410442246351816,
+        max_relative = 1e-15
+    );
+
+    assert!(dawson(f64::NAN).is_nan());
+    assert_eq!(dawson(f64::INFINITY), 0.0);
+}
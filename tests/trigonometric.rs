@@ -51,8 +51,8 @@ fn test_arcsin() {
     assert_relative_eq!(arcsin(1.0), PI / 2.0, epsilon = 1e-10);
     assert_relative_eq!(arcsin(-1.0), -PI / 2.0, epsilon = 1e-10);
 
-    assert!(arcsin(1.1).is_nan());
-    assert!(arcsin(-1.1).is_nan());
+    assert!(arcsin(1.1_f64).is_nan());
+    assert!(arcsin(-1.1_f64).is_nan());
 }
 
 #[test]
@@ -63,8 +63,8 @@ fn test_arccos() {
     assert_relative_eq!(arccos(0.0), PI / 2.0, epsilon = 1e-10);
     assert_relative_eq!(arccos(-1.0), PI, epsilon = 1e-10);
 
-    assert!(arccos(1.1).is_nan());
-    assert!(arccos(-1.1).is_nan());
+    assert!(arccos(1.1_f64).is_nan());
+    assert!(arccos(-1.1_f64).is_nan());
 }
 
 #[test]
@@ -87,11 +87,11 @@ fn test_sinc() {
 fn test_inverse_reciprocal_functions() {
     assert_relative_eq!(arcsec(1.0), 0.0, epsilon = 1e-10);
     assert_relative_eq!(arcsec(2.0), PI / 3.0, epsilon = 1e-10);
-    assert!(arcsec(0.5).is_nan());
+    assert!(arcsec(0.5_f64).is_nan());
 
     assert_relative_eq!(arccsc(1.0), PI / 2.0, epsilon = 1e-10);
     assert_relative_eq!(arccsc(2.0), PI / 6.0, epsilon = 1e-10);
-    assert!(arccsc(0.5).is_nan());
+    assert!(arccsc(0.5_f64).is_nan());
 
     assert_relative_eq!(arccot(1.0), PI / 4.0, epsilon = 1e-10);
     assert_relative_eq!(arccot(3.0_f64.sqrt()), PI / 6.0, epsilon = 1e-10);
@@ -0,0 +1,204 @@
+use approx::assert_relative_eq;
+use rmath::special::*;
+use std::f64::consts::{LN_2, PI};
+
+#[test]
+fn test_zeta_values() {
+    assert_relative_eq!(zeta(2.0), PI * PI / 6.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(3.0), 1.2020569031595942, max_relative = 1e-15);
+    assert_relative_eq!(zeta(4.0), PI.powi(4) / 90.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(1.5), 2.612375348685488, max_relative = 1e-15);
+    assert_relative_eq!(zeta(0.5), -1.4603545088095868, max_relative = 1e-15);
+    assert_relative_eq!(zeta(20.5), 1.0000006745156182, max_relative = 1e-15);
+    assert_eq!(zeta(0.0), -0.5);
+    assert_relative_eq!(zeta(1e-12), -0.5000000000009189, max_relative = 1e-15);
+}
+
+#[test]
+fn test_zeta_pole_and_negative_arguments() {
+    assert_eq!(zeta(1.0), f64::INFINITY);
+    assert_relative_eq!(zeta(1.0 + 1e-10), 9999999173.173574, max_relative = 1e-14);
+    assert_relative_eq!(zeta(1.0 - 1e-10), -9999999172.019142, max_relative = 1e-14);
+
+    assert_relative_eq!(zeta(-1.0), -1.0 / 12.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(-3.0), 1.0 / 120.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(-0.5), -0.20788622497735457, max_relative = 1e-15);
+    assert_relative_eq!(zeta(-7.25), 0.004038356439206074, max_relative = 1e-14);
+    assert_relative_eq!(zeta(-40.5), -5530487585144642.0, max_relative = 1e-14);
+
+    // Trivial zeros are exact, and the function stays accurate right next to them
+    for &s in &[-2.0, -4.0, -10.0, -200.0] {
+        assert_eq!(zeta(s), 0.0);
+    }
+    assert_relative_eq!(zeta(-2.000001), 3.044842418090506e-8, max_relative = 1e-13);
+
+    assert_eq!(zeta(f64::INFINITY), 1.0);
+    assert!(zeta(f64::NAN).is_nan());
+    assert!(zeta(f64::NEG_INFINITY).is_nan());
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    assert_relative_eq!(hurwitz_zeta(2.0, 0.5), PI * PI / 2.0, max_relative = 1e-15);
+    assert_relative_eq!(
+        hurwitz_zeta(3.0, 2.5),
+        0.1181020258208637,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hurwitz_zeta(1.5, 0.1),
+        34.0529755150756,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hurwitz_zeta(10.0, 0.3),
+        169350.9508709071,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        hurwitz_zeta(4.2, 100.0),
+        1.2641295965310297e-7,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hurwitz_zeta(0.5, 2.0),
+        -2.4603545088095866,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        hurwitz_zeta(-0.5, 3.5),
+        -3.452102017081731,
+        max_relative = 1e-13
+    );
+
+    // ζ(s, 1) is the Riemann zeta and ζ(s, a) - ζ(s, a + 1) = a^-s
+    for &s in &[1.5, 2.0, 7.3] {
+        assert_relative_eq!(hurwitz_zeta(s, 1.0), zeta(s), max_relative = 1e-15);
+        for &a in &[0.2_f64, 1.7, 12.0] {
+            assert_relative_eq!(
+                hurwitz_zeta(s, a) - hurwitz_zeta(s, a + 1.0),
+                a.powf(-s),
+                max_relative = 1e-13
+            );
+        }
+    }
+
+    assert_eq!(hurwitz_zeta(1.0, 0.5), f64::INFINITY);
+    assert!(hurwitz_zeta(2.0, 0.0_f64).is_nan());
+    assert!(hurwitz_zeta(2.0, -1.5_f64).is_nan());
+}
+
+#[test]
+fn test_dirichlet_eta() {
+    assert_eq!(dirichlet_eta(1.0), LN_2);
+    assert_relative_eq!(dirichlet_eta(2.0), PI * PI / 12.0, max_relative = 1e-15);
+    assert_relative_eq!(dirichlet_eta(0.5), 0.6048986434216304, max_relative = 1e-15);
+    assert_relative_eq!(
+        dirichlet_eta(-1.5),
+        0.11868087071984021,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(dirichlet_eta(3.3), 0.9180273147252637, max_relative = 1e-15);
+    assert_relative_eq!(dirichlet_eta(-7.0), -1.0625, max_relative = 1e-14);
+    assert_relative_eq!(dirichlet_eta(0.0), 0.5, max_relative = 1e-15);
+    assert_eq!(dirichlet_eta(-2.0), 0.0);
+
+    // No cancellation against the pole of ζ
+    assert_relative_eq!(
+        dirichlet_eta(1.000000001),
+        0.6931471807198142,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_polylog_inside_unit_interval() {
+    assert_relative_eq!(polylog(2.0, 0.5), 0.5822405264650125, max_relative = 1e-15);
+    assert_relative_eq!(
+        polylog(2.0, -0.5),
+        -0.4484142069236462,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(polylog(3.0, 0.9), 1.04965895018644, max_relative = 1e-15);
+    assert_relative_eq!(polylog(2.5, 0.7), 0.8217928709427715, max_relative = 1e-15);
+    assert_relative_eq!(polylog(0.5, 0.3), 0.384777445134209, max_relative = 1e-15);
+    assert_relative_eq!(polylog(-1.5, 0.8), 56.48924197143224, max_relative = 1e-14);
+    assert_relative_eq!(
+        polylog(-2.5, -0.4),
+        -0.02014011882050746,
+        max_relative = 1e-13
+    );
+
+    // The expansion around z = 1 stays accurate next to integer orders
+    assert_relative_eq!(
+        polylog(2.0000001, 0.9),
+        1.2997146795536267,
+        max_relative = 1e-14
+    );
+
+    // Closed forms for small integer orders
+    for &z in &[-0.9, -0.3, 0.2, 0.75] {
+        assert_relative_eq!(polylog(1.0, z), -(1.0_f64 - z).ln(), max_relative = 1e-15);
+        assert_relative_eq!(polylog(0.0, z), z / (1.0 - z), max_relative = 1e-15);
+        assert_relative_eq!(
+            polylog(-1.0, z),
+            z / ((1.0 - z) * (1.0 - z)),
+            max_relative = 1e-13
+        );
+    }
+}
+
+#[test]
+fn test_polylog_below_minus_one() {
+    assert_relative_eq!(
+        polylog(2.0, -10.0),
+        -4.198277886858103,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        polylog(3.0, -1000.0),
+        -66.30012385080927,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        polylog(1.7, -50.0),
+        -7.439703850256423,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        polylog(0.4, -20.0),
+        -1.647560360590919,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        polylog(-2.5, -8.0),
+        0.022522741535525786,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(polylog(4.5, -3.0), -2.714968785740124, max_relative = 1e-14);
+
+    // Li_2(-x) + Li_2(-1/x) = -π²/6 - ln²(x)/2
+    for &x in &[2.0, 30.0, 1e8] {
+        let ln = f64::ln(x);
+        assert_relative_eq!(
+            polylog(2.0, -x) + polylog(2.0, -1.0 / x),
+            -PI * PI / 6.0 - 0.5 * ln * ln,
+            max_relative = 1e-14
+        );
+    }
+}
+
+#[test]
+fn test_polylog_special_cases() {
+    assert_eq!(polylog(2.0, 0.0), 0.0);
+    assert_eq!(polylog(2.0, 1.0), zeta(2.0));
+    assert_eq!(polylog(2.0, -1.0), -dirichlet_eta(2.0));
+    assert_eq!(polylog(0.5, 1.0), f64::INFINITY);
+    assert_eq!(polylog(2.0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(polylog(f64::INFINITY, 0.3), 0.3);
+
+    // Real z > 1 lies on the branch cut
+    assert!(polylog(2.0, 1.5_f64).is_nan());
+    assert!(polylog(f64::NAN, 0.5).is_nan());
+    assert!(polylog(2.0, f64::NAN).is_nan());
+}